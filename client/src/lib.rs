@@ -0,0 +1,237 @@
+use ::b64::FromBase64;
+
+use ::backtrace::Backtrace;
+
+use ::futures_util::{SinkExt, StreamExt};
+
+use ::tokio::net::TcpStream;
+
+use ::tokio_tungstenite::{tungstenite, MaybeTlsStream, WebSocketStream};
+
+use ::std::path::Path;
+
+use ::network::{EncodingError, Message};
+
+#[derive(Debug)]
+pub struct ClientError {
+    kind: ClientErrorKind,
+    stack: Backtrace,
+}
+
+impl ClientError {
+    fn new(kind: ClientErrorKind) -> Self {
+        ClientError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+
+    pub fn kind(&self) -> &ClientErrorKind {
+        &self.kind
+    }
+
+    pub fn stack(&self) -> &Backtrace {
+        &self.stack
+    }
+}
+
+impl From<ClientErrorKind> for ClientError {
+    fn from(kind: ClientErrorKind) -> Self {
+        ClientError::new(kind)
+    }
+}
+
+impl From<::std::io::Error> for ClientError {
+    fn from(error: ::std::io::Error) -> Self {
+        ClientError::new(ClientErrorKind::Io(error))
+    }
+}
+
+impl From<::bincode::Error> for ClientError {
+    fn from(error: ::bincode::Error) -> Self {
+        ClientError::new(ClientErrorKind::Bincode(error))
+    }
+}
+
+impl From<EncodingError> for ClientError {
+    fn from(error: EncodingError) -> Self {
+        ClientError::new(ClientErrorKind::Encoding(error))
+    }
+}
+
+impl From<tungstenite::Error> for ClientError {
+    fn from(error: tungstenite::Error) -> Self {
+        ClientError::new(ClientErrorKind::Websocket(Box::new(error)))
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientErrorKind {
+    Io(::std::io::Error),
+    Bincode(::bincode::Error),
+    Encoding(EncodingError),
+    Websocket(Box<tungstenite::Error>),
+    /// The server sent a frame that is not a base64 encoded `Message`.
+    UnexpectedFrame(tungstenite::Message),
+}
+
+/// A websocket connection to a session on the server.
+pub struct Session {
+    websocket: WebSocketStream<MaybeTlsStream<TcpStream>>,
+}
+
+impl Session {
+    /// Connects to the server's websocket, e.g. `ws://localhost:3030/websocket`.
+    pub async fn connect(url: &str) -> Result<Session, ClientError> {
+        let (websocket, _) = tokio_tungstenite::connect_async(url).await?;
+
+        Ok(Session { websocket })
+    }
+
+    pub async fn send(&mut self, message: &Message) -> Result<(), ClientError> {
+        let data: String = message.try_into()?;
+
+        self.websocket
+            .send(tungstenite::Message::Text(data))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Waits for the next message from the session, or `None` once the server closes the connection.
+    pub async fn receive(&mut self) -> Result<Option<Message>, ClientError> {
+        loop {
+            match self.websocket.next().await {
+                None => return Ok(None),
+                Some(Err(e)) => Err(e)?,
+                Some(Ok(tungstenite::Message::Text(data))) => {
+                    return Ok(Some(Message::try_from(data.as_str())?))
+                }
+                Some(Ok(tungstenite::Message::Close(_))) => return Ok(None),
+                Some(Ok(tungstenite::Message::Ping(_))) | Some(Ok(tungstenite::Message::Pong(_))) => {}
+                Some(Ok(frame)) => Err(ClientErrorKind::UnexpectedFrame(frame))?,
+            }
+        }
+    }
+
+    pub async fn close(mut self) -> Result<(), ClientError> {
+        self.websocket.close(None).await?;
+
+        Ok(())
+    }
+}
+
+/// Tracks the last full state of a session so `DeltaSnapshot`s can be turned back into full states.
+#[derive(Debug, Default)]
+pub struct SnapshotRebuilder {
+    current: Option<Vec<u8>>,
+}
+
+impl SnapshotRebuilder {
+    pub fn new() -> SnapshotRebuilder {
+        SnapshotRebuilder { current: None }
+    }
+
+    pub fn current(&self) -> Option<&[u8]> {
+        self.current.as_deref()
+    }
+
+    /// Feeds a received message and returns the full state it produced, if any.
+    ///
+    /// Deltas that arrive before any full state, that were created against a different base, or whose changes lie
+    /// outside the current state are dropped.
+    pub fn accept(&mut self, message: Message) -> Option<&[u8]> {
+        match message {
+            Message::Snapshot(snapshot) | Message::Play(snapshot) => {
+                self.current = Some(snapshot);
+            }
+            Message::DeltaSnapshot(delta) => match self.current.as_deref() {
+                Some(current) => match delta.try_apply(current) {
+                    Some(new) => self.current = Some(new),
+                    None => {
+                        log::warn!("Dropping delta that does not fit the current state.");
+                        return None;
+                    }
+                },
+                None => {
+                    log::warn!("Dropping delta received before any full state.");
+                    return None;
+                }
+            },
+            _ => return None,
+        }
+
+        self.current.as_deref()
+    }
+}
+
+/// Reads a saved state from disk.
+///
+/// Accepts both the raw bytes from `serialize_to_uint8array` and the base64 text from `exportSaveState`.
+pub fn read_state_file(path: &Path) -> Result<Vec<u8>, ClientError> {
+    let data = ::std::fs::read(path)?;
+
+    match ::std::str::from_utf8(&data) {
+        Ok(text) => match text.trim().from_base64() {
+            Ok(decoded) => Ok(decoded),
+            Err(_) => Ok(data),
+        },
+        Err(_) => Ok(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SnapshotRebuilder;
+
+    use ::network::{DeltaSnapshot, Message};
+
+    #[test]
+    fn test_rebuilder_applies_deltas() {
+        let old = vec![1u8, 2, 3, 4];
+        let new = vec![1u8, 9, 3, 4];
+
+        let mut rebuilder = SnapshotRebuilder::new();
+
+        assert_eq!(rebuilder.accept(Message::Snapshot(old.clone())), Some(old.as_ref()));
+        assert_eq!(
+            rebuilder.accept(Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new))),
+            Some(new.as_ref())
+        );
+    }
+
+    #[test]
+    fn test_rebuilder_drops_unmatched_deltas() {
+        let old = vec![1u8, 2, 3, 4];
+        let new = vec![1u8, 9, 3, 4];
+
+        let mut rebuilder = SnapshotRebuilder::new();
+
+        assert_eq!(
+            rebuilder.accept(Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new))),
+            None
+        );
+
+        rebuilder.accept(Message::Snapshot(vec![7u8, 2, 3, 8]));
+        assert_eq!(
+            rebuilder.accept(Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new))),
+            None
+        );
+        assert_eq!(rebuilder.current(), Some([7u8, 2, 3, 8].as_ref()));
+    }
+
+    #[test]
+    fn test_rebuilder_drops_out_of_range_deltas() {
+        let mut longer = vec![3u8; 1024];
+        longer[600] = 4;
+
+        let mut rebuilder = SnapshotRebuilder::new();
+        rebuilder.accept(Message::Snapshot(vec![3u8; 32]));
+
+        assert_eq!(
+            rebuilder.accept(Message::DeltaSnapshot(DeltaSnapshot::new(&[3u8; 1024], &longer))),
+            None
+        );
+        assert_eq!(rebuilder.current(), Some([3u8; 32].as_ref()));
+    }
+}
@@ -0,0 +1,105 @@
+use ::clap::Parser;
+
+use ::std::path::PathBuf;
+
+use ::network::Message;
+
+use ::client::{read_state_file, ClientError, Session, SnapshotRebuilder};
+
+#[derive(Parser, Debug)]
+struct Args {
+    /// Websocket of the session, e.g. `ws://localhost:3030/websocket`.
+    url: String,
+
+    /// BIOS file to upload to the session.
+    #[clap(long)]
+    bios: Option<PathBuf>,
+
+    /// ROM file to upload to the session.
+    #[clap(long)]
+    rom: Option<PathBuf>,
+
+    /// Saved state (raw or base64) to push into the session.
+    #[clap(long)]
+    state: Option<PathBuf>,
+
+    /// Push `--state` as `Play` so clients that have not started the game yet start it.
+    #[clap(long, requires = "state")]
+    play: bool,
+
+    /// Directory to write every received state into. Without it the client disconnects after uploading.
+    #[clap(long)]
+    dump_dir: Option<PathBuf>,
+
+    /// Disconnect after this many states were written to `--dump-dir`.
+    #[clap(long, requires = "dump-dir")]
+    max_states: Option<usize>,
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let args = Args::parse();
+
+    if let Err(e) = run(args).await {
+        log::error!("Client failed: {:?}", e);
+        ::std::process::exit(1);
+    }
+}
+
+async fn run(args: Args) -> Result<(), ClientError> {
+    let mut session = Session::connect(&args.url).await?;
+    log::info!("Connected to {}", args.url);
+
+    if let Some(path) = args.bios {
+        let bios = tokio::fs::read(&path).await?;
+        log::info!("Bios -- {:?}", bios.len());
+        session.send(&Message::Bios(bios)).await?;
+    }
+
+    if let Some(path) = args.rom {
+        let rom = tokio::fs::read(&path).await?;
+        log::info!("Rom -- {:?}", rom.len());
+        session.send(&Message::Rom(rom)).await?;
+    }
+
+    if let Some(path) = args.state {
+        let state = read_state_file(&path)?;
+        log::info!("State -- {:?}", state.len());
+
+        let message = if args.play {
+            Message::Play(state)
+        } else {
+            Message::Snapshot(state)
+        };
+        session.send(&message).await?;
+    }
+
+    let dump_dir = match args.dump_dir {
+        Some(dump_dir) => dump_dir,
+        None => return session.close().await,
+    };
+
+    tokio::fs::create_dir_all(&dump_dir).await?;
+
+    let mut rebuilder = SnapshotRebuilder::new();
+    let mut dumped = 0usize;
+
+    while let Some(message) = session.receive().await? {
+        if let Some(state) = rebuilder.accept(message) {
+            let path = dump_dir.join(format!("state-{:06}.bin", dumped));
+            tokio::fs::write(&path, state).await?;
+            log::info!("Wrote {:?} -- {:?}", path, state.len());
+
+            dumped += 1;
+            if args.max_states == Some(dumped) {
+                return session.close().await;
+            }
+        }
+    }
+
+    log::info!("Session closed after {} states.", dumped);
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+#[path = "../../server/tests/common/mod.rs"]
+mod common;
+
+use ::tokio::time::timeout;
+
+use ::std::{
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+use ::network::{DeltaSnapshot, Message};
+
+use ::client::{Session, SnapshotRebuilder};
+
+use crate::common::{Relay, RECEIVE_TIMEOUT};
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = ::std::env::temp_dir().join(format!("client-{}-{}", name, ::std::process::id()));
+    let _ = ::std::fs::remove_dir_all(&dir);
+    ::std::fs::create_dir_all(&dir).expect("Failed to create scratch dir");
+    dir
+}
+
+async fn receive(session: &mut Session) -> Message {
+    timeout(RECEIVE_TIMEOUT, session.receive())
+        .await
+        .expect("Timed out waiting for message")
+        .expect("Failed to receive")
+        .expect("Session closed")
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_binary_uploads_rom_and_state() {
+    let relay = Relay::start();
+    let dir = scratch_dir("upload");
+
+    let mut receiver = Session::connect(&relay.url()).await.expect("Failed to connect");
+    relay.wait_for_listeners(1).await;
+
+    let rom = vec![0x2Eu8; 512];
+    let state = vec![0x11u8, 0x22, 0x33];
+    ::std::fs::write(dir.join("game.gba"), &rom).unwrap();
+    ::std::fs::write(dir.join("game.savestate"), "ESIz").unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_client"))
+        .arg(relay.url())
+        .arg("--rom")
+        .arg(dir.join("game.gba"))
+        .arg("--state")
+        .arg(dir.join("game.savestate"))
+        .arg("--play")
+        .stdout(Stdio::null())
+        .status()
+        .expect("Failed to run client");
+    assert!(status.success());
+
+    match receive(&mut receiver).await {
+        Message::Rom(received) => assert_eq!(received, rom),
+        other => panic!("Expected Rom but got {:?}", other),
+    }
+    match receive(&mut receiver).await {
+        Message::Play(received) => assert_eq!(received, state),
+        other => panic!("Expected Play but got {:?}", other),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_binary_dumps_rebuilt_states() {
+    let relay = Relay::start();
+    let dir = scratch_dir("dump");
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_client"))
+        .arg(relay.url())
+        .arg("--dump-dir")
+        .arg(&dir)
+        .arg("--max-states")
+        .arg("2")
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("Failed to run client");
+    relay.wait_for_listeners(1).await;
+
+    let old: Vec<u8> = (0..=u8::MAX).cycle().take(1024).collect();
+    let mut new = old.clone();
+    new[512] = 0;
+
+    let mut sender = Session::connect(&relay.url()).await.expect("Failed to connect");
+    sender.send(&Message::Snapshot(old.clone())).await.unwrap();
+    sender
+        .send(&Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new)))
+        .await
+        .unwrap();
+
+    let status = tokio::task::spawn_blocking(move || child.wait())
+        .await
+        .unwrap()
+        .expect("Failed to wait on client");
+    assert!(status.success());
+
+    assert_eq!(::std::fs::read(dir.join("state-000000.bin")).unwrap(), old);
+    assert_eq!(::std::fs::read(dir.join("state-000001.bin")).unwrap(), new);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_session_round_trip() {
+    let relay = Relay::start();
+
+    let mut sender = Session::connect(&relay.url()).await.expect("Failed to connect");
+    let mut receiver = Session::connect(&relay.url()).await.expect("Failed to connect");
+    relay.wait_for_listeners(2).await;
+
+    let old = vec![5u8; 64];
+    let mut new = old.clone();
+    new[10] = 6;
+
+    sender.send(&Message::Snapshot(old.clone())).await.unwrap();
+    sender
+        .send(&Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new)))
+        .await
+        .unwrap();
+
+    let mut rebuilder = SnapshotRebuilder::new();
+    assert_eq!(rebuilder.accept(receive(&mut receiver).await), Some(old.as_ref()));
+    assert_eq!(rebuilder.accept(receive(&mut receiver).await), Some(new.as_ref()));
+
+    sender.close().await.unwrap();
+    relay.wait_for_listeners(1).await;
+}
@@ -130,6 +130,7 @@ function initTimer() {
 	IodineGUI.Iodine.setIntervalRate(+IodineGUI.defaults.timerRate);
     IodineGUI.coreTimerID = setInterval(function () {
         IodineGUI.Iodine.timerCallback(((+(new Date()).getTime()) - (+IodineGUI.startTime)) >>> 0);
+        applyCheats();
     }, IodineGUI.defaults.timerRate | 0);
 }
 function calculateTiming() {
@@ -138,6 +139,7 @@ function calculateTiming() {
 function startTimer() {
 	IodineGUI.coreTimerID = setInterval(function () {
         IodineGUI.Iodine.timerCallback(((+(new Date()).getTime()) - (+IodineGUI.startTime)) >>> 0);
+        applyCheats();
     }, IodineGUI.defaults.timerRate | 0);
 }
 function updateTimer(newRate) {
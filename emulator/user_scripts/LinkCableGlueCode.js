@@ -0,0 +1,209 @@
+"use strict";
+/*
+ Plugs the emulated SIO port into the session's virtual link cable.
+
+ The server decides who exchanges what (see `server/src/link.rs`), so while plugged in a transfer is never clocked
+ locally: it stays started until the server's result arrives. Like the save states, this patches the core running on
+ the page's thread.
+*/
+const LinkCable = {
+    network: null,
+    /* Sends a message to the server, `null` while unplugged. */
+    send: null,
+    /* This emulator's player number and a bitmask of the occupied ones. */
+    player: 0,
+    connected: 0,
+    /* The last `LinkSend`, so rewriting the same word does not resend it. */
+    registered: null,
+    /* UART bytes received but not yet read from `SIODATA8`. */
+    uartReceived: [],
+};
+
+function attachLinkCable(network, send) {
+    LinkCable.network = network;
+    LinkCable.send = send;
+    LinkCable.registered = null;
+    LinkCable.uartReceived = [];
+
+    send(network.create_link_join_message());
+}
+
+function detachLinkCable() {
+    LinkCable.send = null;
+    LinkCable.player = 0;
+    LinkCable.connected = 0;
+}
+
+function isLinkPlugged(serial) {
+    return LinkCable.send !== null && (LinkCable.connected | 0) != 0 && (serial.RCNTMode | 0) < 2;
+}
+
+function hasLinkPartner() {
+    return (LinkCable.connected & ~(1 << LinkCable.player)) != 0;
+}
+
+/* The word `serial` shifts out in its current mode: `SIODATA8`, `SIODATA32` or `SIOMLT_SEND`. */
+function linkWord(serial) {
+    switch (serial.SIOCNT_MODE | 0) {
+        case 0:
+            return serial.SIODATA8 & 0xFF;
+        case 1:
+            return ((serial.SIODATA_B << 16) | serial.SIODATA_A) >>> 0;
+        default:
+            return serial.SIODATA8 & 0xFFFF;
+    }
+}
+
+/* Whether `serial` clocks transfers, i.e. is the multiplayer parent or uses the internal clock in normal mode. */
+function isLinkClock(serial) {
+    if ((serial.SIOCNT_MODE | 0) == 2) {
+        return (LinkCable.player | 0) == 0;
+    }
+    return (serial.SIOShiftClockExternal | 0) == 0;
+}
+
+/* Tells the server the word this player shifts out when another player clocks the next transfer. */
+function registerLinkWord(serial) {
+    if (!isLinkPlugged(serial) || (serial.SIOCNT_MODE | 0) == 3) {
+        return;
+    }
+
+    const mode = serial.SIOCNT_MODE | 0;
+    const word = linkWord(serial);
+    if (LinkCable.registered && LinkCable.registered.mode == mode && LinkCable.registered.word == word) {
+        return;
+    }
+
+    LinkCable.registered = { mode, word };
+    LinkCable.send(LinkCable.network.create_link_send_message(mode, word));
+}
+
+function completeLinkTransfer(serial) {
+    serial.SIOTransferStarted = false;
+    serial.serialBitsShifted = 0;
+    serial.shiftClocks = 0;
+    if ((serial.SIOCNT_IRQ | 0) != 0) {
+        serial.IOCore.irq.requestIRQ(0x80);
+    }
+}
+
+(function () {
+    const serial = GameBoyAdvanceSerial.prototype;
+
+    const addClocks = serial.addClocks;
+    serial.addClocks = function (clocks) {
+        if (isLinkPlugged(this) && (this.SIOCNT_MODE | 0) < 3) {
+            /* Transfers complete when the server's result arrives. */
+            return;
+        }
+        addClocks.call(this, clocks);
+    };
+
+    const writeSIOCNT0 = serial.writeSIOCNT0;
+    serial.writeSIOCNT0 = function (data) {
+        const wasStarted = this.SIOTransferStarted;
+        writeSIOCNT0.call(this, data);
+
+        if (!isLinkPlugged(this) || (this.SIOCNT_MODE | 0) == 3) {
+            return;
+        }
+        if ((this.SIOCNT_MODE | 0) == 2) {
+            /* The player number is read-only and assigned by the cable. */
+            this.SIOMULT_PLAYER_NUMBER = LinkCable.player | 0;
+        }
+        if (this.SIOTransferStarted && !wasStarted) {
+            if (isLinkClock(this)) {
+                LinkCable.send(LinkCable.network.create_link_start_message(this.SIOCNT_MODE | 0, linkWord(this)));
+            } else {
+                registerLinkWord(this);
+            }
+        }
+    };
+
+    const readSIOCNT0 = serial.readSIOCNT0;
+    serial.readSIOCNT0 = function () {
+        const value = readSIOCNT0.call(this) | 0;
+        if (!isLinkPlugged(this) || (this.SIOCNT_MODE | 0) != 2) {
+            return value;
+        }
+        /* SI is low for the parent and SD high once other players are connected. */
+        return (value & ~0x3C) | (LinkCable.player << 4) | (((LinkCable.player | 0) != 0) ? 0x4 : 0) |
+            (hasLinkPartner() ? 0x8 : 0);
+    };
+
+    for (const name of ["writeSIODATA_A0", "writeSIODATA_A1", "writeSIODATA_B0", "writeSIODATA_B1", "writeSIODATA8_1"]) {
+        const write = serial[name];
+        serial[name] = function (data) {
+            write.call(this, data);
+            registerLinkWord(this);
+        };
+    }
+
+    const writeSIODATA8_0 = serial.writeSIODATA8_0;
+    serial.writeSIODATA8_0 = function (data) {
+        writeSIODATA8_0.call(this, data);
+        if (isLinkPlugged(this) && (this.SIOCNT_MODE | 0) == 3) {
+            if (this.SIOCNT_UART_SEND_ENABLE) {
+                LinkCable.send(LinkCable.network.create_link_uart_message(Uint8Array.of(data & 0xFF)));
+            }
+        } else {
+            registerLinkWord(this);
+        }
+    };
+
+    const readSIODATA8_0 = serial.readSIODATA8_0;
+    serial.readSIODATA8_0 = function () {
+        if (isLinkPlugged(this) && (this.SIOCNT_MODE | 0) == 3 && LinkCable.uartReceived.length > 0) {
+            this.SIODATA8 = (this.SIODATA8 & 0xFF00) | LinkCable.uartReceived.shift();
+        }
+        return readSIODATA8_0.call(this);
+    };
+})();
+
+/* Applies a link message from the server, returning whether `message` was one. */
+function receiveLinkMessage(message) {
+    const iodine = IodineGUI.Iodine;
+    const serial = iodine.IOCore ? iodine.IOCore.serial : null;
+
+    if (message.is_link_status()) {
+        LinkCable.player = message.get_link_player();
+        LinkCable.connected = message.get_link_connected();
+        LinkCable.registered = null;
+    } else if (message.is_link_full()) {
+        console.warn("The link cable already connects four players.");
+    } else if (message.is_link_normal_result()) {
+        if (serial && (serial.SIOCNT_MODE | 0) == message.get_link_normal_mode()) {
+            const data = message.get_link_normal_data();
+            if ((serial.SIOCNT_MODE | 0) == 0) {
+                serial.SIODATA8 = (serial.SIODATA8 & 0xFF00) | (data & 0xFF);
+            } else {
+                serial.SIODATA_A = data & 0xFFFF;
+                serial.SIODATA_B = data >>> 16;
+            }
+            LinkCable.registered = null;
+            completeLinkTransfer(serial);
+        }
+    } else if (message.is_link_multiplayer_result()) {
+        if (serial && (serial.SIOCNT_MODE | 0) == 2) {
+            const data = message.get_link_multiplayer_data();
+            serial.SIODATA_A = data[0];
+            serial.SIODATA_B = data[1];
+            serial.SIODATA_C = data[2];
+            serial.SIODATA_D = data[3];
+            serial.SIOCOMMERROR = false;
+            completeLinkTransfer(serial);
+        }
+    } else if (message.is_link_uart()) {
+        const bytes = message.get_link_uart();
+        if (serial && (serial.SIOCNT_MODE | 0) == 3 && serial.SIOCNT_UART_RECV_ENABLE) {
+            LinkCable.uartReceived.push(...bytes);
+            if ((serial.SIOCNT_IRQ | 0) != 0) {
+                serial.IOCore.irq.requestIRQ(0x80);
+            }
+        }
+    } else {
+        return false;
+    }
+
+    return true;
+}
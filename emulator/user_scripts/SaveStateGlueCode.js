@@ -216,6 +216,7 @@ const uniqueStateKeys = [
     "IOCore.saves.EEPROMChip.buffer",
     "IOCore.saves.EEPROMChip.largestSizePossible",
     "IOCore.saves.EEPROMChip.mode",
+    "IOCore.saves.EEPROMChip.saves",
     "IOCore.saves.FLASHChip.BANKOffset",
     "IOCore.saves.FLASHChip.flashCommand",
     "IOCore.saves.FLASHChip.flashCommandUnlockStage",
@@ -272,118 +273,153 @@ const uniqueStateKeys = [
     "IOCore.serial.SIOTransferStarted",
     "IOCore.serialClocks",
     "IOCore.SKIPBoot",
-    // "IOCore.sound.AGBDirectSoundA",
-    // "IOCore.sound.AGBDirectSoundAFolded",
-    // "IOCore.sound.AGBDirectSoundALeftCanPlay",
-    // "IOCore.sound.AGBDirectSoundARightCanPlay",
-    // "IOCore.sound.AGBDirectSoundAShifter",
-    // "IOCore.sound.AGBDirectSoundATimer",
-    // "IOCore.sound.AGBDirectSoundB",
-    // "IOCore.sound.AGBDirectSoundBFolded",
-    // "IOCore.sound.AGBDirectSoundBLeftCanPlay",
-    // "IOCore.sound.AGBDirectSoundBRightCanPlay",
-    // "IOCore.sound.AGBDirectSoundBShifter",
-    // "IOCore.sound.AGBDirectSoundBTimer",
-    // "IOCore.sound.audioClocksUntilNextEvent",
-    // "IOCore.sound.audioClocksUntilNextEventCounter",
-    // "IOCore.sound.audioIndex",
-    // "IOCore.sound.audioResamplerFirstPassFactor",
-    // "IOCore.sound.audioTicks",
-    // "IOCore.sound.CGBMixerOutputCacheLeft",
-    // "IOCore.sound.CGBMixerOutputCacheLeftFolded",
-    // "IOCore.sound.CGBMixerOutputCacheRight",
-    // "IOCore.sound.CGBMixerOutputCacheRightFolded",
-    // "IOCore.sound.CGBOutputRatio",
-    // "IOCore.sound.channel1.CachedDuty",
-    // "IOCore.sound.channel1.canPlay",
-    // "IOCore.sound.channel1.consecutive",
-    // "IOCore.sound.channel1.currentSampleLeft",
-    // "IOCore.sound.channel1.currentSampleRight",
-    // "IOCore.sound.channel1.decreaseSweep",
-    // "IOCore.sound.channel1.DutyTracker",
-    // "IOCore.sound.channel1.Enabled",
-    // "IOCore.sound.channel1.envelopeSweeps",
-    // "IOCore.sound.channel1.envelopeSweepsLast",
-    // "IOCore.sound.channel1.envelopeVolume",
-    // "IOCore.sound.channel1.frequency",
-    // "IOCore.sound.channel1.FrequencyCounter",
-    // "IOCore.sound.channel1.frequencySweepDivider",
-    // "IOCore.sound.channel1.FrequencyTracker",
-    // "IOCore.sound.channel1.lastTimeSweep",
-    // "IOCore.sound.channel1.leftEnable",
-    // "IOCore.sound.channel1.nr10",
-    // "IOCore.sound.channel1.nr11",
-    // "IOCore.sound.channel1.nr12",
-    // "IOCore.sound.channel1.nr14",
-    // "IOCore.sound.channel1.rightEnable",
-    // "IOCore.sound.channel1.ShadowFrequency",
-    // "IOCore.sound.channel1.SweepFault",
-    // "IOCore.sound.channel1.Swept",
-    // "IOCore.sound.channel1.timeSweep",
-    // "IOCore.sound.channel1.totalLength",
-    // "IOCore.sound.channel2.nr21",
-    // "IOCore.sound.channel2.nr22",
-    // "IOCore.sound.channel2.nr23",
-    // "IOCore.sound.channel2.nr24",
-    // "IOCore.sound.channel2.ShadowFrequency",
-    // "IOCore.sound.channel3.cachedSample",
-    // "IOCore.sound.channel3.canPlay",
-    // "IOCore.sound.channel3.counter",
-    // "IOCore.sound.channel3.FrequencyPeriod",
-    // "IOCore.sound.channel3.lastSampleLookup",
-    // "IOCore.sound.channel3.nr30",
-    // "IOCore.sound.channel3.nr31",
-    // "IOCore.sound.channel3.nr32",
-    // "IOCore.sound.channel3.nr33",
-    // "IOCore.sound.channel3.nr34",
-    // "IOCore.sound.channel3.patternType",
-    // "IOCore.sound.channel3.PCM",
-    // "IOCore.sound.channel3.PCM16",
-    // "IOCore.sound.channel3.PCM32",
-    // "IOCore.sound.channel3.WAVERAM16",
-    // "IOCore.sound.channel3.WAVERAM32",
-    // "IOCore.sound.channel3.WAVERAM8",
-    // "IOCore.sound.channel3.WAVERAMBankAccessed",
-    // "IOCore.sound.channel3.WaveRAMBankSize",
-    // "IOCore.sound.channel3.WAVERAMBankSpecified",
-    // "IOCore.sound.channel4.BitRange",
-    // "IOCore.sound.channel4.counter",
-    // "IOCore.sound.channel4.currentVolume",
-    // "IOCore.sound.channel4.FrequencyPeriod",
-    // "IOCore.sound.channel4.lastSampleLookup",
-    // "IOCore.sound.channel4.LSFR15Table",
-    // "IOCore.sound.channel4.LSFR7Table",
-    // "IOCore.sound.channel4.noiseSampleTable",
-    // "IOCore.sound.channel4.nr42",
-    // "IOCore.sound.channel4.nr43",
-    // "IOCore.sound.channel4.nr44",
-    // "IOCore.sound.channel4.VolumeShifter",
-    // "IOCore.sound.downsampleInputLeft",
-    // "IOCore.sound.downsampleInputRight",
-    // "IOCore.sound.FIFOABuffer.buffer",
-    // "IOCore.sound.FIFOABuffer.count",
-    // "IOCore.sound.FIFOABuffer.position",
-    // "IOCore.sound.FIFOBBuffer.buffer",
-    // "IOCore.sound.mixerOutputCacheLeft",
-    // "IOCore.sound.mixerOutputCacheRight",
-    // "IOCore.sound.mixerSoundBIAS",
-    // "IOCore.sound.nr50",
-    // "IOCore.sound.nr51",
-    // "IOCore.sound.nr52",
-    // "IOCore.sound.nr60",
-    // "IOCore.sound.nr61",
-    // "IOCore.sound.nr62",
-    // "IOCore.sound.nr63",
-    // "IOCore.sound.PWMBitDepthMask",
-    // "IOCore.sound.PWMBitDepthMaskShadow",
-    // "IOCore.sound.PWMWidth",
-    // "IOCore.sound.PWMWidthOld",
-    // "IOCore.sound.PWMWidthShadow",
-    // "IOCore.sound.sequencePosition",
-    // "IOCore.sound.sequencerClocks",
-    // "IOCore.sound.soundMasterEnabled",
-    // "IOCore.sound.VinLeftChannelMasterVolume",
-    // "IOCore.sound.VinRightChannelMasterVolume",
+    "IOCore.sound.AGBDirectSoundA",
+    "IOCore.sound.AGBDirectSoundAFolded",
+    "IOCore.sound.AGBDirectSoundALeftCanPlay",
+    "IOCore.sound.AGBDirectSoundARightCanPlay",
+    "IOCore.sound.AGBDirectSoundAShifter",
+    "IOCore.sound.AGBDirectSoundATimer",
+    "IOCore.sound.AGBDirectSoundB",
+    "IOCore.sound.AGBDirectSoundBFolded",
+    "IOCore.sound.AGBDirectSoundBLeftCanPlay",
+    "IOCore.sound.AGBDirectSoundBRightCanPlay",
+    "IOCore.sound.AGBDirectSoundBShifter",
+    "IOCore.sound.AGBDirectSoundBTimer",
+    "IOCore.sound.audioClocksUntilNextEvent",
+    "IOCore.sound.audioClocksUntilNextEventCounter",
+    "IOCore.sound.audioIndex",
+    "IOCore.sound.audioResamplerFirstPassFactor",
+    "IOCore.sound.audioTicks",
+    "IOCore.sound.CGBMixerOutputCacheLeft",
+    "IOCore.sound.CGBMixerOutputCacheLeftFolded",
+    "IOCore.sound.CGBMixerOutputCacheRight",
+    "IOCore.sound.CGBMixerOutputCacheRightFolded",
+    "IOCore.sound.CGBOutputRatio",
+    "IOCore.sound.channel1.CachedDuty",
+    "IOCore.sound.channel1.canPlay",
+    "IOCore.sound.channel1.consecutive",
+    "IOCore.sound.channel1.currentSampleLeft",
+    "IOCore.sound.channel1.currentSampleRight",
+    "IOCore.sound.channel1.decreaseSweep",
+    "IOCore.sound.channel1.DutyTracker",
+    "IOCore.sound.channel1.Enabled",
+    "IOCore.sound.channel1.envelopeSweeps",
+    "IOCore.sound.channel1.envelopeSweepsLast",
+    "IOCore.sound.channel1.envelopeVolume",
+    "IOCore.sound.channel1.frequency",
+    "IOCore.sound.channel1.FrequencyCounter",
+    "IOCore.sound.channel1.frequencySweepDivider",
+    "IOCore.sound.channel1.FrequencyTracker",
+    "IOCore.sound.channel1.lastTimeSweep",
+    "IOCore.sound.channel1.leftEnable",
+    "IOCore.sound.channel1.nr10",
+    "IOCore.sound.channel1.nr11",
+    "IOCore.sound.channel1.nr12",
+    "IOCore.sound.channel1.nr14",
+    "IOCore.sound.channel1.rightEnable",
+    "IOCore.sound.channel1.ShadowFrequency",
+    "IOCore.sound.channel1.SweepFault",
+    "IOCore.sound.channel1.Swept",
+    "IOCore.sound.channel1.timeSweep",
+    "IOCore.sound.channel1.totalLength",
+    "IOCore.sound.channel2.CachedDuty",
+    "IOCore.sound.channel2.canPlay",
+    "IOCore.sound.channel2.consecutive",
+    "IOCore.sound.channel2.currentSampleLeft",
+    "IOCore.sound.channel2.currentSampleRight",
+    "IOCore.sound.channel2.DutyTracker",
+    "IOCore.sound.channel2.Enabled",
+    "IOCore.sound.channel2.envelopeSweeps",
+    "IOCore.sound.channel2.envelopeSweepsLast",
+    "IOCore.sound.channel2.envelopeVolume",
+    "IOCore.sound.channel2.frequency",
+    "IOCore.sound.channel2.FrequencyCounter",
+    "IOCore.sound.channel2.FrequencyTracker",
+    "IOCore.sound.channel2.leftEnable",
+    "IOCore.sound.channel2.nr21",
+    "IOCore.sound.channel2.nr22",
+    "IOCore.sound.channel2.nr23",
+    "IOCore.sound.channel2.nr24",
+    "IOCore.sound.channel2.rightEnable",
+    "IOCore.sound.channel2.ShadowFrequency",
+    "IOCore.sound.channel2.totalLength",
+    "IOCore.sound.channel3.cachedSample",
+    "IOCore.sound.channel3.canPlay",
+    "IOCore.sound.channel3.consecutive",
+    "IOCore.sound.channel3.counter",
+    "IOCore.sound.channel3.currentSampleLeft",
+    "IOCore.sound.channel3.currentSampleRight",
+    "IOCore.sound.channel3.Enabled",
+    "IOCore.sound.channel3.frequency",
+    "IOCore.sound.channel3.FrequencyPeriod",
+    "IOCore.sound.channel3.lastSampleLookup",
+    "IOCore.sound.channel3.leftEnable",
+    "IOCore.sound.channel3.nr30",
+    "IOCore.sound.channel3.nr31",
+    "IOCore.sound.channel3.nr32",
+    "IOCore.sound.channel3.nr33",
+    "IOCore.sound.channel3.nr34",
+    "IOCore.sound.channel3.patternType",
+    "IOCore.sound.channel3.PCM",
+    "IOCore.sound.channel3.PCM16",
+    "IOCore.sound.channel3.PCM32",
+    "IOCore.sound.channel3.rightEnable",
+    "IOCore.sound.channel3.totalLength",
+    "IOCore.sound.channel3.WAVERAM16",
+    "IOCore.sound.channel3.WAVERAM32",
+    "IOCore.sound.channel3.WAVERAM8",
+    "IOCore.sound.channel3.WAVERAMBankAccessed",
+    "IOCore.sound.channel3.WaveRAMBankSize",
+    "IOCore.sound.channel3.WAVERAMBankSpecified",
+    "IOCore.sound.channel4.BitRange",
+    "IOCore.sound.channel4.cachedSample",
+    "IOCore.sound.channel4.canPlay",
+    "IOCore.sound.channel4.consecutive",
+    "IOCore.sound.channel4.counter",
+    "IOCore.sound.channel4.currentSampleLeft",
+    "IOCore.sound.channel4.currentSampleRight",
+    "IOCore.sound.channel4.currentVolume",
+    "IOCore.sound.channel4.Enabled",
+    "IOCore.sound.channel4.envelopeSweeps",
+    "IOCore.sound.channel4.envelopeSweepsLast",
+    "IOCore.sound.channel4.envelopeVolume",
+    "IOCore.sound.channel4.FrequencyPeriod",
+    "IOCore.sound.channel4.lastSampleLookup",
+    "IOCore.sound.channel4.leftEnable",
+    "IOCore.sound.channel4.nr42",
+    "IOCore.sound.channel4.nr43",
+    "IOCore.sound.channel4.nr44",
+    "IOCore.sound.channel4.rightEnable",
+    "IOCore.sound.channel4.totalLength",
+    "IOCore.sound.channel4.VolumeShifter",
+    "IOCore.sound.downsampleInputLeft",
+    "IOCore.sound.downsampleInputRight",
+    "IOCore.sound.FIFOABuffer.buffer",
+    "IOCore.sound.FIFOABuffer.count",
+    "IOCore.sound.FIFOABuffer.position",
+    "IOCore.sound.FIFOBBuffer.buffer",
+    "IOCore.sound.FIFOBBuffer.count",
+    "IOCore.sound.FIFOBBuffer.position",
+    "IOCore.sound.mixerOutputCacheLeft",
+    "IOCore.sound.mixerOutputCacheRight",
+    "IOCore.sound.mixerSoundBIAS",
+    "IOCore.sound.nr50",
+    "IOCore.sound.nr51",
+    "IOCore.sound.nr52",
+    "IOCore.sound.nr60",
+    "IOCore.sound.nr61",
+    "IOCore.sound.nr62",
+    "IOCore.sound.nr63",
+    "IOCore.sound.PWMBitDepthMask",
+    "IOCore.sound.PWMBitDepthMaskShadow",
+    "IOCore.sound.PWMWidth",
+    "IOCore.sound.PWMWidthOld",
+    "IOCore.sound.PWMWidthShadow",
+    "IOCore.sound.sequencePosition",
+    "IOCore.sound.sequencerClocks",
+    "IOCore.sound.soundMasterEnabled",
+    "IOCore.sound.VinLeftChannelMasterVolume",
+    "IOCore.sound.VinRightChannelMasterVolume",
     "IOCore.systemStatus",
     "IOCore.THUMB.decode",
     "IOCore.THUMB.execute",
@@ -548,21 +584,22 @@ const unchangeStateKeys = [
     // "IOCore.saves.EEPROMChip.buffer",
     // "IOCore.saves.EEPROMChip.largestSizePossible",
     // "IOCore.saves.EEPROMChip.mode",
-    "IOCore.saves.FLASHChip.BANKOffset",
-    "IOCore.saves.FLASHChip.flashCommand",
-    "IOCore.saves.FLASHChip.flashCommandUnlockStage",
-    "IOCore.saves.FLASHChip.largestSizePossible",
-    "IOCore.saves.FLASHChip.notATMEL",
-    "IOCore.saves.FLASHChip.saves",
-    "IOCore.saves.FLASHChip.writeBytesLeft",
+    // "IOCore.saves.EEPROMChip.saves",
+    // "IOCore.saves.FLASHChip.BANKOffset",
+    // "IOCore.saves.FLASHChip.flashCommand",
+    // "IOCore.saves.FLASHChip.flashCommandUnlockStage",
+    // "IOCore.saves.FLASHChip.largestSizePossible",
+    // "IOCore.saves.FLASHChip.notATMEL",
+    // "IOCore.saves.FLASHChip.saves",
+    // "IOCore.saves.FLASHChip.writeBytesLeft",
     // "IOCore.saves.GPIOChip.data",
     // "IOCore.saves.GPIOChip.direction",
     // "IOCore.saves.GPIOChip.readWrite",
     // "IOCore.saves.GPIOChip.type",
     // "IOCore.saves.saveType",
-    "IOCore.saves.SRAMChip.saves",
+    // "IOCore.saves.SRAMChip.saves",
     "IOCore.saves.SRAMChip.TILTChip",
-    "IOCore.saves.SRAMChip.TILTChipUnlocked",
+    // "IOCore.saves.SRAMChip.TILTChipUnlocked",
     // "IOCore.saves.UNDETERMINED.possible",
     // "IOCore.serial.JOYBUS_CNTL_FLAGS",
     // "IOCore.serial.JOYBUS_IRQ",
@@ -729,6 +766,14 @@ const unchangeStateKeys = [
 let saveStateKeys = uniqueStateKeys
     .filter(key => !unchangeStateKeys.includes(key));
 
+/* Save chip buffers whose size follows the cartridge, e.g. flash can grow to 128K. */
+const resizableStateKeys = [
+    "IOCore.saves.EEPROMChip.buffer",
+    "IOCore.saves.EEPROMChip.saves",
+    "IOCore.saves.FLASHChip.saves",
+    "IOCore.saves.SRAMChip.saves",
+];
+
 function withEmulatorPaused(fn) {
     const iodine = IodineGUI.Iodine;
 
@@ -741,7 +786,7 @@ function withEmulatorPaused(fn) {
 }
 
 function fastLoad(fastSaveState) {
-    function write(object, key, value) {
+    function write(object, key, value, resizable) {
         const valueType = typeof value;
         switch (valueType) {
             case "object":
@@ -753,8 +798,14 @@ function fastLoad(fastSaveState) {
                         object[key][i] = value[i];
                     }
                 } else if (ArrayBuffer.isView(value)) {
-                    for (let i = 0; i < value.length; i++) {
-                        object[key][i] = value[i];
+                    if (value.length == 0) {
+                        /* Migrated from a format without this buffer, keep the emulator's own. */
+                    } else if (object[key] == null || (resizable && object[key].length != value.length)) {
+                        /* Save chips allocate their buffer lazily. */
+                        object[key] = value.slice();
+                    } else {
+                        /* Copy in place, so views aliasing the same buffer keep seeing it. */
+                        object[key].set(value);
                     }
                 } else {
                     for (let i of value) {
@@ -772,6 +823,9 @@ function fastLoad(fastSaveState) {
                 break;
             case "function":
                 break;
+            case "undefined":
+                /* Not part of this state, keep the emulator's own value. */
+                break;
             default:
                 throw new Error(`Unhandled type: '${valueType}' - key: '${key}' - object: ${object}`);
         }
@@ -787,12 +841,43 @@ function fastLoad(fastSaveState) {
                 object = object[part];
             }
 
-            write(object, lastPart, fastSaveState[key]);
+            write(object, lastPart, fastSaveState[key], resizableStateKeys.includes(key));
         }
+
+        restoreDerivedSoundState(iodine.IOCore.sound);
     }
     );
 }
 
+/** Writes the patches of the session's `CheatEngine`, like the devices do on every frame. */
+function applyCheats() {
+    const saveStates = IodineGUI.Iodine.SaveStates;
+    if (!saveStates || !saveStates.cheats || !saveStates.cheats.is_active()) {
+        return;
+    }
+
+    const memory = IodineGUI.Iodine.IOCore.memory;
+    const patches = saveStates.cheats.patches(memory.externalRAM, memory.internalRAM);
+    for (let i = 0; i < patches.length; i += 2) {
+        const address = patches[i];
+        if ((address >>> 24) == 0x02) {
+            memory.externalRAM[address & 0x3FFFF] = patches[i + 1];
+        } else {
+            memory.internalRAM[address & 0x7FFF] = patches[i + 1];
+        }
+    }
+}
+
+/** Sound state that is not saved because it aliases a constant table or mirrors a register. */
+function restoreDerivedSoundState(sound) {
+    const channel4 = sound.channel4;
+    channel4.noiseSampleTable = (channel4.BitRange == 0x7F) ? channel4.LSFR7Table : channel4.LSFR15Table;
+
+    sound.channel1.envelopeType = (sound.channel1.nr12 & 0x08) != 0;
+    sound.channel2.envelopeType = (sound.channel2.nr22 & 0x08) != 0;
+    channel4.envelopeType = (channel4.nr42 & 0x08) != 0;
+}
+
 function fastSave() {
     function read(value, key, state) {
         const valueType = typeof value;
@@ -845,3 +930,9 @@ function exportSaveState(blob) {
     return IodineGUI.Iodine.SaveStates.snapshotter.serialize_to_b64(blob);
 }
 
+
+/** Sends the active cheats to every other player of the session. */
+function shareCheats() {
+    const saveStates = IodineGUI.Iodine.SaveStates;
+    saveStates.websocket.send(saveStates.network.create_cheats_message(saveStates.cheats.to_bytes()));
+}
@@ -1,4 +1,4 @@
-use ::network::{DeltaSnapshot, Message};
+use ::network::{DeltaSnapshot, IceCandidate, Message, PeerId, SioWord};
 
 use std::sync::Once;
 
@@ -24,10 +24,7 @@ pub struct MessageWrapper(Message);
 #[wasm_bindgen]
 impl MessageWrapper {
     pub fn is_bios(&self) -> bool {
-        match self.0 {
-            Message::Bios(_) => true,
-            _ => false,
-        }
+        matches!(self.0, Message::Bios(_))
     }
 
     pub fn get_bios(self) -> js_sys::Uint8Array {
@@ -38,10 +35,7 @@ impl MessageWrapper {
     }
 
     pub fn is_rom(&self) -> bool {
-        match self.0 {
-            Message::Rom(_) => true,
-            _ => false,
-        }
+        matches!(self.0, Message::Rom(_))
     }
 
     pub fn get_rom(self) -> js_sys::Uint8Array {
@@ -52,10 +46,7 @@ impl MessageWrapper {
     }
 
     pub fn is_play(&self) -> bool {
-        match self.0 {
-            Message::Play(_) => true,
-            _ => false,
-        }
+        matches!(self.0, Message::Play(_))
     }
 
     pub fn get_play(self) -> js_sys::Uint8Array  {
@@ -66,9 +57,14 @@ impl MessageWrapper {
     }
 
     pub fn is_delta_snapshot(&self) -> bool {
-        match self.0 {
-            Message::DeltaSnapshot(_) => true,
-            _ => false,
+        matches!(self.0, Message::DeltaSnapshot(_))
+    }
+
+    /// Whether `get_delta_snapshot` can be called with `old_array`. If not, ask for a full state with `create_snapshot_request_message`.
+    pub fn can_apply_delta_snapshot(&self, old_array: &[u8]) -> bool {
+        match &self.0 {
+            Message::DeltaSnapshot(delta_snapshot) => delta_snapshot.can_apply(old_array),
+            _ => unreachable!("Call `is_delta_snapshot` first."),
         }
     }
 
@@ -80,10 +76,7 @@ impl MessageWrapper {
     }
 
     pub fn is_snapshot(&self) -> bool {
-        match self.0 {
-            Message::Snapshot(_) => true,
-            _ => false,
-        }
+        matches!(self.0, Message::Snapshot(_))
     }
 
     pub fn get_snapshot(self) -> js_sys::Uint8Array  {
@@ -92,9 +85,167 @@ impl MessageWrapper {
             _ => unreachable!("Call `is_snapshot` first."),
         }
     }
+
+    pub fn is_rtc_peers(&self) -> bool {
+        matches!(self.0, Message::RtcPeers { .. })
+    }
+
+    /// The id the server assigned to this connection.
+    pub fn get_rtc_you(&self) -> u32 {
+        match self.0 {
+            Message::RtcPeers { you, .. } => you as u32,
+            _ => unreachable!("Call `is_rtc_peers` first."),
+        }
+    }
+
+    pub fn get_rtc_peers(&self) -> Vec<u32> {
+        match &self.0 {
+            Message::RtcPeers { peers, .. } => peers.iter().map(|peer| *peer as u32).collect(),
+            _ => unreachable!("Call `is_rtc_peers` first."),
+        }
+    }
+
+    pub fn is_rtc_peer_joined(&self) -> bool {
+        matches!(self.0, Message::RtcPeerJoined(_))
+    }
+
+    pub fn is_rtc_peer_left(&self) -> bool {
+        matches!(self.0, Message::RtcPeerLeft(_))
+    }
+
+    pub fn is_rtc_offer(&self) -> bool {
+        matches!(self.0, Message::RtcOffer { .. })
+    }
+
+    pub fn is_rtc_answer(&self) -> bool {
+        matches!(self.0, Message::RtcAnswer { .. })
+    }
+
+    pub fn is_rtc_ice_candidate(&self) -> bool {
+        matches!(self.0, Message::RtcIceCandidate { .. })
+    }
+
+    /// The peer that joined, left, or sent the offer, answer or ICE candidate.
+    pub fn get_rtc_peer(&self) -> u32 {
+        match self.0 {
+            Message::RtcPeerJoined(peer)
+            | Message::RtcPeerLeft(peer)
+            | Message::RtcOffer { peer, .. }
+            | Message::RtcAnswer { peer, .. }
+            | Message::RtcIceCandidate { peer, .. } => peer as u32,
+            _ => unreachable!("Call an `is_rtc_*` that carries a peer first."),
+        }
+    }
+
+    pub fn get_rtc_sdp(self) -> String {
+        match self.0 {
+            Message::RtcOffer { sdp, .. } | Message::RtcAnswer { sdp, .. } => sdp,
+            _ => unreachable!("Call `is_rtc_offer` or `is_rtc_answer` first."),
+        }
+    }
+
+    pub fn get_rtc_candidate(&self) -> String {
+        match &self.0 {
+            Message::RtcIceCandidate { candidate, .. } => candidate.candidate.clone(),
+            _ => unreachable!("Call `is_rtc_ice_candidate` first."),
+        }
+    }
+
+    pub fn get_rtc_sdp_mid(&self) -> Option<String> {
+        match &self.0 {
+            Message::RtcIceCandidate { candidate, .. } => candidate.sdp_mid.clone(),
+            _ => unreachable!("Call `is_rtc_ice_candidate` first."),
+        }
+    }
+
+    pub fn get_rtc_sdp_m_line_index(&self) -> Option<u16> {
+        match &self.0 {
+            Message::RtcIceCandidate { candidate, .. } => candidate.sdp_m_line_index,
+            _ => unreachable!("Call `is_rtc_ice_candidate` first."),
+        }
+    }
+
+    pub fn is_link_status(&self) -> bool {
+        matches!(self.0, Message::LinkStatus { .. })
+    }
+
+    /// This emulator's `SIOMULT_PLAYER_NUMBER`.
+    pub fn get_link_player(&self) -> u8 {
+        match self.0 {
+            Message::LinkStatus { player, .. } => player,
+            _ => unreachable!("Call `is_link_status` first."),
+        }
+    }
+
+    /// Bitmask of the occupied player numbers.
+    pub fn get_link_connected(&self) -> u8 {
+        match self.0 {
+            Message::LinkStatus { connected, .. } => connected,
+            _ => unreachable!("Call `is_link_status` first."),
+        }
+    }
+
+    pub fn is_link_full(&self) -> bool {
+        matches!(self.0, Message::LinkFull)
+    }
+
+    pub fn is_link_normal_result(&self) -> bool {
+        matches!(self.0, Message::LinkNormalResult(_))
+    }
+
+    /// `SIOCNT` mode bits of the normal transfer, 0 for 8-bit and 1 for 32-bit.
+    pub fn get_link_normal_mode(&self) -> u8 {
+        match self.0 {
+            Message::LinkNormalResult(word) => word.mode(),
+            _ => unreachable!("Call `is_link_normal_result` first."),
+        }
+    }
+
+    pub fn get_link_normal_data(&self) -> u32 {
+        match self.0 {
+            Message::LinkNormalResult(word) => word.data(),
+            _ => unreachable!("Call `is_link_normal_result` first."),
+        }
+    }
+
+    pub fn is_link_multiplayer_result(&self) -> bool {
+        matches!(self.0, Message::LinkMultiplayerResult(_))
+    }
+
+    /// `SIOMULTI0`-`SIOMULTI3`.
+    pub fn get_link_multiplayer_data(&self) -> js_sys::Uint16Array {
+        match self.0 {
+            Message::LinkMultiplayerResult(data) => js_sys::Uint16Array::from(data.as_ref()),
+            _ => unreachable!("Call `is_link_multiplayer_result` first."),
+        }
+    }
+
+    pub fn is_link_uart(&self) -> bool {
+        matches!(self.0, Message::LinkUart(_))
+    }
+
+    pub fn get_link_uart(self) -> js_sys::Uint8Array {
+        match self.0 {
+            Message::LinkUart(bytes) => js_sys::Uint8Array::from(bytes.as_ref()),
+            _ => unreachable!("Call `is_link_uart` first."),
+        }
+    }
+
+    pub fn is_cheats(&self) -> bool {
+        matches!(self.0, Message::Cheats(_))
+    }
+
+    /// Bytes for `CheatEngine.from_bytes`.
+    pub fn get_cheats(self) -> js_sys::Uint8Array {
+        match self.0 {
+            Message::Cheats(cheats) => js_sys::Uint8Array::from(cheats.as_ref()),
+            _ => unreachable!("Call `is_cheats` first."),
+        }
+    }
 }
 
 #[wasm_bindgen]
+#[derive(Default)]
 pub struct Network;
 
 #[wasm_bindgen]
@@ -132,4 +283,77 @@ impl Network {
         let message = Message::Snapshot(Vec::from(snapshot));
         (&message).try_into().unwrap()
     }
+
+    pub fn create_snapshot_request_message(&self) -> String {
+        let message = Message::SnapshotRequest;
+        (&message).try_into().unwrap()
+    }
+
+    pub fn create_rtc_join_message(&self) -> String {
+        let message = Message::RtcJoin;
+        (&message).try_into().unwrap()
+    }
+
+    pub fn create_rtc_offer_message(&self, peer: u32, sdp: String) -> String {
+        let message = Message::RtcOffer { peer: peer as PeerId, sdp };
+        (&message).try_into().unwrap()
+    }
+
+    pub fn create_rtc_answer_message(&self, peer: u32, sdp: String) -> String {
+        let message = Message::RtcAnswer { peer: peer as PeerId, sdp };
+        (&message).try_into().unwrap()
+    }
+
+    pub fn create_rtc_ice_candidate_message(
+        &self,
+        peer: u32,
+        candidate: String,
+        sdp_mid: Option<String>,
+        sdp_m_line_index: Option<u16>,
+    ) -> String {
+        let message = Message::RtcIceCandidate {
+            peer: peer as PeerId,
+            candidate: IceCandidate {
+                candidate,
+                sdp_mid,
+                sdp_m_line_index,
+            },
+        };
+        (&message).try_into().unwrap()
+    }
+
+    pub fn create_link_join_message(&self) -> String {
+        let message = Message::LinkJoin;
+        (&message).try_into().unwrap()
+    }
+
+    /// `mode` is `SIOCNT`'s mode bits: 0 for 8-bit, 1 for 32-bit and 2 for multiplayer.
+    /// Throws for UART, which has no words, use `create_link_uart_message`.
+    pub fn create_link_send_message(&self, mode: u8, data: u32) -> Result<String, JsValue> {
+        let message = Message::LinkSend(sio_word(mode, data)?);
+        Ok((&message).try_into().unwrap())
+    }
+
+    /// `mode` is `SIOCNT`'s mode bits: 0 for 8-bit, 1 for 32-bit and 2 for multiplayer.
+    /// Throws for UART, which has no words, use `create_link_uart_message`.
+    pub fn create_link_start_message(&self, mode: u8, data: u32) -> Result<String, JsValue> {
+        let message = Message::LinkStart(sio_word(mode, data)?);
+        Ok((&message).try_into().unwrap())
+    }
+
+    pub fn create_link_uart_message(&self, bytes: &[u8]) -> String {
+        let message = Message::LinkUart(Vec::from(bytes));
+        (&message).try_into().unwrap()
+    }
+
+    /// `cheats` from `CheatEngine.to_bytes`.
+    pub fn create_cheats_message(&self, cheats: &[u8]) -> String {
+        let message = Message::Cheats(Vec::from(cheats));
+        (&message).try_into().unwrap()
+    }
+}
+
+fn sio_word(mode: u8, data: u32) -> Result<SioWord, JsValue> {
+    SioWord::from_mode(mode, data)
+        .ok_or_else(|| js_sys::Error::new(&format!("SIO mode {} has no words.", mode)).into())
 }
@@ -19,6 +19,14 @@ impl EncodingError {
             stack: Backtrace::new(),
         }
     }
+
+    pub fn kind(&self) -> &EncodingErrorKind {
+        &self.kind
+    }
+
+    pub fn stack(&self) -> &Backtrace {
+        &self.stack
+    }
 }
 
 impl From<::bincode::Error> for EncodingError {
@@ -66,11 +74,15 @@ pub struct DeltaSnapshot {
 }
 
 impl DeltaSnapshot {
+    fn hash(snapshot: &[u8]) -> [u8; 2] {
+        [
+            snapshot.first().copied().unwrap_or(0),
+            snapshot.last().copied().unwrap_or(0),
+        ]
+    }
+
     pub fn new(old_snapshot: &[u8], new_snapshot: &[u8]) -> DeltaSnapshot {
-        let old_hash = [
-            old_snapshot.first().map(|v| *v).unwrap_or(0),
-            old_snapshot.last().map(|v| *v).unwrap_or(0),
-        ];
+        let old_hash = DeltaSnapshot::hash(old_snapshot);
 
         assert_eq!(old_snapshot.len(), new_snapshot.len());
 
@@ -94,16 +106,27 @@ impl DeltaSnapshot {
         DeltaSnapshot { old_hash, chunks }
     }
 
-    pub fn apply(self, old_snapshot: &[u8]) -> Vec<u8> {
-        let old_hash = [
-            old_snapshot.first().map(|v| *v).unwrap_or(0),
-            old_snapshot.last().map(|v| *v).unwrap_or(0),
-        ];
+    /// Whether `old_snapshot` looks like the snapshot this delta was created against.
+    pub fn can_apply(&self, old_snapshot: &[u8]) -> bool {
+        self.old_hash == DeltaSnapshot::hash(old_snapshot)
+    }
 
-        if self.old_hash != old_hash {
+    pub fn apply(self, old_snapshot: &[u8]) -> Vec<u8> {
+        if !self.can_apply(old_snapshot) {
             panic!("Hashes did not match!");
         }
 
+        self.try_apply(old_snapshot)
+            .expect("Delta does not fit the snapshot!")
+    }
+
+    /// Like `apply`, but returns `None` instead of panicking when the hashes do not match or a change lies outside
+    /// `old_snapshot`, e.g. for deltas received from untrusted peers.
+    pub fn try_apply(self, old_snapshot: &[u8]) -> Option<Vec<u8>> {
+        if !self.can_apply(old_snapshot) {
+            return None;
+        }
+
         let mut new_snapshot = Vec::from(old_snapshot);
 
         for (chunk_index, chunk) in self.chunks.into_iter() {
@@ -111,20 +134,86 @@ impl DeltaSnapshot {
                 .into_iter()
                 .map(|delta| (delta.offset(), delta.value()))
             {
-                let index = ((chunk_index as usize) * (u8::MAX as usize)) + (offset_index as usize);
+                let index = (chunk_index as usize)
+                    .checked_mul(u8::MAX as usize)?
+                    .checked_add(offset_index as usize)?;
 
-                new_snapshot[index] = new_value;
+                *new_snapshot.get_mut(index)? = new_value;
             }
         }
 
-        new_snapshot
+        Some(new_snapshot)
     }
 
     pub fn len(&self) -> usize {
-        self.chunks.iter().map(|(_, chunk)| chunk.len()).sum()
+        self.chunks.values().map(|chunk| chunk.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.values().all(|chunk| chunk.is_empty())
     }
 }
 
+/// Identifies a websocket connection, assigned by the server.
+pub type PeerId = usize;
+
+/// Mirrors the fields of a browser `RTCIceCandidate` needed to add it on the other peer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IceCandidate {
+    pub candidate: String,
+    pub sdp_mid: Option<String>,
+    pub sdp_m_line_index: Option<u16>,
+}
+
+/// A word shifted over the link cable, tagged with the SIO mode it was written in.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SioWord {
+    /// Normal mode, 8-bit (`SIODATA8`).
+    Normal8(u8),
+    /// Normal mode, 32-bit (`SIODATA32`).
+    Normal32(u32),
+    /// Multiplayer mode (`SIOMLT_SEND`).
+    Multiplayer(u16),
+}
+
+impl SioWord {
+    /// Builds a word from `SIOCNT`'s mode bits (0 = 8-bit, 1 = 32-bit, 2 = multiplayer). UART has no words.
+    pub fn from_mode(mode: u8, data: u32) -> Option<SioWord> {
+        match mode {
+            0 => Some(SioWord::Normal8(data as u8)),
+            1 => Some(SioWord::Normal32(data)),
+            2 => Some(SioWord::Multiplayer(data as u16)),
+            _ => None,
+        }
+    }
+
+    /// What a port reads when nothing drives the line, i.e. all ones in the same mode.
+    pub fn disconnected(&self) -> SioWord {
+        match self {
+            SioWord::Normal8(_) => SioWord::Normal8(u8::MAX),
+            SioWord::Normal32(_) => SioWord::Normal32(u32::MAX),
+            SioWord::Multiplayer(_) => SioWord::Multiplayer(u16::MAX),
+        }
+    }
+
+    pub fn mode(&self) -> u8 {
+        match self {
+            SioWord::Normal8(_) => 0,
+            SioWord::Normal32(_) => 1,
+            SioWord::Multiplayer(_) => 2,
+        }
+    }
+
+    pub fn data(&self) -> u32 {
+        match self {
+            SioWord::Normal8(data) => *data as u32,
+            SioWord::Normal32(data) => *data,
+            SioWord::Multiplayer(data) => *data as u32,
+        }
+    }
+}
+
+/// New variants must be appended so that the bincode indices of the existing ones do not change.
 #[derive(Serialize, Deserialize, Debug)]
 pub enum Message {
     Bios(Vec<u8>),
@@ -132,6 +221,41 @@ pub enum Message {
     Play(Vec<u8>),
     DeltaSnapshot(DeltaSnapshot),
     Snapshot(Vec<u8>),
+    /// Opts the connection into WebRTC signaling. The server answers with `RtcPeers`.
+    RtcJoin,
+    /// The connection's own id and the ids of every other peer that has sent `RtcJoin`.
+    RtcPeers { you: PeerId, peers: Vec<PeerId> },
+    RtcPeerJoined(PeerId),
+    /// Sent when a signaling peer disconnects, or in reply to signaling a peer that does not exist.
+    RtcPeerLeft(PeerId),
+    /// `peer` is the recipient when sent to the server and the sender when received from it.
+    RtcOffer { peer: PeerId, sdp: String },
+    /// `peer` is the recipient when sent to the server and the sender when received from it.
+    RtcAnswer { peer: PeerId, sdp: String },
+    /// `peer` is the recipient when sent to the server and the sender when received from it.
+    RtcIceCandidate { peer: PeerId, candidate: IceCandidate },
+    /// Plugs the connection into the session's link cable. The server answers with `LinkStatus` or `LinkFull`.
+    LinkJoin,
+    /// Sent to every linked player when the cable changes.
+    /// `player` is the receiver's SIO player number and `connected` a bitmask of the occupied player numbers.
+    LinkStatus { player: u8, connected: u8 },
+    /// All four player numbers are taken.
+    LinkFull,
+    /// The word this player shifts out when another player clocks the next transfer.
+    LinkSend(SioWord),
+    /// Clocks a transfer shifting out the given word.
+    /// Only player 0 may start a multiplayer transfer; a normal transfer exchanges with the next linked player.
+    LinkStart(SioWord),
+    /// Normal mode: the word shifted in by the last transfer.
+    LinkNormalResult(SioWord),
+    /// Multiplayer mode: `SIOMULTI0`-`SIOMULTI3` after the last transfer, `0xFFFF` for empty player numbers.
+    LinkMultiplayerResult([u16; 4]),
+    /// UART mode: bytes sent to every other linked player.
+    LinkUart(Vec<u8>),
+    /// Asks the server for the session's full current state, e.g. after a `DeltaSnapshot` could not be applied.
+    SnapshotRequest,
+    /// The session's active cheats as `state::cheat::CheatList` bytes, sent to every other player and to new ones.
+    Cheats(Vec<u8>),
 }
 
 impl TryInto<Vec<u8>> for &Message {
@@ -201,4 +325,19 @@ mod tests {
 
         assert_eq!(new, new2);
     }
+
+    #[test]
+    fn test_try_apply_rejects_out_of_range_changes() {
+        let old = vec![3; 32];
+        let mut new = old.clone();
+        new[5] = 4;
+
+        assert_eq!(DeltaSnapshot::new(&old, &new).try_apply(&old), Some(new));
+        assert!(DeltaSnapshot::new(&old, &old).try_apply(&[4; 32]).is_none());
+
+        let mut longer = vec![3; 1024];
+        longer[600] = 4;
+
+        assert!(DeltaSnapshot::new(&[3; 1024], &longer).try_apply(&old).is_none());
+    }
 }
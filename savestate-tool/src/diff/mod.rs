@@ -0,0 +1,156 @@
+//! The differences between two states for `savestate-tool diff`.
+
+use ::std::fmt;
+
+use ::state::format::SaveStateDelta;
+use ::state::SaveState;
+
+use crate::{ToolError, REGIONS};
+
+#[derive(Debug)]
+pub struct Diff {
+    /// Names of the fields that differ.
+    pub fields: Vec<String>,
+    pub ranges: Vec<MemoryRange>,
+}
+
+/// Bytes of a memory that differ, by their address on the GBA's bus.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MemoryRange {
+    pub region: &'static str,
+    pub start: u32,
+    /// Exclusive.
+    pub end: u32,
+}
+
+impl Diff {
+    pub fn new(old: &SaveState, new: &SaveState) -> Result<Diff, ToolError> {
+        let delta = SaveStateDelta::new(old, new)?;
+        let fields = delta.fields().map(str::to_string).collect();
+
+        let ranges = REGIONS
+            .iter()
+            .flat_map(|region| {
+                let old = (region.data)(old).unwrap_or_default();
+                let new = (region.data)(new).unwrap_or_default();
+
+                changed_ranges(old, new)
+                    .into_iter()
+                    .map(move |(start, end)| MemoryRange {
+                        region: region.name,
+                        start: region.address + start as u32,
+                        end: region.address + end as u32,
+                    })
+            })
+            .collect();
+
+        Ok(Diff { fields, ranges })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} fields changed:", self.fields.len())?;
+
+        for field in &self.fields {
+            write!(f, "\n  {}", field)?;
+        }
+
+        write!(f, "\n{} memory ranges changed:", self.ranges.len())?;
+
+        for range in &self.ranges {
+            write!(
+                f,
+                "\n  {:<8}0x{:08X}..0x{:08X} ({} bytes)",
+                range.region,
+                range.start,
+                range.end,
+                range.end - range.start
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The runs of bytes that differ. Bytes only one of both has, e.g. of a save chip that grew, count as changed.
+fn changed_ranges(old: &[u8], new: &[u8]) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for index in 0..old.len().max(new.len()) {
+        if old.get(index) == new.get(index) {
+            continue;
+        }
+
+        match ranges.last_mut() {
+            Some((_, end)) if *end == index => *end = index + 1,
+            _ => ranges.push((index, index + 1)),
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diff::{changed_ranges, Diff, MemoryRange};
+
+    use ::state::SaveState;
+
+    #[test]
+    fn test_changed_ranges() {
+        assert_eq!(changed_ranges(&[1, 2, 3, 4], &[1, 2, 3, 4]), vec![]);
+        assert_eq!(
+            changed_ranges(&[1, 2, 3, 4, 5], &[0, 0, 3, 0, 5, 6]),
+            vec![(0, 2), (3, 4), (5, 6)]
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_bus_addresses() {
+        let old = SaveState {
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            iocore_memory_external_ram: vec![0; 0x40000],
+            ..SaveState::default()
+        };
+        let mut new = SaveState {
+            clock_cycles_since_start: 1,
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            iocore_memory_external_ram: vec![0; 0x40000],
+            ..SaveState::default()
+        };
+        new.iocore_memory_internal_ram[0x100] = 1;
+        new.iocore_memory_internal_ram[0x101] = 1;
+        new.iocore_memory_external_ram[0x3FFFF] = 1;
+
+        let diff = Diff::new(&old, &new).unwrap();
+
+        assert_eq!(
+            diff.fields,
+            [
+                "clock_cycles_since_start",
+                "iocore_memory_external_ram",
+                "iocore_memory_internal_ram"
+            ]
+        );
+        assert_eq!(
+            diff.ranges,
+            [
+                MemoryRange {
+                    region: "ewram",
+                    start: 0x0203_FFFF,
+                    end: 0x0204_0000,
+                },
+                MemoryRange {
+                    region: "iwram",
+                    start: 0x0300_0100,
+                    end: 0x0300_0102,
+                },
+            ]
+        );
+    }
+}
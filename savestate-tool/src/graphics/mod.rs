@@ -0,0 +1,104 @@
+//! PNGs of the video memory of a state for `savestate-tool graphics`.
+
+use ::std::path::{Path, PathBuf};
+
+use ::state::graphics::{self, Depth, Palette};
+use ::state::screenshot::Screenshot;
+use ::state::SaveState;
+
+use crate::ToolError;
+
+/// Writes the palettes, both tile sheets, every background layer of the video mode and every sprite that is not
+/// disabled into `dir`, returning the files written.
+pub fn render(save_state: &SaveState, dir: &Path) -> Result<Vec<PathBuf>, ToolError> {
+    ::std::fs::create_dir_all(dir)?;
+
+    let mut pictures = vec![
+        ("palettes".to_string(), graphics::palettes(save_state)),
+        (
+            "tiles-4bpp".to_string(),
+            graphics::tiles(save_state, Depth::Bpp4, Palette::Background, 0),
+        ),
+        (
+            "tiles-8bpp".to_string(),
+            graphics::tiles(save_state, Depth::Bpp8, Palette::Background, 0),
+        ),
+    ];
+
+    for background in graphics::backgrounds(save_state) {
+        pictures.push((
+            format!("bg{}", background.index),
+            background.render(save_state),
+        ));
+    }
+
+    for sprite in graphics::sprites(save_state) {
+        if !sprite.disabled {
+            pictures.push((
+                format!("sprite-{:03}", sprite.index),
+                sprite.render(save_state),
+            ));
+        }
+    }
+
+    pictures
+        .into_iter()
+        .map(|(name, picture)| write_png(dir, &name, &picture))
+        .collect()
+}
+
+fn write_png(dir: &Path, name: &str, picture: &Screenshot) -> Result<PathBuf, ToolError> {
+    let path = dir.join(format!("{}.png", name));
+    ::std::fs::write(&path, picture.to_png()?)?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graphics::render;
+
+    use ::state::{OamTableEntry, SaveState};
+
+    #[test]
+    fn test_render_writes_the_layers_of_the_mode() {
+        let dir = ::std::env::temp_dir()
+            .join(format!("savestate-tool-graphics-{}", ::std::process::id()));
+        let mut save_state = SaveState {
+            iocore_gfx_renderer_iodata16: vec![0; 10],
+            iocore_gfx_renderer_renderer_vram: vec![0; 0x18000],
+            iocore_gfx_renderer_renderer_palette_ram: vec![0; 0x400],
+            iocore_gfx_renderer_renderer_obj_renderer_oamtable: (0..128)
+                .map(|_| OamTableEntry {
+                    double_size_or_disabled: 1,
+                    ..OamTableEntry::default()
+                })
+                .collect(),
+            ..SaveState::default()
+        };
+        /* Mode 2 has the affine layers 2 and 3. */
+        save_state.iocore_gfx_renderer_iodata16[0] = 0x0002;
+        save_state.iocore_gfx_renderer_renderer_obj_renderer_oamtable[5].double_size_or_disabled =
+            0;
+
+        let names: Vec<String> = render(&save_state, &dir)
+            .unwrap()
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                "palettes.png",
+                "tiles-4bpp.png",
+                "tiles-8bpp.png",
+                "bg2.png",
+                "bg3.png",
+                "sprite-005.png"
+            ]
+        );
+
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+}
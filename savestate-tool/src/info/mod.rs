@@ -0,0 +1,132 @@
+//! A summary of a state for `savestate-tool info`.
+
+use ::std::fmt;
+
+use ::state::format;
+use ::state::inspect::save_type_name;
+
+use crate::{read_save_state, ToolError};
+
+/// The field prefix of each section, fields of no section count as "Other".
+const SECTIONS: &[(&str, &str)] = &[
+    ("iocore_arm_", "CPU"),
+    ("iocore_cpu_", "CPU"),
+    ("iocore_thumb_", "CPU"),
+    ("iocore_cartridge_", "Cartridge"),
+    ("iocore_dma_", "DMA"),
+    ("iocore_gfx_", "Graphics"),
+    ("iocore_irq_", "IRQ"),
+    ("iocore_joypad_", "Joypad"),
+    ("iocore_memory_", "Memory"),
+    ("iocore_saves_", "Saves"),
+    ("iocore_serial_", "Serial"),
+    ("iocore_sound_", "Sound"),
+    ("iocore_timer_", "Timers"),
+    ("iocore_wait_", "Wait states"),
+];
+
+#[derive(Debug)]
+pub struct Info {
+    pub format_version: u16,
+    /// Version of the build that wrote the state, `None` for headerless states.
+    pub emulator_version: Option<String>,
+    pub cartridge_name: String,
+    pub cycles: i32,
+    pub save_type: &'static str,
+    /// Bytes of the state as given.
+    pub size: usize,
+    /// Encoded bytes of each section in the current format, largest first.
+    pub sections: Vec<(&'static str, usize)>,
+}
+
+impl Info {
+    pub fn of_state(data: &[u8]) -> Result<Info, ToolError> {
+        let save_state = read_save_state(data)?;
+        let (format_version, emulator_version) = format::version(data)?;
+
+        let mut sections: Vec<(&'static str, usize)> = Vec::new();
+
+        for (field, size) in format::field_sizes(&save_state)? {
+            let name = section(&field);
+
+            match sections.iter_mut().find(|(section, _)| *section == name) {
+                Some((_, total)) => *total += size,
+                None => sections.push((name, size)),
+            }
+        }
+
+        sections.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        Ok(Info {
+            format_version,
+            emulator_version,
+            cartridge_name: save_state.iocore_cartridge_name,
+            cycles: save_state.clock_cycles_since_start,
+            save_type: save_type_name(save_state.iocore_saves_save_type),
+            size: data.len(),
+            sections,
+        })
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Cartridge:  {}", self.cartridge_name)?;
+        writeln!(f, "Cycles:     {}", self.cycles)?;
+        writeln!(f, "Save type:  {}", self.save_type)?;
+
+        match &self.emulator_version {
+            Some(version) => writeln!(
+                f,
+                "Format:     {} (written by {})",
+                self.format_version, version
+            )?,
+            None => writeln!(f, "Format:     {} (headerless)", self.format_version)?,
+        }
+
+        write!(f, "Size:       {} bytes", self.size)?;
+
+        for (section, size) in &self.sections {
+            write!(f, "\n  {:<12}{:>10}", section, size)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn section(field: &str) -> &'static str {
+    SECTIONS
+        .iter()
+        .find(|(prefix, _)| field.starts_with(prefix))
+        .map_or("Other", |(_, name)| name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::info::Info;
+
+    use ::state::{format, SaveState};
+
+    #[test]
+    fn test_info() {
+        let save_state = SaveState {
+            clock_cycles_since_start: 1234,
+            iocore_cartridge_name: "POKEMON EMER".to_string(),
+            iocore_saves_save_type: 2,
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            iocore_memory_external_ram: vec![0; 0x40000],
+            ..SaveState::default()
+        };
+        let data = format::write(&save_state).unwrap();
+
+        let info = Info::of_state(&data).unwrap();
+
+        assert_eq!(info.cartridge_name, "POKEMON EMER");
+        assert_eq!(info.cycles, 1234);
+        assert_eq!(info.save_type, "Flash");
+        assert_eq!(info.format_version, format::FORMAT_VERSION);
+        assert_eq!(info.size, data.len());
+        assert_eq!(info.sections[0].0, "Memory");
+        assert!(info.sections[0].1 > 0x48000);
+    }
+}
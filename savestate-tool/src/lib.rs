@@ -0,0 +1,251 @@
+//! Reads, compares and converts the states `Snapshotter` writes, outside of the browser.
+
+use ::b64::{FromBase64, ToBase64};
+
+use ::backtrace::Backtrace;
+
+use ::std::path::{Path, PathBuf};
+
+use ::state::format::{self, FormatError};
+use ::state::screenshot::ScreenshotError;
+use ::state::{battery, generic, SaveState};
+
+pub mod diff;
+
+pub mod graphics;
+
+pub mod info;
+
+#[derive(Debug)]
+pub struct ToolError {
+    kind: ToolErrorKind,
+    stack: Backtrace,
+}
+
+impl ToolError {
+    fn new(kind: ToolErrorKind) -> Self {
+        ToolError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+
+    pub fn kind(&self) -> &ToolErrorKind {
+        &self.kind
+    }
+
+    pub fn stack(&self) -> &Backtrace {
+        &self.stack
+    }
+}
+
+impl From<ToolErrorKind> for ToolError {
+    fn from(kind: ToolErrorKind) -> Self {
+        ToolError::new(kind)
+    }
+}
+
+impl From<::std::io::Error> for ToolError {
+    fn from(error: ::std::io::Error) -> Self {
+        ToolError::new(ToolErrorKind::Io(error))
+    }
+}
+
+impl From<FormatError> for ToolError {
+    fn from(error: FormatError) -> Self {
+        ToolError::new(ToolErrorKind::Format(error))
+    }
+}
+
+impl From<ScreenshotError> for ToolError {
+    fn from(error: ScreenshotError) -> Self {
+        ToolError::new(ToolErrorKind::Screenshot(error))
+    }
+}
+
+#[derive(Debug)]
+pub enum ToolErrorKind {
+    Io(::std::io::Error),
+    Format(FormatError),
+    Screenshot(ScreenshotError),
+    /// Generic states have no `SaveState` schema to read them with.
+    Generic,
+}
+
+/// How a state is stored on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// The bytes from `serialize_to_uint8array`.
+    Raw,
+    /// The text from `exportSaveState`.
+    Base64,
+}
+
+/// Reads a state from disk, accepting both raw bytes and base64 text.
+pub fn read_state_file(path: &Path) -> Result<Vec<u8>, ToolError> {
+    let data = ::std::fs::read(path)?;
+
+    match ::std::str::from_utf8(&data) {
+        Ok(text) => match text.trim().from_base64() {
+            Ok(decoded) => Ok(decoded),
+            Err(_) => Ok(data),
+        },
+        Err(_) => Ok(data),
+    }
+}
+
+pub fn write_state_file(path: &Path, data: &[u8], encoding: Encoding) -> Result<(), ToolError> {
+    match encoding {
+        Encoding::Raw => ::std::fs::write(path, data)?,
+        Encoding::Base64 => ::std::fs::write(path, data.to_base64(b64::STANDARD))?,
+    }
+
+    Ok(())
+}
+
+/// Reads a state of any format version, generic states are rejected.
+pub fn read_save_state(data: &[u8]) -> Result<SaveState, ToolError> {
+    if generic::is_generic(data) {
+        Err(ToolErrorKind::Generic)?
+    }
+
+    Ok(format::read(data)?)
+}
+
+/// Rewrites a state of any format version in the current one.
+pub fn upgrade(data: &[u8]) -> Result<Vec<u8>, ToolError> {
+    Ok(format::write(&read_save_state(data)?)?)
+}
+
+/// A memory of the GBA as a state holds it.
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    pub name: &'static str,
+    /// Where the memory starts on the GBA's bus.
+    pub address: u32,
+    pub data: fn(&SaveState) -> Option<&[u8]>,
+}
+
+pub const REGIONS: [Region; 6] = [
+    Region {
+        name: "ewram",
+        address: 0x0200_0000,
+        data: |s| Some(s.iocore_memory_external_ram.as_slice()),
+    },
+    Region {
+        name: "iwram",
+        address: 0x0300_0000,
+        data: |s| Some(s.iocore_memory_internal_ram.as_slice()),
+    },
+    Region {
+        name: "palette",
+        address: 0x0500_0000,
+        data: |s| Some(s.iocore_gfx_renderer_renderer_palette_ram.as_slice()),
+    },
+    Region {
+        name: "vram",
+        address: 0x0600_0000,
+        data: |s| Some(s.iocore_gfx_renderer_renderer_vram.as_slice()),
+    },
+    Region {
+        name: "oam",
+        address: 0x0700_0000,
+        data: |s| {
+            Some(
+                s.iocore_gfx_renderer_renderer_obj_renderer_oamram
+                    .as_slice(),
+            )
+        },
+    },
+    Region {
+        name: "save",
+        address: 0x0E00_0000,
+        data: |s| battery::export(s).ok(),
+    },
+];
+
+/// Writes every region of `save_state` that it holds into `dir` as `<name>.bin`, returning the files written.
+pub fn extract(save_state: &SaveState, dir: &Path) -> Result<Vec<PathBuf>, ToolError> {
+    ::std::fs::create_dir_all(dir)?;
+
+    let mut paths = Vec::new();
+
+    for region in &REGIONS {
+        if let Some(data) = (region.data)(save_state) {
+            let path = dir.join(format!("{}.bin", region.name));
+            ::std::fs::write(&path, data)?;
+
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{extract, read_save_state, read_state_file, upgrade, write_state_file, Encoding};
+
+    use ::state::{format, generic, SaveState};
+
+    use ::std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = ::std::env::temp_dir().join(format!(
+            "savestate-tool-{}-{}",
+            name,
+            ::std::process::id()
+        ));
+        ::std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    #[test]
+    fn test_base64_and_raw_files_read_the_same() {
+        let dir = temp_dir("encoding");
+        let data = format::write(&SaveState::default()).unwrap();
+
+        write_state_file(&dir.join("raw"), &data, Encoding::Raw).unwrap();
+        write_state_file(&dir.join("base64"), &data, Encoding::Base64).unwrap();
+
+        assert_eq!(read_state_file(&dir.join("raw")).unwrap(), data);
+        assert_eq!(read_state_file(&dir.join("base64")).unwrap(), data);
+
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_extract_writes_the_save_chip_of_the_save_type() {
+        let dir = temp_dir("extract");
+        let save_state = SaveState {
+            iocore_memory_internal_ram: vec![1; 0x8000],
+            iocore_saves_save_type: 1,
+            iocore_saves_sramchip_saves: Some(vec![2; 0x8000]),
+            iocore_saves_flashchip_saves: Some(vec![3; 0x10000]),
+            ..SaveState::default()
+        };
+
+        let paths = extract(&save_state, &dir).unwrap();
+
+        assert_eq!(paths.len(), 6);
+        assert_eq!(
+            ::std::fs::read(dir.join("iwram.bin")).unwrap(),
+            vec![1; 0x8000]
+        );
+        assert_eq!(
+            ::std::fs::read(dir.join("save.bin")).unwrap(),
+            vec![2; 0x8000]
+        );
+
+        ::std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_generic_states_are_not_upgraded() {
+        let data = generic::write(generic::Record(Vec::new())).unwrap();
+
+        assert!(read_save_state(&data).is_err());
+        assert!(upgrade(&data).is_err());
+    }
+}
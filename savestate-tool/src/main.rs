@@ -0,0 +1,120 @@
+use ::clap::{Parser, Subcommand};
+
+use ::std::path::PathBuf;
+
+use ::state::validate::ValidationReport;
+
+use ::savestate_tool::{
+    diff::Diff, extract, graphics, info::Info, read_save_state, read_state_file, upgrade,
+    write_state_file, Encoding, ToolError,
+};
+
+/// Inspects and converts states exported from the emulator, raw or base64.
+#[derive(Parser, Debug)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Cartridge name, cycles, save type and size by section of a state.
+    Info { state: PathBuf },
+
+    /// Fields and memory ranges that differ between two states.
+    Diff { old: PathBuf, new: PathBuf },
+
+    /// Writes VRAM, IWRAM, EWRAM, palette, OAM and the save chip into a directory, one file each.
+    Extract { state: PathBuf, dir: PathBuf },
+
+    /// Writes PNGs of the palettes, tiles, background maps and sprites into a directory.
+    Graphics { state: PathBuf, dir: PathBuf },
+
+    /// Rewrites a state as raw bytes or base64.
+    Convert {
+        input: PathBuf,
+        output: PathBuf,
+
+        /// Write base64 text like `exportSaveState` instead of raw bytes.
+        #[clap(long)]
+        base64: bool,
+
+        /// Migrate the state to the current format version instead of copying it as is.
+        #[clap(long)]
+        upgrade: bool,
+    },
+
+    /// Checks that a state can be loaded, exits with 1 if it can not.
+    Validate { state: PathBuf },
+}
+
+fn main() {
+    env_logger::init();
+
+    let args = Args::parse();
+
+    match run(args) {
+        Ok(true) => {}
+        Ok(false) => ::std::process::exit(1),
+        Err(e) => {
+            log::error!("savestate-tool failed: {:?}", e);
+            ::std::process::exit(1);
+        }
+    }
+}
+
+/// Runs a command, returning whether the state(s) passed its checks.
+fn run(args: Args) -> Result<bool, ToolError> {
+    match args.command {
+        Command::Info { state } => {
+            println!("{}", Info::of_state(&read_state_file(&state)?)?);
+        }
+        Command::Diff { old, new } => {
+            let old = read_save_state(&read_state_file(&old)?)?;
+            let new = read_save_state(&read_state_file(&new)?)?;
+
+            println!("{}", Diff::new(&old, &new)?);
+        }
+        Command::Extract { state, dir } => {
+            let save_state = read_save_state(&read_state_file(&state)?)?;
+
+            for path in extract(&save_state, &dir)? {
+                println!("Wrote {:?}", path);
+            }
+        }
+        Command::Graphics { state, dir } => {
+            let save_state = read_save_state(&read_state_file(&state)?)?;
+
+            for path in graphics::render(&save_state, &dir)? {
+                println!("Wrote {:?}", path);
+            }
+        }
+        Command::Convert {
+            input,
+            output,
+            base64,
+            upgrade: should_upgrade,
+        } => {
+            let mut data = read_state_file(&input)?;
+
+            if should_upgrade {
+                data = upgrade(&data)?;
+            }
+
+            let encoding = if base64 {
+                Encoding::Base64
+            } else {
+                Encoding::Raw
+            };
+            write_state_file(&output, &data, encoding)?;
+        }
+        Command::Validate { state } => {
+            let report = ValidationReport::of_state(&read_state_file(&state)?);
+            println!("{}", report);
+
+            return Ok(report.is_valid());
+        }
+    }
+
+    Ok(true)
+}
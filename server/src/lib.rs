@@ -1 +1,590 @@
+use ::backtrace::Backtrace;
 
+use ::futures::stream::SplitSink;
+
+use ::futures_util::{Future, SinkExt, StreamExt};
+
+use ::tokio::sync::RwLock;
+
+use ::warp::{ws::WebSocket, Filter, Rejection, Reply};
+
+use ::std::{
+    collections::{BTreeMap, BTreeSet},
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use ::network::{DeltaSnapshot, Message, PeerId, SioWord};
+
+mod link;
+use link::LinkCable;
+
+mod session;
+use session::SessionState;
+
+#[derive(Debug)]
+pub struct BroadcastError {
+    kind: BroadcastErrorKind,
+    stack: Backtrace,
+}
+
+impl BroadcastError {
+    fn new(kind: BroadcastErrorKind) -> Self {
+        BroadcastError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+
+    pub fn kind(&self) -> &BroadcastErrorKind {
+        &self.kind
+    }
+
+    pub fn stack(&self) -> &Backtrace {
+        &self.stack
+    }
+}
+
+impl From<::bincode::Error> for BroadcastError {
+    fn from(error: ::bincode::Error) -> Self {
+        BroadcastError::new(BroadcastErrorKind::Bincode(error))
+    }
+}
+
+impl From<::warp::Error> for BroadcastError {
+    fn from(error: ::warp::Error) -> Self {
+        BroadcastError::new(BroadcastErrorKind::Warp(error))
+    }
+}
+
+#[derive(Debug)]
+pub enum BroadcastErrorKind {
+    Bincode(::bincode::Error),
+    Warp(::warp::Error),
+}
+
+#[derive(Debug)]
+pub struct Services {
+    next_id: AtomicUsize,
+    listeners: BTreeMap<usize, SplitSink<WebSocket, ::warp::ws::Message>>,
+    /// Listeners that opted into WebRTC signaling with `Message::RtcJoin`.
+    rtc_peers: BTreeSet<PeerId>,
+    link: LinkCable,
+    session: SessionState,
+    /// The last `Message::Cheats` a player sent, for players that join later.
+    cheats: Option<Vec<u8>>,
+}
+
+impl Default for Services {
+    fn default() -> Self {
+        Services::new()
+    }
+}
+
+impl Services {
+    pub fn new() -> Services {
+        Services {
+            next_id: AtomicUsize::new(0),
+            listeners: BTreeMap::new(),
+            rtc_peers: BTreeSet::new(),
+            link: LinkCable::new(),
+            session: SessionState::new(),
+            cheats: None,
+        }
+    }
+
+    /// Services that drop snapshots which would not load in the emulator instead of relaying them.
+    pub fn with_state_validation() -> Services {
+        Services {
+            session: SessionState::with_state_validation(),
+            ..Services::new()
+        }
+    }
+
+    /// Number of websockets currently registered to receive broadcasts.
+    pub fn listener_count(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// The session's current emulator state as last rebuilt by the server.
+    pub fn current_state(&self) -> Option<&[u8]> {
+        self.session.current()
+    }
+
+    fn add_listener(&mut self, tx: SplitSink<WebSocket, ::warp::ws::Message>) -> usize {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        self.listeners.insert(id, tx);
+        id
+    }
+
+    fn remove_listener(&mut self, id: usize) -> Option<SplitSink<WebSocket, ::warp::ws::Message>> {
+        self.listeners.remove(&id)
+    }
+
+    /// Sends to a single listener, returning `false` if it is not connected.
+    async fn send_to(&mut self, id: usize, message: &Message) -> Result<bool, BroadcastError> {
+        let data: String = message.try_into()?;
+
+        match self.listeners.get_mut(&id) {
+            Some(tx) => {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn join_rtc(&mut self, joiner_id: PeerId) -> Result<(), BroadcastError> {
+        let peers: Vec<PeerId> = self
+            .rtc_peers
+            .iter()
+            .copied()
+            .filter(|id| *id != joiner_id)
+            .collect();
+
+        let data: String = Message::RtcPeerJoined(joiner_id).try_into()?;
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if peers.contains(id) {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+            }
+        }
+
+        self.rtc_peers.insert(joiner_id);
+        self.send_to(joiner_id, &Message::RtcPeers { you: joiner_id, peers })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn leave_rtc(&mut self, leaver_id: PeerId) -> Result<(), BroadcastError> {
+        if !self.rtc_peers.remove(&leaver_id) {
+            return Ok(());
+        }
+
+        let data: String = Message::RtcPeerLeft(leaver_id).try_into()?;
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if self.rtc_peers.contains(id) {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Forwards an offer, answer or ICE candidate to `recipient_id`.
+    ///
+    /// `signal` must already name the sender as its peer. Both ends must have sent `RtcJoin`; if either has not, or the
+    /// recipient is gone, the sender is told with `RtcPeerLeft`.
+    async fn relay_signal(
+        &mut self,
+        sender_id: PeerId,
+        recipient_id: PeerId,
+        signal: Message,
+    ) -> Result<(), BroadcastError> {
+        let joined = self.rtc_peers.contains(&sender_id) && self.rtc_peers.contains(&recipient_id);
+
+        if !joined || recipient_id == sender_id || !self.send_to(recipient_id, &signal).await? {
+            self.send_to(sender_id, &Message::RtcPeerLeft(recipient_id))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_all(&mut self, deliveries: Vec<(PeerId, Message)>) -> Result<(), BroadcastError> {
+        for (id, message) in deliveries.iter() {
+            self.send_to(*id, message).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn join_link(&mut self, joiner_id: PeerId) -> Result<(), BroadcastError> {
+        match self.link.join(joiner_id) {
+            Some(_) => self.send_all(self.link.status()).await,
+            None => self.send_to(joiner_id, &Message::LinkFull).await.map(|_| ()),
+        }
+    }
+
+    async fn leave_link(&mut self, leaver_id: PeerId) -> Result<(), BroadcastError> {
+        if self.link.leave(leaver_id) {
+            self.send_all(self.link.status()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn start_link_transfer(
+        &mut self,
+        starter_id: PeerId,
+        word: SioWord,
+    ) -> Result<(), BroadcastError> {
+        let deliveries = self.link.start(starter_id, word);
+        self.send_all(deliveries).await
+    }
+
+    async fn send_link_uart(&mut self, sender_id: PeerId, bytes: Vec<u8>) -> Result<(), BroadcastError> {
+        let recipients = self.link.uart_recipients(sender_id);
+        let message = Message::LinkUart(bytes);
+
+        for id in recipients {
+            self.send_to(id, &message).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn set_rom(&mut self, setter_id: usize, rom: Vec<u8>) -> Result<(), BroadcastError> {
+        let data: String = Message::Rom(rom).try_into()?;
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if *id != setter_id {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_bios(&mut self, setter_id: usize, bios: Vec<u8>) -> Result<(), BroadcastError> {
+        let data: String = Message::Bios(bios).try_into()?;
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if *id != setter_id {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn share_cheats(&mut self, sender_id: usize, cheats: Vec<u8>) -> Result<(), BroadcastError> {
+        let data: String = Message::Cheats(cheats.clone()).try_into()?;
+        self.cheats = Some(cheats);
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if *id != sender_id {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends a new listener the session's cheats, if anyone shared some.
+    async fn send_cheats(&mut self, receiver_id: usize) -> Result<(), BroadcastError> {
+        if let Some(cheats) = self.cheats.clone() {
+            self.send_to(receiver_id, &Message::Cheats(cheats)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends every other listener the cheapest update to the session's current state.
+    async fn broadcast_session_update(&mut self, sender_id: usize) -> Result<(), BroadcastError> {
+        let receiver_ids: Vec<usize> = self
+            .listeners
+            .keys()
+            .copied()
+            .filter(|id| *id != sender_id)
+            .collect();
+
+        for receiver_id in receiver_ids {
+            self.send_session_update(receiver_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_session_update(&mut self, receiver_id: usize) -> Result<(), BroadcastError> {
+        if let Some(update) = self.session.update_for(receiver_id) {
+            if self.send_to(receiver_id, &update).await? {
+                self.session.delivered(receiver_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn broadcast_delta_snapshot(
+        &mut self,
+        sender_id: usize,
+        snapshot: DeltaSnapshot,
+    ) -> Result<(), BroadcastError> {
+        if !self.session.apply_delta(sender_id, snapshot) {
+            log::warn!(
+                "Dropping delta from {:?} that does not fit its base or gives an invalid state.",
+                sender_id
+            );
+            return Ok(());
+        }
+
+        self.broadcast_session_update(sender_id).await
+    }
+
+    async fn broadcast_play(
+        &mut self,
+        sender_id: usize,
+        snapshot: Vec<u8>,
+    ) -> Result<(), BroadcastError> {
+        let data: String = Message::Play(snapshot.clone()).try_into()?;
+
+        if !self.session.set_current(sender_id, snapshot) {
+            return Ok(());
+        }
+
+        for (id, tx) in self.listeners.iter_mut() {
+            if *id != sender_id {
+                tx.send(::warp::ws::Message::text(&data)).await?;
+                self.session.delivered(*id);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn broadcast_snapshot(
+        &mut self,
+        sender_id: usize,
+        snapshot: Vec<u8>,
+    ) -> Result<(), BroadcastError> {
+        if !self.session.set_current(sender_id, snapshot) {
+            return Ok(());
+        }
+
+        self.broadcast_session_update(sender_id).await
+    }
+
+    async fn resend_snapshot(&mut self, requester_id: usize) -> Result<(), BroadcastError> {
+        self.session.forget(requester_id);
+
+        self.send_session_update(requester_id).await
+    }
+}
+
+/// Builds every route the server exposes: the static web files in `www_dir` and the `/websocket` relay.
+pub fn routes(
+    www_dir: PathBuf,
+    services: Arc<RwLock<Services>>,
+) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone {
+    let services_filter = warp::any().map(move || services.clone());
+
+    let inline_paths = { warp::get().and(warp::fs::dir(www_dir)) };
+
+    let websocket = warp::path("websocket")
+        .and(warp::ws())
+        .and(warp::addr::remote())
+        .and(services_filter)
+        .map(
+            |ws: warp::ws::Ws, remote: Option<SocketAddr>, services: Arc<RwLock<Services>>| {
+                ws.on_upgrade(move |socket| on_websocket(socket, remote, services))
+            },
+        );
+
+    inline_paths.or(websocket)
+}
+
+/// Binds the routes to `addr` and returns the bound address with the server future.
+///
+/// Binding to port `0` picks an ephemeral port, which is what the integration tests rely on.
+pub fn serve(
+    www_dir: PathBuf,
+    services: Arc<RwLock<Services>>,
+    addr: impl Into<SocketAddr> + 'static,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> (SocketAddr, impl Future<Output = ()> + 'static) {
+    warp::serve(routes(www_dir, services)).bind_with_graceful_shutdown(addr, shutdown)
+}
+
+async fn on_websocket(ws: WebSocket, _remote: Option<SocketAddr>, services: Arc<RwLock<Services>>) {
+    let (tx, mut rx) = ws.split();
+
+    let id = {
+        let mut services = services.write().await;
+        let id = services.add_listener(tx);
+
+        if let Err(e) = services.send_cheats(id).await {
+            log::error!("Failed to send cheats: {:?}", e)
+        }
+
+        id
+    };
+
+    loop {
+        match rx.next().await {
+            None => break,
+            Some(Err(e)) => {
+                log::warn!("Failure accepting message: {:?}", e);
+                break;
+            }
+            Some(Ok(message)) => match message.to_str() {
+                Err(_) => {
+                    log::info!("Non-Text of length: {}", message.as_bytes().len());
+                    break;
+                }
+                Ok(str) => match network::Message::try_from(str) {
+                    Ok(Message::Bios(bios)) => {
+                        log::info!("Bios -- {:?}", bios.len());
+
+                        if let Err(e) = services.write().await.set_bios(id, bios).await {
+                            log::error!("Failed to send bios: {:?}", e)
+                        }
+                    }
+                    Ok(Message::Rom(rom)) => {
+                        log::info!("Rom -- {:?}", rom.len());
+
+                        if let Err(e) = services.write().await.set_rom(id, rom).await {
+                            log::error!("Failed to send rom: {:?}", e)
+                        }
+                    }
+                    Ok(Message::Play(snapshot)) => {
+                        log::info!("Play -- {:?}", snapshot.len());
+
+                        if let Err(e) = services.write().await.broadcast_play(id, snapshot).await {
+                            log::error!("Failed to send delta: {:?}", e)
+                        }
+                    }
+                    Ok(Message::DeltaSnapshot(snapshot)) => {
+                        log::info!("Snapshot (Delta) -- {:?}", snapshot.len());
+
+                        if let Err(e) = services
+                            .write()
+                            .await
+                            .broadcast_delta_snapshot(id, snapshot)
+                            .await
+                        {
+                            log::error!("Failed to send delta: {:?}", e)
+                        }
+                    }
+                    Ok(Message::Snapshot(snapshot)) => {
+                        log::info!("Snapshot -- {:?}", snapshot.len());
+
+                        if let Err(e) = services
+                            .write()
+                            .await
+                            .broadcast_snapshot(id, snapshot)
+                            .await
+                        {
+                            log::error!("Failed to send snapshot: {:?}", e)
+                        }
+                    }
+                    Ok(Message::RtcJoin) => {
+                        log::info!("RTC Join -- {:?}", id);
+
+                        if let Err(e) = services.write().await.join_rtc(id).await {
+                            log::error!("Failed to join rtc: {:?}", e)
+                        }
+                    }
+                    Ok(Message::RtcOffer { peer, sdp }) => {
+                        log::info!("RTC Offer -- {:?} -> {:?}", id, peer);
+
+                        let signal = Message::RtcOffer { peer: id, sdp };
+                        if let Err(e) = services.write().await.relay_signal(id, peer, signal).await {
+                            log::error!("Failed to send rtc offer: {:?}", e)
+                        }
+                    }
+                    Ok(Message::RtcAnswer { peer, sdp }) => {
+                        log::info!("RTC Answer -- {:?} -> {:?}", id, peer);
+
+                        let signal = Message::RtcAnswer { peer: id, sdp };
+                        if let Err(e) = services.write().await.relay_signal(id, peer, signal).await {
+                            log::error!("Failed to send rtc answer: {:?}", e)
+                        }
+                    }
+                    Ok(Message::RtcIceCandidate { peer, candidate }) => {
+                        log::info!("RTC ICE Candidate -- {:?} -> {:?}", id, peer);
+
+                        let signal = Message::RtcIceCandidate {
+                            peer: id,
+                            candidate,
+                        };
+                        if let Err(e) = services.write().await.relay_signal(id, peer, signal).await {
+                            log::error!("Failed to send rtc ice candidate: {:?}", e)
+                        }
+                    }
+                    Ok(Message::SnapshotRequest) => {
+                        log::info!("Snapshot Request -- {:?}", id);
+
+                        if let Err(e) = services.write().await.resend_snapshot(id).await {
+                            log::error!("Failed to send snapshot: {:?}", e)
+                        }
+                    }
+                    Ok(Message::LinkJoin) => {
+                        log::info!("Link Join -- {:?}", id);
+
+                        if let Err(e) = services.write().await.join_link(id).await {
+                            log::error!("Failed to join link: {:?}", e)
+                        }
+                    }
+                    Ok(Message::LinkSend(word)) => {
+                        services.write().await.link.set_send(id, word);
+                    }
+                    Ok(Message::LinkStart(word)) => {
+                        log::debug!("Link Start -- {:?} {:?}", id, word);
+
+                        if let Err(e) = services.write().await.start_link_transfer(id, word).await {
+                            log::error!("Failed to send link transfer: {:?}", e)
+                        }
+                    }
+                    Ok(Message::LinkUart(bytes)) => {
+                        log::debug!("Link UART -- {:?} {:?}", id, bytes.len());
+
+                        if let Err(e) = services.write().await.send_link_uart(id, bytes).await {
+                            log::error!("Failed to send link uart: {:?}", e)
+                        }
+                    }
+                    Ok(Message::Cheats(cheats)) => {
+                        log::info!("Cheats -- {:?}", cheats.len());
+
+                        if let Err(e) = services.write().await.share_cheats(id, cheats).await {
+                            log::error!("Failed to send cheats: {:?}", e)
+                        }
+                    }
+                    Ok(
+                        message @ (Message::RtcPeers { .. }
+                        | Message::RtcPeerJoined(_)
+                        | Message::RtcPeerLeft(_)
+                        | Message::LinkStatus { .. }
+                        | Message::LinkFull
+                        | Message::LinkNormalResult(_)
+                        | Message::LinkMultiplayerResult(_)),
+                    ) => {
+                        log::warn!("Ignoring server-only message: {:?}", message);
+                    }
+                    Err(e) => {
+                        log::warn!("Fail to parse message. {:?}", e);
+                        break;
+                    }
+                },
+            },
+        }
+    }
+
+    let tx = {
+        let mut services = services.write().await;
+        let tx = services.remove_listener(id);
+        services.session.forget(id);
+
+        if let Err(e) = services.leave_rtc(id).await {
+            log::error!("Failed to leave rtc: {:?}", e)
+        }
+
+        if let Err(e) = services.leave_link(id).await {
+            log::error!("Failed to leave link: {:?}", e)
+        }
+
+        tx
+    };
+
+    if let Some(tx) = tx {
+        if let Err(e) = rx.reunite(tx).unwrap().close().await {
+            log::debug!("Failed to close ws: {}", e)
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use ::network::{Message, PeerId, SioWord};
+
+/// The GBA link cable has one parent (player 0) and up to three children.
+pub const LINK_PLAYERS: usize = 4;
+
+/// A virtual link cable connecting the SIO ports of up to four players in a session.
+///
+/// Only decides who receives what; sending is left to `Services`.
+#[derive(Debug, Default)]
+pub struct LinkCable {
+    /// Player number -> connection.
+    players: [Option<PeerId>; LINK_PLAYERS],
+    /// The word each player last registered with `Message::LinkSend`.
+    pending: [Option<SioWord>; LINK_PLAYERS],
+}
+
+impl LinkCable {
+    pub fn new() -> LinkCable {
+        LinkCable::default()
+    }
+
+    pub fn player_number(&self, id: PeerId) -> Option<usize> {
+        self.players.iter().position(|player| *player == Some(id))
+    }
+
+    /// Bitmask of the occupied player numbers.
+    pub fn connected(&self) -> u8 {
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| player.is_some())
+            .fold(0, |mask, (number, _)| mask | (1 << number))
+    }
+
+    /// Plugs `id` into the lowest free player number, or returns `None` if the cable is full.
+    pub fn join(&mut self, id: PeerId) -> Option<usize> {
+        if let Some(number) = self.player_number(id) {
+            return Some(number);
+        }
+
+        let number = self.players.iter().position(|player| player.is_none())?;
+        self.players[number] = Some(id);
+        self.pending[number] = None;
+
+        Some(number)
+    }
+
+    /// Unplugs `id`, returning whether it was linked.
+    pub fn leave(&mut self, id: PeerId) -> bool {
+        match self.player_number(id) {
+            Some(number) => {
+                self.players[number] = None;
+                self.pending[number] = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The `LinkStatus` every linked player should receive after the cable changed.
+    pub fn status(&self) -> Vec<(PeerId, Message)> {
+        let connected = self.connected();
+
+        self.linked()
+            .map(|(number, id)| {
+                (
+                    id,
+                    Message::LinkStatus {
+                        player: number as u8,
+                        connected,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    pub fn set_send(&mut self, id: PeerId, word: SioWord) {
+        match self.player_number(id) {
+            Some(number) => self.pending[number] = Some(word),
+            None => log::warn!("{:?} is not linked, ignoring `LinkSend`.", id),
+        }
+    }
+
+    /// Clocks a transfer started by `id` and returns the results each linked player should receive.
+    pub fn start(&mut self, id: PeerId, word: SioWord) -> Vec<(PeerId, Message)> {
+        let number = match self.player_number(id) {
+            Some(number) => number,
+            None => {
+                log::warn!("{:?} is not linked, ignoring `LinkStart`.", id);
+                return Vec::new();
+            }
+        };
+
+        match word {
+            SioWord::Multiplayer(data) => self.start_multiplayer(number, data),
+            SioWord::Normal8(_) | SioWord::Normal32(_) => self.start_normal(number, word),
+        }
+    }
+
+    fn start_multiplayer(&self, number: usize, data: u16) -> Vec<(PeerId, Message)> {
+        if number != 0 {
+            log::warn!("Player {} cannot start a multiplayer transfer.", number);
+            return Vec::new();
+        }
+
+        let mut result = [u16::MAX; LINK_PLAYERS];
+        result[0] = data;
+
+        for (child, word) in result.iter_mut().enumerate().skip(1) {
+            if self.players[child].is_some() {
+                if let Some(SioWord::Multiplayer(data)) = self.pending[child] {
+                    *word = data;
+                }
+            }
+        }
+
+        self.linked()
+            .map(|(_, id)| (id, Message::LinkMultiplayerResult(result)))
+            .collect()
+    }
+
+    /// Normal mode is point to point, so the initiator exchanges with the next linked player number.
+    fn start_normal(&self, number: usize, word: SioWord) -> Vec<(PeerId, Message)> {
+        let initiator = self.players[number].expect("Initiator is linked");
+
+        let partner = (1..LINK_PLAYERS)
+            .map(|offset| (number + offset) % LINK_PLAYERS)
+            .find(|other| self.players[*other].is_some());
+
+        match partner {
+            Some(partner) => {
+                let received = match self.pending[partner] {
+                    Some(pending) if pending.mode() == word.mode() => pending,
+                    _ => word.disconnected(),
+                };
+
+                vec![
+                    (initiator, Message::LinkNormalResult(received)),
+                    (
+                        self.players[partner].expect("Partner is linked"),
+                        Message::LinkNormalResult(word),
+                    ),
+                ]
+            }
+            None => vec![(initiator, Message::LinkNormalResult(word.disconnected()))],
+        }
+    }
+
+    /// Every other linked player receives UART bytes from `id`.
+    pub fn uart_recipients(&self, id: PeerId) -> Vec<PeerId> {
+        if self.player_number(id).is_none() {
+            log::warn!("{:?} is not linked, ignoring `LinkUart`.", id);
+            return Vec::new();
+        }
+
+        self.linked()
+            .map(|(_, other)| other)
+            .filter(|other| *other != id)
+            .collect()
+    }
+
+    fn linked(&self) -> impl Iterator<Item = (usize, PeerId)> + '_ {
+        self.players
+            .iter()
+            .enumerate()
+            .filter_map(|(number, player)| player.map(|id| (number, id)))
+    }
+}
@@ -0,0 +1,193 @@
+use ::network::{DeltaSnapshot, Message, PeerId};
+
+use ::state::validate::ValidationReport;
+
+use ::std::{collections::BTreeMap, sync::Arc};
+
+/// The server's copy of a session's emulator state and of the state each client was last sent.
+///
+/// Websockets are ordered and reliable, so a state that was sent successfully is the receiver's base for the next delta.
+#[derive(Debug, Default)]
+pub struct SessionState {
+    current: Option<Arc<Vec<u8>>>,
+    bases: BTreeMap<PeerId, Arc<Vec<u8>>>,
+    /// Whether states that would not load in the emulator are rejected instead of relayed.
+    validate_states: bool,
+}
+
+impl SessionState {
+    pub fn new() -> SessionState {
+        SessionState::default()
+    }
+
+    /// A session that only accepts states passing `ValidationReport::of_state`.
+    pub fn with_state_validation() -> SessionState {
+        SessionState {
+            validate_states: true,
+            ..SessionState::default()
+        }
+    }
+
+    pub fn current(&self) -> Option<&[u8]> {
+        self.current.as_deref().map(|current| current.as_ref())
+    }
+
+    /// Replaces the current state with a full snapshot from `sender_id`, which now holds it as its base.
+    ///
+    /// Returns `false` and keeps the current state if the snapshot fails validation.
+    pub fn set_current(&mut self, sender_id: PeerId, snapshot: Vec<u8>) -> bool {
+        if self.validate_states {
+            let report = ValidationReport::of_state(&snapshot);
+
+            if !report.is_valid() {
+                log::warn!("Rejecting state from {:?}, {}", sender_id, report);
+                return false;
+            }
+        }
+
+        let snapshot = Arc::new(snapshot);
+
+        self.bases.insert(sender_id, snapshot.clone());
+        self.current = Some(snapshot);
+
+        true
+    }
+
+    /// Rebuilds the current state from a delta `sender_id` created against its own base.
+    ///
+    /// Returns `false` if the server does not know the base the delta was created against, the delta does not fit that
+    /// base, or the rebuilt state fails validation.
+    pub fn apply_delta(&mut self, sender_id: PeerId, delta: DeltaSnapshot) -> bool {
+        let state = match self.bases.get(&sender_id).or(self.current.as_ref()) {
+            Some(base) => delta.try_apply(base),
+            None => None,
+        };
+
+        match state {
+            Some(state) => self.set_current(sender_id, state),
+            None => false,
+        }
+    }
+
+    /// The cheapest message that brings `receiver_id` up to the current state.
+    ///
+    /// A delta needs a known base of the same length and is only used while it is smaller than the full state.
+    pub fn update_for(&self, receiver_id: PeerId) -> Option<Message> {
+        let current = self.current.as_ref()?;
+
+        match self.bases.get(&receiver_id) {
+            Some(base) if Arc::ptr_eq(base, current) => None,
+            Some(base) if base.len() == current.len() => {
+                let delta = DeltaSnapshot::new(base, current);
+
+                /* Each changed byte costs at least an offset and a value. */
+                if delta.len() * 2 < current.len() {
+                    Some(Message::DeltaSnapshot(delta))
+                } else {
+                    Some(Message::Snapshot(current.to_vec()))
+                }
+            }
+            _ => Some(Message::Snapshot(current.to_vec())),
+        }
+    }
+
+    /// Records that `receiver_id` was sent the current state.
+    pub fn delivered(&mut self, receiver_id: PeerId) {
+        if let Some(current) = &self.current {
+            self.bases.insert(receiver_id, current.clone());
+        }
+    }
+
+    /// Forgets the base of `id`, so its next update is a full snapshot.
+    pub fn forget(&mut self, id: PeerId) {
+        self.bases.remove(&id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::session::SessionState;
+
+    use ::network::{DeltaSnapshot, Message};
+
+    #[test]
+    fn test_unknown_base_gets_snapshot() {
+        let mut session = SessionState::new();
+        assert!(session.update_for(1).is_none());
+
+        session.set_current(0, vec![1; 64]);
+
+        match session.update_for(1) {
+            Some(Message::Snapshot(snapshot)) => assert_eq!(snapshot, vec![1; 64]),
+            other => panic!("Expected Snapshot but got {:?}", other),
+        }
+        assert!(session.update_for(0).is_none());
+    }
+
+    #[test]
+    fn test_known_base_gets_delta() {
+        let mut session = SessionState::new();
+
+        session.set_current(0, vec![1; 64]);
+        session.delivered(1);
+
+        let mut new = vec![1; 64];
+        new[7] = 2;
+        session.set_current(0, new.clone());
+
+        match session.update_for(1) {
+            Some(Message::DeltaSnapshot(delta)) => assert_eq!(delta.apply(&[1; 64]), new),
+            other => panic!("Expected DeltaSnapshot but got {:?}", other),
+        }
+
+        session.forget(1);
+        assert!(matches!(session.update_for(1), Some(Message::Snapshot(_))));
+    }
+
+    #[test]
+    fn test_large_delta_gets_snapshot() {
+        let mut session = SessionState::new();
+
+        session.set_current(0, vec![1; 64]);
+        session.delivered(1);
+        session.set_current(0, vec![2; 64]);
+
+        assert!(matches!(session.update_for(1), Some(Message::Snapshot(_))));
+    }
+
+    #[test]
+    fn test_delta_is_applied_to_senders_base() {
+        let mut session = SessionState::new();
+
+        let old = vec![3; 32];
+        let mut new = old.clone();
+        new[0] = 4;
+
+        assert!(!session.apply_delta(0, DeltaSnapshot::new(&old, &new)));
+
+        session.set_current(0, old.clone());
+        assert!(session.apply_delta(0, DeltaSnapshot::new(&old, &new)));
+        assert_eq!(session.current(), Some(new.as_ref()));
+    }
+
+    #[test]
+    fn test_delta_outside_base_is_rejected() {
+        let mut session = SessionState::new();
+
+        let mut longer = vec![3; 1024];
+        longer[600] = 4;
+
+        session.set_current(0, vec![3; 32]);
+        assert!(!session.apply_delta(0, DeltaSnapshot::new(&[3; 1024], &longer)));
+        assert_eq!(session.current(), Some([3; 32].as_ref()));
+    }
+
+    #[test]
+    fn test_invalid_state_is_rejected_with_validation() {
+        let mut session = SessionState::with_state_validation();
+
+        assert!(!session.set_current(0, vec![1; 64]));
+        assert!(session.current().is_none());
+        assert!(session.update_for(1).is_none());
+    }
+}
@@ -0,0 +1,79 @@
+//! The relay fixture shared by the server's and the client's integration tests.
+#![allow(dead_code)] // Each test crate only uses some of the helpers.
+
+use ::tokio::{
+    net::TcpStream,
+    sync::{oneshot, RwLock},
+    time::{sleep, timeout},
+};
+
+use ::tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+use ::std::{net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+
+use ::server::Services;
+
+pub type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+pub const RECEIVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A relay serving on a free local port until it is dropped.
+pub struct Relay {
+    pub addr: SocketAddr,
+    pub services: Arc<RwLock<Services>>,
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl Relay {
+    pub fn start() -> Relay {
+        let services = Arc::new(RwLock::new(Services::new()));
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+
+        let (addr, server) = server::serve(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+            services.clone(),
+            ([127, 0, 0, 1], 0),
+            async {
+                shutdown_rx.await.ok();
+            },
+        );
+        tokio::spawn(server);
+
+        Relay {
+            addr,
+            services,
+            _shutdown: shutdown,
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("ws://{}/websocket", self.addr)
+    }
+
+    /// Connects a new client and waits until the relay has registered it as a listener.
+    pub async fn connect(&self) -> Client {
+        let expected = self.listener_count().await + 1;
+
+        let (client, _) = tokio_tungstenite::connect_async(self.url())
+            .await
+            .expect("Failed to connect");
+
+        self.wait_for_listeners(expected).await;
+
+        client
+    }
+
+    pub async fn listener_count(&self) -> usize {
+        self.services.read().await.listener_count()
+    }
+
+    pub async fn wait_for_listeners(&self, expected: usize) {
+        timeout(RECEIVE_TIMEOUT, async {
+            while self.listener_count().await != expected {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("Relay never reached {} listeners", expected));
+    }
+}
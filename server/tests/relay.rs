@@ -0,0 +1,690 @@
+mod common;
+
+use ::futures_util::{SinkExt, StreamExt};
+
+use ::tokio::time::{sleep, timeout};
+
+use ::tokio_tungstenite::tungstenite;
+
+use ::std::time::Duration;
+
+use ::network::{DeltaSnapshot, IceCandidate, Message, PeerId, SioWord};
+
+use crate::common::{Client, Relay, RECEIVE_TIMEOUT};
+
+const SILENCE_TIMEOUT: Duration = Duration::from_millis(250);
+
+async fn send(client: &mut Client, message: Message) {
+    let data: String = message.try_into().expect("Failed to serialize");
+
+    client
+        .send(tungstenite::Message::Text(data))
+        .await
+        .expect("Failed to send");
+}
+
+async fn receive(client: &mut Client) -> Message {
+    let message = timeout(RECEIVE_TIMEOUT, client.next())
+        .await
+        .expect("Timed out waiting for message")
+        .expect("Stream ended")
+        .expect("Websocket error");
+
+    match message {
+        tungstenite::Message::Text(data) => {
+            Message::try_from(data.as_str()).expect("Failed to deserialize")
+        }
+        other => panic!("Unexpected frame: {:?}", other),
+    }
+}
+
+/// Joins WebRTC signaling and returns the client's own id with the peers that joined before it.
+async fn join_rtc(client: &mut Client) -> (PeerId, Vec<PeerId>) {
+    send(client, Message::RtcJoin).await;
+
+    match receive(client).await {
+        Message::RtcPeers { you, peers } => (you, peers),
+        other => panic!("Expected RtcPeers but got {:?}", other),
+    }
+}
+
+async fn receive_link_status(client: &mut Client) -> (u8, u8) {
+    match receive(client).await {
+        Message::LinkStatus { player, connected } => (player, connected),
+        other => panic!("Expected LinkStatus but got {:?}", other),
+    }
+}
+
+/// Plugs each client into the link cable in order and drains the status updates.
+async fn join_link(clients: &mut [&mut Client]) {
+    for joined in 0..clients.len() {
+        send(clients[joined], Message::LinkJoin).await;
+
+        for client in clients[..=joined].iter_mut() {
+            receive_link_status(client).await;
+        }
+    }
+}
+
+async fn assert_silent(client: &mut Client) {
+    if let Ok(message) = timeout(SILENCE_TIMEOUT, client.next()).await {
+        panic!("Expected no message but got {:?}", message);
+    }
+}
+
+/// Waits for the relay to drop the connection, either with a close frame or by ending the stream.
+async fn assert_closed(client: &mut Client) {
+    loop {
+        match timeout(RECEIVE_TIMEOUT, client.next())
+            .await
+            .expect("Timed out waiting for close")
+        {
+            None | Some(Err(_)) | Some(Ok(tungstenite::Message::Close(_))) => return,
+            Some(Ok(tungstenite::Message::Ping(_))) | Some(Ok(tungstenite::Message::Pong(_))) => {}
+            Some(Ok(other)) => panic!("Expected close but got {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_sender_is_excluded() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver1 = relay.connect().await;
+    let mut receiver2 = relay.connect().await;
+
+    send(&mut sender, Message::Rom(vec![1, 2, 3])).await;
+
+    for receiver in [&mut receiver1, &mut receiver2] {
+        match receive(receiver).await {
+            Message::Rom(rom) => assert_eq!(rom, vec![1, 2, 3]),
+            other => panic!("Expected Rom but got {:?}", other),
+        }
+    }
+
+    assert_silent(&mut sender).await;
+}
+
+#[tokio::test]
+async fn test_rom_is_delivered() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let rom: Vec<u8> = (0..=u8::MAX).cycle().take(4096).collect();
+    send(&mut sender, Message::Rom(rom.clone())).await;
+
+    match receive(&mut receiver).await {
+        Message::Rom(received) => assert_eq!(received, rom),
+        other => panic!("Expected Rom but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_bios_is_delivered() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let bios: Vec<u8> = (0..=u8::MAX).rev().cycle().take(16384).collect();
+    send(&mut sender, Message::Bios(bios.clone())).await;
+
+    match receive(&mut receiver).await {
+        Message::Bios(received) => assert_eq!(received, bios),
+        other => panic!("Expected Bios but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_snapshot_is_delivered() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let snapshot = vec![7u8; 1024];
+    send(&mut sender, Message::Snapshot(snapshot.clone())).await;
+
+    match receive(&mut receiver).await {
+        Message::Snapshot(received) => assert_eq!(received, snapshot),
+        other => panic!("Expected Snapshot but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_delta_snapshot_is_delivered() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let old: Vec<u8> = (0..=u8::MAX).cycle().take(2048).collect();
+    let mut new = old.clone();
+    new[3] = 0xAA;
+    new[1000] = 0xBB;
+    new[2047] = 0xCC;
+
+    send(&mut sender, Message::Snapshot(old.clone())).await;
+    let _ = receive(&mut receiver).await;
+
+    send(
+        &mut sender,
+        Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new)),
+    )
+    .await;
+
+    match receive(&mut receiver).await {
+        Message::DeltaSnapshot(delta) => {
+            assert_eq!(delta.len(), 3);
+            assert_eq!(delta.apply(&old), new);
+        }
+        other => panic!("Expected DeltaSnapshot but got {:?}", other),
+    }
+
+    assert_eq!(relay.services.read().await.current_state(), Some(new.as_ref()));
+}
+
+#[tokio::test]
+async fn test_delta_snapshot_without_base_is_dropped() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let old = vec![1u8; 64];
+    let mut new = old.clone();
+    new[1] = 2;
+
+    send(
+        &mut sender,
+        Message::DeltaSnapshot(DeltaSnapshot::new(&old, &new)),
+    )
+    .await;
+
+    assert_silent(&mut receiver).await;
+    assert_eq!(relay.services.read().await.current_state(), None);
+}
+
+#[tokio::test]
+async fn test_snapshots_become_per_receiver_deltas() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut early = relay.connect().await;
+
+    let first: Vec<u8> = (0..=u8::MAX).cycle().take(1024).collect();
+    let mut second = first.clone();
+    second[10] = 0;
+    let mut third = second.clone();
+    third[20] = 0;
+
+    send(&mut sender, Message::Snapshot(first.clone())).await;
+    match receive(&mut early).await {
+        Message::Snapshot(received) => assert_eq!(received, first),
+        other => panic!("Expected Snapshot but got {:?}", other),
+    }
+
+    send(&mut sender, Message::Snapshot(second.clone())).await;
+    match receive(&mut early).await {
+        Message::DeltaSnapshot(delta) => {
+            assert_eq!(delta.len(), 1);
+            assert_eq!(delta.apply(&first), second);
+        }
+        other => panic!("Expected DeltaSnapshot but got {:?}", other),
+    }
+
+    // A receiver without a base gets the full state while the others keep getting deltas.
+    let mut late = relay.connect().await;
+
+    send(&mut sender, Message::Snapshot(third.clone())).await;
+    match receive(&mut early).await {
+        Message::DeltaSnapshot(delta) => assert_eq!(delta.apply(&second), third),
+        other => panic!("Expected DeltaSnapshot but got {:?}", other),
+    }
+    match receive(&mut late).await {
+        Message::Snapshot(received) => assert_eq!(received, third),
+        other => panic!("Expected Snapshot but got {:?}", other),
+    }
+
+    assert_silent(&mut sender).await;
+}
+
+#[tokio::test]
+async fn test_snapshot_request_resends_full_state() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    send(&mut receiver, Message::SnapshotRequest).await;
+    assert_silent(&mut receiver).await;
+
+    send(&mut sender, Message::Snapshot(vec![5u8; 128])).await;
+    let _ = receive(&mut receiver).await;
+
+    send(&mut receiver, Message::SnapshotRequest).await;
+    match receive(&mut receiver).await {
+        Message::Snapshot(received) => assert_eq!(received, vec![5u8; 128]),
+        other => panic!("Expected Snapshot but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_play_is_delivered() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    send(&mut sender, Message::Play(vec![9, 8, 7])).await;
+
+    match receive(&mut receiver).await {
+        Message::Play(received) => assert_eq!(received, vec![9, 8, 7]),
+        other => panic!("Expected Play but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_disconnect_removes_listener() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut leaver = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    leaver.close(None).await.expect("Failed to close");
+    relay.wait_for_listeners(2).await;
+
+    send(&mut sender, Message::Snapshot(vec![1])).await;
+
+    match receive(&mut receiver).await {
+        Message::Snapshot(received) => assert_eq!(received, vec![1]),
+        other => panic!("Expected Snapshot but got {:?}", other),
+    }
+
+    drop(receiver);
+    relay.wait_for_listeners(1).await;
+
+    drop(sender);
+    relay.wait_for_listeners(0).await;
+}
+
+#[tokio::test]
+async fn test_unparsable_message_closes_connection() {
+    let relay = Relay::start();
+
+    let mut bad = relay.connect().await;
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    bad.send(tungstenite::Message::Text("not a message".to_string()))
+        .await
+        .expect("Failed to send");
+
+    assert_closed(&mut bad).await;
+    relay.wait_for_listeners(2).await;
+
+    send(&mut sender, Message::Bios(vec![4, 5, 6])).await;
+
+    match receive(&mut receiver).await {
+        Message::Bios(received) => assert_eq!(received, vec![4, 5, 6]),
+        other => panic!("Expected Bios but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_binary_message_closes_connection() {
+    let relay = Relay::start();
+
+    let mut bad = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    let data: Vec<u8> = Message::Rom(vec![1]).try_into().expect("Failed to serialize");
+    bad.send(tungstenite::Message::Binary(data))
+        .await
+        .expect("Failed to send");
+
+    assert_closed(&mut bad).await;
+    relay.wait_for_listeners(1).await;
+
+    assert_silent(&mut receiver).await;
+}
+
+#[tokio::test]
+async fn test_rtc_join_announces_peers() {
+    let relay = Relay::start();
+
+    let mut first = relay.connect().await;
+    let mut second = relay.connect().await;
+    let mut bystander = relay.connect().await;
+
+    let (first_id, peers) = join_rtc(&mut first).await;
+    assert!(peers.is_empty());
+
+    let (second_id, peers) = join_rtc(&mut second).await;
+    assert_eq!(peers, vec![first_id]);
+
+    match receive(&mut first).await {
+        Message::RtcPeerJoined(peer) => assert_eq!(peer, second_id),
+        other => panic!("Expected RtcPeerJoined but got {:?}", other),
+    }
+
+    assert_silent(&mut bystander).await;
+}
+
+#[tokio::test]
+async fn test_rtc_signals_are_routed_to_named_peer() {
+    let relay = Relay::start();
+
+    let mut caller = relay.connect().await;
+    let mut callee = relay.connect().await;
+    let mut other = relay.connect().await;
+
+    let (caller_id, _) = join_rtc(&mut caller).await;
+    let (callee_id, _) = join_rtc(&mut callee).await;
+    let _ = receive(&mut caller).await;
+    let _ = join_rtc(&mut other).await;
+    let _ = receive(&mut caller).await;
+    let _ = receive(&mut callee).await;
+
+    send(
+        &mut caller,
+        Message::RtcOffer {
+            peer: callee_id,
+            sdp: "offer".to_string(),
+        },
+    )
+    .await;
+
+    match receive(&mut callee).await {
+        Message::RtcOffer { peer, sdp } => {
+            assert_eq!(peer, caller_id);
+            assert_eq!(sdp, "offer");
+        }
+        other => panic!("Expected RtcOffer but got {:?}", other),
+    }
+
+    send(
+        &mut callee,
+        Message::RtcAnswer {
+            peer: caller_id,
+            sdp: "answer".to_string(),
+        },
+    )
+    .await;
+
+    match receive(&mut caller).await {
+        Message::RtcAnswer { peer, sdp } => {
+            assert_eq!(peer, callee_id);
+            assert_eq!(sdp, "answer");
+        }
+        other => panic!("Expected RtcAnswer but got {:?}", other),
+    }
+
+    let candidate = IceCandidate {
+        candidate: "candidate:1 1 udp 1 127.0.0.1 9 typ host".to_string(),
+        sdp_mid: Some("0".to_string()),
+        sdp_m_line_index: Some(0),
+    };
+    send(
+        &mut caller,
+        Message::RtcIceCandidate {
+            peer: callee_id,
+            candidate: candidate.clone(),
+        },
+    )
+    .await;
+
+    match receive(&mut callee).await {
+        Message::RtcIceCandidate {
+            peer,
+            candidate: received,
+        } => {
+            assert_eq!(peer, caller_id);
+            assert_eq!(received, candidate);
+        }
+        other => panic!("Expected RtcIceCandidate but got {:?}", other),
+    }
+
+    assert_silent(&mut other).await;
+}
+
+#[tokio::test]
+async fn test_rtc_signal_to_missing_peer_is_rejected() {
+    let relay = Relay::start();
+
+    let mut caller = relay.connect().await;
+    let (caller_id, _) = join_rtc(&mut caller).await;
+
+    for peer in [caller_id, caller_id + 100] {
+        send(
+            &mut caller,
+            Message::RtcOffer {
+                peer,
+                sdp: "offer".to_string(),
+            },
+        )
+        .await;
+
+        match receive(&mut caller).await {
+            Message::RtcPeerLeft(left) => assert_eq!(left, peer),
+            other => panic!("Expected RtcPeerLeft but got {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_rtc_signals_need_both_peers_joined() {
+    let relay = Relay::start();
+
+    let mut joined = relay.connect().await;
+    let mut outsider = relay.connect().await;
+
+    let (joined_id, _) = join_rtc(&mut joined).await;
+    // Ids are handed out in connection order.
+    let outsider_id = joined_id + 1;
+
+    send(
+        &mut outsider,
+        Message::RtcOffer {
+            peer: joined_id,
+            sdp: "offer".to_string(),
+        },
+    )
+    .await;
+    match receive(&mut outsider).await {
+        Message::RtcPeerLeft(left) => assert_eq!(left, joined_id),
+        other => panic!("Expected RtcPeerLeft but got {:?}", other),
+    }
+
+    send(
+        &mut joined,
+        Message::RtcOffer {
+            peer: outsider_id,
+            sdp: "offer".to_string(),
+        },
+    )
+    .await;
+    match receive(&mut joined).await {
+        Message::RtcPeerLeft(left) => assert_eq!(left, outsider_id),
+        other => panic!("Expected RtcPeerLeft but got {:?}", other),
+    }
+
+    assert_silent(&mut joined).await;
+    assert_silent(&mut outsider).await;
+}
+
+#[tokio::test]
+async fn test_rtc_disconnect_announces_peer_left() {
+    let relay = Relay::start();
+
+    let mut staying = relay.connect().await;
+    let mut leaving = relay.connect().await;
+
+    let _ = join_rtc(&mut staying).await;
+    let (leaving_id, _) = join_rtc(&mut leaving).await;
+    let _ = receive(&mut staying).await;
+
+    drop(leaving);
+    relay.wait_for_listeners(1).await;
+
+    match receive(&mut staying).await {
+        Message::RtcPeerLeft(peer) => assert_eq!(peer, leaving_id),
+        other => panic!("Expected RtcPeerLeft but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_link_join_assigns_player_numbers() {
+    let relay = Relay::start();
+
+    let mut clients = Vec::new();
+    for _ in 0..5 {
+        clients.push(relay.connect().await);
+    }
+
+    for (joined, client) in clients.iter_mut().take(4).enumerate() {
+        send(client, Message::LinkJoin).await;
+        assert_eq!(
+            receive_link_status(client).await,
+            (joined as u8, (1 << (joined + 1)) - 1)
+        );
+    }
+
+    send(&mut clients[4], Message::LinkJoin).await;
+    match receive(&mut clients[4]).await {
+        Message::LinkFull => {}
+        other => panic!("Expected LinkFull but got {:?}", other),
+    }
+
+    for (number, client) in clients.iter_mut().take(3).enumerate() {
+        for joined in (number + 1)..4 {
+            assert_eq!(
+                receive_link_status(client).await,
+                (number as u8, (1 << (joined + 1)) - 1)
+            );
+        }
+    }
+
+    let parent = clients.remove(0);
+    drop(parent);
+    relay.wait_for_listeners(4).await;
+
+    for (number, client) in clients.iter_mut().take(3).enumerate() {
+        assert_eq!(receive_link_status(client).await, (number as u8 + 1, 0b1110));
+    }
+}
+
+#[tokio::test]
+async fn test_link_multiplayer_transfer() {
+    let relay = Relay::start();
+
+    let mut parent = relay.connect().await;
+    let mut child1 = relay.connect().await;
+    let mut child2 = relay.connect().await;
+    let mut bystander = relay.connect().await;
+
+    join_link(&mut [&mut parent, &mut child1, &mut child2]).await;
+
+    send(&mut child1, Message::LinkSend(SioWord::Multiplayer(0x1111))).await;
+    send(&mut child2, Message::LinkSend(SioWord::Multiplayer(0x2222))).await;
+    // `LinkSend` is not acknowledged and each connection is handled by its own task.
+    sleep(SILENCE_TIMEOUT).await;
+
+    // Only the parent clocks multiplayer transfers.
+    send(&mut child1, Message::LinkStart(SioWord::Multiplayer(0xBAD))).await;
+    send(&mut parent, Message::LinkStart(SioWord::Multiplayer(0x0000))).await;
+
+    for client in [&mut parent, &mut child1, &mut child2] {
+        match receive(client).await {
+            Message::LinkMultiplayerResult(data) => {
+                assert_eq!(data, [0x0000, 0x1111, 0x2222, 0xFFFF])
+            }
+            other => panic!("Expected LinkMultiplayerResult but got {:?}", other),
+        }
+    }
+
+    assert_silent(&mut bystander).await;
+}
+
+#[tokio::test]
+async fn test_link_normal_transfer() {
+    let relay = Relay::start();
+
+    let mut parent = relay.connect().await;
+    let mut child = relay.connect().await;
+
+    send(&mut parent, Message::LinkJoin).await;
+    receive_link_status(&mut parent).await;
+
+    // Nothing on the other end of the cable reads as all ones.
+    send(&mut parent, Message::LinkStart(SioWord::Normal8(0x12))).await;
+    match receive(&mut parent).await {
+        Message::LinkNormalResult(word) => assert_eq!(word, SioWord::Normal8(0xFF)),
+        other => panic!("Expected LinkNormalResult but got {:?}", other),
+    }
+
+    send(&mut child, Message::LinkJoin).await;
+    receive_link_status(&mut parent).await;
+    receive_link_status(&mut child).await;
+
+    send(&mut child, Message::LinkSend(SioWord::Normal32(0xCAFEBABE))).await;
+    sleep(SILENCE_TIMEOUT).await;
+    send(&mut parent, Message::LinkStart(SioWord::Normal32(0xDEADBEEF))).await;
+
+    match receive(&mut parent).await {
+        Message::LinkNormalResult(word) => assert_eq!(word, SioWord::Normal32(0xCAFEBABE)),
+        other => panic!("Expected LinkNormalResult but got {:?}", other),
+    }
+    match receive(&mut child).await {
+        Message::LinkNormalResult(word) => assert_eq!(word, SioWord::Normal32(0xDEADBEEF)),
+        other => panic!("Expected LinkNormalResult but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_link_uart_reaches_other_players() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+    let mut bystander = relay.connect().await;
+
+    join_link(&mut [&mut sender, &mut receiver]).await;
+
+    send(&mut sender, Message::LinkUart(b"hello".to_vec())).await;
+
+    match receive(&mut receiver).await {
+        Message::LinkUart(bytes) => assert_eq!(bytes, b"hello".to_vec()),
+        other => panic!("Expected LinkUart but got {:?}", other),
+    }
+
+    assert_silent(&mut sender).await;
+    assert_silent(&mut bystander).await;
+}
+
+#[tokio::test]
+async fn test_cheats_reach_players_that_join_later() {
+    let relay = Relay::start();
+
+    let mut sender = relay.connect().await;
+    let mut receiver = relay.connect().await;
+
+    send(&mut sender, Message::Cheats(vec![1, 2, 3])).await;
+
+    match receive(&mut receiver).await {
+        Message::Cheats(cheats) => assert_eq!(cheats, vec![1, 2, 3]),
+        other => panic!("Expected Cheats but got {:?}", other),
+    }
+    assert_silent(&mut sender).await;
+
+    let mut late = relay.connect().await;
+
+    match receive(&mut late).await {
+        Message::Cheats(cheats) => assert_eq!(cheats, vec![1, 2, 3]),
+        other => panic!("Expected Cheats but got {:?}", other),
+    }
+}
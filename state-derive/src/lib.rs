@@ -0,0 +1,296 @@
+//! `#[derive(JsObjectCodec)]` for the structs `state` maps to and from the emulator's JS save-state objects.
+//!
+//! It implements `state::schema::Schema`, which `state-wasm` maps onto JS objects through its `JsObjectCodec`.
+//!
+//! Every field needs a `#[js(key = "...")]` naming the JS property it is read from and written to:
+//!
+//! ```ignore
+//! #[derive(JsObjectCodec)]
+//! struct SaveState {
+//!     #[js(key = "IOCore.ARM.decode")]
+//!     iocore_arm_decode: i32,
+//!     /// An array of objects that derive `JsObjectCodec` themselves.
+//!     #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMTable", array)]
+//!     oamtable: Vec<OamTableEntry>,
+//!     /// `view`s are only encoded, from the field through the `with` function.
+//!     #[js(key = "IOCore.memory.internalRAM")]
+//!     #[js(view(key = "IOCore.memory.internalRAM16", with = vu8_to_vu16))]
+//!     iocore_memory_internal_ram: Vec<u8>,
+//! }
+//! ```
+//!
+//! A key used twice within a struct is a compile error.
+//!
+//! The generated code refers to `crate::schema`, so it only works inside `state`.
+
+use ::proc_macro2::TokenStream;
+
+use ::quote::quote;
+
+use ::std::collections::BTreeMap;
+
+use ::syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, Ident, LitStr, Path};
+
+#[proc_macro_derive(JsObjectCodec, attributes(js))]
+pub fn derive_js_object_codec(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// An encode-only property computed from a field, e.g. the 16-bit view of an 8-bit memory.
+struct View {
+    key: LitStr,
+    with: Path,
+}
+
+struct Field {
+    ident: Ident,
+    key: LitStr,
+    array: bool,
+    views: Vec<View>,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "`JsObjectCodec` needs a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "`JsObjectCodec` can only be derived for structs",
+            ))
+        }
+    };
+
+    let fields = fields
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<Field>>>()?;
+
+    check_duplicate_keys(&fields)?;
+
+    let name = &input.ident;
+
+    let decode_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let key = &field.key;
+
+        if field.array {
+            quote! { #ident: decoder.array(#key) }
+        } else {
+            quote! { #ident: decoder.field(#key) }
+        }
+    });
+
+    let decode_fields_into = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let key = &field.key;
+
+        if field.array {
+            quote! { self.#ident = decoder.array(#key); }
+        } else {
+            quote! { decoder.field_into(#key, &mut self.#ident); }
+        }
+    });
+
+    /* Views borrow their field, so they are encoded before the fields are moved out of `self`. */
+    let encode_views = fields.iter().flat_map(|field| {
+        let ident = &field.ident;
+
+        field.views.iter().map(move |View { key, with }| {
+            quote! { encoder.field(#key, #with(&self.#ident)); }
+        })
+    });
+
+    let encode_fields = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let key = &field.key;
+
+        if field.array {
+            quote! { encoder.array(#key, self.#ident); }
+        } else {
+            quote! { encoder.field(#key, self.#ident); }
+        }
+    });
+
+    Ok(quote! {
+        impl crate::schema::Schema for #name {
+            fn decode<D: crate::schema::Decoder>(decoder: &mut D) -> Self {
+                #name {
+                    #(#decode_fields,)*
+                }
+            }
+
+            fn encode<E: crate::schema::Encoder>(self, encoder: &mut E) {
+                #(#encode_views)*
+                #(#encode_fields)*
+            }
+
+            fn decode_into<D: crate::schema::Decoder>(&mut self, decoder: &mut D) {
+                #(#decode_fields_into)*
+            }
+        }
+    })
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+    let ident = field.ident.clone().expect("Named fields have an ident");
+    let mut key = None;
+    let mut array = false;
+    let mut views = Vec::new();
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("key") {
+                if key.is_some() {
+                    return Err(meta.error("`key` is already set"));
+                }
+                key = Some(meta.value()?.parse::<LitStr>()?);
+            } else if meta.path.is_ident("array") {
+                array = true;
+            } else if meta.path.is_ident("view") {
+                let mut view_key = None;
+                let mut with = None;
+
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("key") {
+                        view_key = Some(meta.value()?.parse::<LitStr>()?);
+                    } else if meta.path.is_ident("with") {
+                        with = Some(meta.value()?.parse::<Path>()?);
+                    } else {
+                        return Err(meta.error("expected `key` or `with`"));
+                    }
+
+                    Ok(())
+                })?;
+
+                match (view_key, with) {
+                    (Some(key), Some(with)) => views.push(View { key, with }),
+                    _ => return Err(meta.error("a `view` needs both `key` and `with`")),
+                }
+            } else {
+                return Err(meta.error("expected `key`, `array` or `view`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    match key {
+        Some(key) => Ok(Field {
+            ident,
+            key,
+            array,
+            views,
+        }),
+        None => Err(syn::Error::new(
+            field.span(),
+            format!("`{}` needs a `#[js(key = \"...\")]` attribute", ident),
+        )),
+    }
+}
+
+fn check_duplicate_keys(fields: &[Field]) -> syn::Result<()> {
+    let mut seen: BTreeMap<String, &Ident> = BTreeMap::new();
+
+    let keys = fields.iter().flat_map(|field| {
+        ::std::iter::once(&field.key)
+            .chain(field.views.iter().map(|view| &view.key))
+            .map(move |key| (key, &field.ident))
+    });
+
+    for (key, ident) in keys {
+        if let Some(first) = seen.insert(key.value(), ident) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!(
+                    "duplicate key \"{}\", already used by `{}`",
+                    key.value(),
+                    first
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::expand;
+
+    use ::syn::{parse_quote, DeriveInput};
+
+    #[test]
+    fn test_expand_fields_and_views() {
+        let input: DeriveInput = parse_quote! {
+            struct State {
+                #[js(key = "IOCore.memory.internalRAM")]
+                #[js(view(key = "IOCore.memory.internalRAM16", with = vu8_to_vu16))]
+                internal_ram: Vec<u8>,
+                #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMTable", array)]
+                oamtable: Vec<OamTableEntry>,
+            }
+        };
+
+        let output = expand(&input).expect("Valid input").to_string();
+
+        assert!(output.contains("internal_ram : decoder . field (\"IOCore.memory.internalRAM\")"));
+        assert!(output.contains(
+            "decoder . field_into (\"IOCore.memory.internalRAM\" , & mut self . internal_ram)"
+        ));
+        assert!(output.contains(
+            "encoder . field (\"IOCore.memory.internalRAM16\" , vu8_to_vu16 (& self . internal_ram))"
+        ));
+        assert!(output.contains(
+            "encoder . array (\"IOCore.gfxRenderer.renderer.objRenderer.OAMTable\" , self . oamtable)"
+        ));
+    }
+
+    #[test]
+    fn test_duplicate_keys_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            struct State {
+                #[js(key = "IOCore.memory.internalRAM")]
+                #[js(view(key = "IOCore.memory.internalRAM16", with = vu8_to_vu16))]
+                internal_ram: Vec<u8>,
+                #[js(key = "IOCore.memory.internalRAM16")]
+                internal_ram16: Vec<u16>,
+            }
+        };
+
+        let error = expand(&input).expect_err("Duplicate key");
+
+        assert_eq!(
+            error.to_string(),
+            "duplicate key \"IOCore.memory.internalRAM16\", already used by `internal_ram`"
+        );
+    }
+
+    #[test]
+    fn test_missing_key_is_rejected() {
+        let input: DeriveInput = parse_quote! {
+            struct State {
+                #[js(array)]
+                oamtable: Vec<OamTableEntry>,
+            }
+        };
+
+        let error = expand(&input).expect_err("Missing key");
+
+        assert_eq!(
+            error.to_string(),
+            "`oamtable` needs a `#[js(key = \"...\")]` attribute"
+        );
+    }
+}
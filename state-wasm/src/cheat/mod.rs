@@ -0,0 +1,127 @@
+//! `state::cheat` for the emulator, which applies the patches every frame.
+
+use ::state::cheat::{self, Cheat, CheatList, Device, Operation};
+use ::state::format;
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::js_error;
+
+/// The active cheats of a player, shared with the session as `to_bytes`.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct CheatEngine {
+    list: CheatList,
+    /// The operations of the enabled cheats, decoded once instead of every frame.
+    operations: Vec<Operation>,
+}
+
+#[wasm_bindgen]
+impl CheatEngine {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> CheatEngine {
+        CheatEngine::default()
+    }
+
+    /// A list from `to_bytes`, e.g. of another player.
+    pub fn from_bytes(data: &[u8]) -> Result<CheatEngine, JsValue> {
+        let list = CheatList::from_bytes(data).map_err(js_error)?;
+        let operations = list.operations().map_err(js_error)?;
+
+        Ok(CheatEngine { list, operations })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, JsValue> {
+        self.list.to_bytes().map_err(js_error)
+    }
+
+    /// Adds an enabled cheat for `device` (`"gameshark-v1"`, `"gameshark-v3"`, `"actionreplay-v1"`,
+    /// `"actionreplay-v3"` or `"codebreaker"`), returning its index. Codes that do not decode are rejected.
+    pub fn add(&mut self, name: String, device: &str, codes: String) -> Result<usize, JsValue> {
+        let device = Device::from_name(device)
+            .ok_or_else(|| js_error(format_args!("Unknown device {:?}", device)))?;
+        let cheat = Cheat {
+            name,
+            device,
+            codes,
+            enabled: true,
+        };
+        cheat.operations().map_err(js_error)?;
+
+        self.list.cheats.push(cheat);
+        self.update()?;
+
+        Ok(self.list.cheats.len() - 1)
+    }
+
+    pub fn remove(&mut self, index: usize) -> Result<(), JsValue> {
+        if index < self.list.cheats.len() {
+            self.list.cheats.remove(index);
+        }
+
+        self.update()
+    }
+
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) -> Result<(), JsValue> {
+        if let Some(cheat) = self.list.cheats.get_mut(index) {
+            cheat.enabled = enabled;
+        }
+
+        self.update()
+    }
+
+    /// Whether any enabled cheat has operations, i.e. `patches` can return anything.
+    pub fn is_active(&self) -> bool {
+        !self.operations.is_empty()
+    }
+
+    pub fn count(&self) -> usize {
+        self.list.cheats.len()
+    }
+
+    pub fn name(&self, index: usize) -> Option<String> {
+        Some(self.list.cheats.get(index)?.name.clone())
+    }
+
+    pub fn codes(&self, index: usize) -> Option<String> {
+        Some(self.list.cheats.get(index)?.codes.clone())
+    }
+
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.list
+            .cheats
+            .get(index)
+            .is_some_and(|cheat| cheat.enabled)
+    }
+
+    /// The bytes to write this frame as `[address, value, address, value, ...]`, for the emulator's
+    /// `IOCore.memory.externalRAM` and `internalRAM`.
+    ///
+    /// Only the bytes the cheats touch are read from the arrays, instead of copying both RAMs into wasm every frame.
+    pub fn patches(&self, ewram: &js_sys::Uint8Array, iwram: &js_sys::Uint8Array) -> Vec<u32> {
+        cheat::patches_from(
+            &self.operations,
+            (ewram.length() as usize, |offset| ewram.get_index(offset as u32)),
+            (iwram.length() as usize, |offset| iwram.get_index(offset as u32)),
+        )
+            .iter()
+            .flat_map(|patch| [patch.address, patch.value as u32])
+            .collect()
+    }
+
+    /// A state written by `Snapshotter::serialize_to_uint8array` with the cheats applied to its RAM.
+    pub fn apply(&self, state: &[u8]) -> Result<Vec<u8>, JsValue> {
+        let mut save_state = format::read(state).map_err(js_error)?;
+        cheat::apply(&self.operations, &mut save_state);
+
+        format::write(&save_state).map_err(js_error)
+    }
+}
+
+impl CheatEngine {
+    fn update(&mut self) -> Result<(), JsValue> {
+        self.operations = self.list.operations().map_err(js_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+//! A `Host` of maps instead of JS objects, for testing the codecs natively.
+
+use ::state::generic::Value;
+use ::state::schema::TypedArrayMut;
+
+use ::std::collections::HashMap;
+
+use crate::encoder::{EncoderError, EncoderErrorKind, FieldErrors, Host};
+
+/// A JS object with `Value` properties and arrays of objects.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct MockObject {
+    pub(crate) values: HashMap<String, Value>,
+    pub(crate) arrays: HashMap<String, Vec<MockObject>>,
+}
+
+pub(crate) type MockArray = Vec<MockObject>;
+
+pub(crate) struct MockHost;
+
+impl Host for MockHost {
+    type Object = MockObject;
+    type Array = MockArray;
+
+    fn object(&self) -> MockObject {
+        MockObject::default()
+    }
+
+    fn array(&self) -> MockArray {
+        Vec::new()
+    }
+
+    fn get(
+        &self,
+        object: &MockObject,
+        key: &str,
+        _errors: &mut FieldErrors,
+    ) -> Result<Value, EncoderError> {
+        Ok(object.values.get(key).cloned().unwrap_or(Value::Undefined))
+    }
+
+    fn set(&self, object: &mut MockObject, key: &str, value: Value) {
+        object.values.insert(key.to_string(), value);
+    }
+
+    fn get_into(&self, object: &MockObject, key: &str, array: TypedArrayMut<'_>) -> bool {
+        match (object.values.get(key), array) {
+            (Some(Value::Int8Array(source)), TypedArrayMut::Int8Array(values)) => {
+                copy(source, values)
+            }
+            (Some(Value::Int16Array(source)), TypedArrayMut::Int16Array(values)) => {
+                copy(source, values)
+            }
+            (Some(Value::Int32Array(source)), TypedArrayMut::Int32Array(values)) => {
+                copy(source, values)
+            }
+            (Some(Value::Uint8Array(source)), TypedArrayMut::Uint8Array(values)) => {
+                copy(source, values)
+            }
+            (Some(Value::Uint16Array(source)), TypedArrayMut::Uint16Array(values)) => {
+                copy(source, values)
+            }
+            (Some(Value::Uint32Array(source)), TypedArrayMut::Uint32Array(values)) => {
+                copy(source, values)
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Maps have no typed arrays to keep, so this is `set`.
+    fn set_into(&self, object: &mut MockObject, key: &str, value: Value) {
+        self.set(object, key, value);
+    }
+
+    /// Keys without an array read as `undefined`.
+    fn get_array(&self, object: &MockObject, key: &str) -> Result<MockArray, EncoderError> {
+        object.arrays.get(key).cloned().ok_or_else(|| {
+            EncoderErrorKind::UnexpectedType {
+                expected: "an array",
+                actual: "undefined".to_string(),
+            }
+            .into()
+        })
+    }
+
+    fn set_array(&self, object: &mut MockObject, key: &str, array: MockArray) {
+        object.arrays.insert(key.to_string(), array);
+    }
+
+    fn length(&self, array: &MockArray, _errors: &mut FieldErrors) -> Result<Value, EncoderError> {
+        Ok(Value::Number(array.len() as f64))
+    }
+
+    /// Holes read as empty objects, as their properties are all `undefined`.
+    fn get_object(&self, array: &MockArray, index: usize) -> MockObject {
+        array.get(index).cloned().unwrap_or_default()
+    }
+
+    fn set_object(&self, array: &mut MockArray, index: usize, object: MockObject) {
+        if array.len() <= index {
+            array.resize_with(index + 1, MockObject::default);
+        }
+        array[index] = object;
+    }
+}
+
+/// Copies like `TypedArray.copyTo`, into the buffer `values` already has.
+fn copy<T: Copy>(source: &[T], values: &mut Vec<T>) {
+    values.clear();
+    values.extend_from_slice(source);
+}
@@ -1,8 +1,21 @@
+//! Maps `state`'s schema and generic values to and from JS objects.
+
 use ::backtrace::Backtrace;
 
-use ::wasm_bindgen::JsValue;
+use ::state::generic::{Record, Value};
+use ::state::schema::{Decoder, Encoder, FieldValue, Schema, TypedArrayMut, ValueError};
+
+use ::wasm_bindgen::{JsCast, JsValue};
+
+use ::std::fmt;
+
+use crate::{Array, Factory, Object};
+
+#[cfg(test)]
+pub(crate) mod mock;
 
 #[derive(Debug)]
+#[allow(dead_code)] // `stack` is only read through `Debug`.
 pub(crate) struct EncoderError {
     kind: EncoderErrorKind,
     stack: Backtrace,
@@ -15,6 +28,13 @@ impl EncoderError {
             stack: Backtrace::new(),
         }
     }
+
+    fn unexpected_type(expected: &'static str, value: &JsValue) -> Self {
+        EncoderError::new(EncoderErrorKind::UnexpectedType {
+            expected,
+            actual: js_type_name(value),
+        })
+    }
 }
 
 impl From<EncoderErrorKind> for EncoderError {
@@ -23,126 +43,998 @@ impl From<EncoderErrorKind> for EncoderError {
     }
 }
 
+impl From<ValueError> for EncoderError {
+    fn from(error: ValueError) -> Self {
+        EncoderError::new(EncoderErrorKind::Value(error))
+    }
+}
+
+impl fmt::Display for EncoderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            EncoderErrorKind::UnexpectedType { expected, actual } => {
+                write!(f, "expected {} but got {}", expected, actual)
+            }
+            EncoderErrorKind::Value(error) => write!(f, "{}", error),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum EncoderErrorKind {
-    JsValueNotF64,
-    JsValueNotI32(f64),
-    JsValueNotBool,
-    JsValueNotString,
+    UnexpectedType {
+        expected: &'static str,
+        actual: String,
+    },
+    Value(ValueError),
 }
 
-pub(crate) trait JsValueEncoder
-where
-    Self: Sized,
-{
-    fn encode(value: Self) -> Result<JsValue, EncoderError>;
-    fn decode(value: JsValue) -> Result<Self, EncoderError>;
+/// `null`, the constructor name of objects (e.g. `Int8Array`) or the `typeof` of anything else.
+fn js_type_name(value: &JsValue) -> String {
+    if value.is_null() {
+        return "null".to_string();
+    }
+
+    if value.is_object() {
+        let name = String::from(value.unchecked_ref::<js_sys::Object>().constructor().name());
+
+        if !name.is_empty() {
+            return name;
+        }
+    }
+
+    value.js_typeof().as_string().unwrap_or_default()
+}
+
+/// Every property that failed to decode, by path.
+///
+/// Failures are recorded instead of returned so a whole object is checked in one pass.
+#[derive(Debug, Default)]
+pub(crate) struct FieldErrors {
+    errors: Vec<(String, EncoderError)>,
 }
 
-impl JsValueEncoder for i32 {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(value))
+impl FieldErrors {
+    pub(crate) fn new() -> FieldErrors {
+        FieldErrors::default()
+    }
+
+    /// Records a failed `result` for `path` and carries on with the default value.
+    pub(crate) fn record<T: Default>(&mut self, path: &str, result: Result<T, EncoderError>) -> T {
+        match result {
+            Ok(value) => value,
+            Err(error) => {
+                self.errors.push((path.to_string(), error));
+                T::default()
+            }
+        }
+    }
+
+    /// Runs `f` on a nested object, recording its failures with their paths under `prefix`.
+    pub(crate) fn nested<T>(&mut self, prefix: &str, f: impl FnOnce(&mut FieldErrors) -> T) -> T {
+        let mut nested = FieldErrors::new();
+        let value = f(&mut nested);
+
+        self.errors.extend(
+            nested
+                .errors
+                .into_iter()
+                .map(|(path, error)| (format!("{}.{}", prefix, path), error)),
+        );
+
+        value
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        let f = value.as_f64().ok_or(EncoderErrorKind::JsValueNotF64)?;
+    pub(crate) fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for FieldErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} properties failed:", self.errors.len())?;
 
-        if f > (i32::MAX as f64) {
-            Err(EncoderErrorKind::JsValueNotI32(f))?
+        for (path, error) in &self.errors {
+            write!(f, "\n  {}: {}", path, error)?;
         }
 
-        Ok(f as i32)
+        Ok(())
     }
 }
 
-impl JsValueEncoder for f64 {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(value))
+/// Copies a JS typed array into the `TypedArrayMut` of its type, resized to fit.
+macro_rules! copy_to_typed_arrays {
+    ($value:expr, $target:expr, $($array:ident),*) => {
+        match $target {
+            $(
+                TypedArrayMut::$array(values) => match $value.dyn_ref::<js_sys::$array>() {
+                    Some(array) => {
+                        values.resize(array.length() as usize, 0);
+                        array.copy_to(values);
+                        true
+                    }
+                    None => false,
+                },
+            )*
+        }
+    };
+}
+
+/// Copies a typed-array `Value` into the JS typed array `$existing` and returns, if their types and lengths match.
+macro_rules! copy_from_typed_arrays {
+    ($existing:expr, $value:expr, $($array:ident),*) => {
+        match &$value {
+            $(
+                Value::$array(values) => {
+                    if let Some(array) = $existing.dyn_ref::<js_sys::$array>() {
+                        if array.length() as usize == values.len() {
+                            array.copy_from(values);
+                            return;
+                        }
+                    }
+                }
+            )*
+            _ => {}
+        }
+    };
+}
+
+/// The operations on the emulator's objects the codecs need.
+///
+/// `Factory` implements it with JS objects and `mock::MockHost` with maps, so the codecs are testable natively.
+pub(crate) trait Host {
+    type Object;
+    type Array;
+
+    fn object(&self) -> Self::Object;
+
+    fn array(&self) -> Self::Array;
+
+    /// The value of `key`, with the failures of nested values recorded in `errors`.
+    fn get(
+        &self,
+        object: &Self::Object,
+        key: &str,
+        errors: &mut FieldErrors,
+    ) -> Result<Value, EncoderError>;
+
+    fn set(&self, object: &mut Self::Object, key: &str, value: Value);
+
+    /// Copies the typed array at `key` into `array` if it is of the same type, returning whether it was.
+    fn get_into(&self, object: &Self::Object, key: &str, array: TypedArrayMut<'_>) -> bool;
+
+    /// Like `set`, but copies a typed array into the one at `key` if that has the same type and length.
+    fn set_into(&self, object: &mut Self::Object, key: &str, value: Value);
+
+    /// The array of objects at `key`, failing if `key` holds something else, e.g. is `undefined`.
+    fn get_array(&self, object: &Self::Object, key: &str) -> Result<Self::Array, EncoderError>;
+
+    fn set_array(&self, object: &mut Self::Object, key: &str, array: Self::Array);
+
+    /// The `length` of `array`, like `get`.
+    fn length(&self, array: &Self::Array, errors: &mut FieldErrors) -> Result<Value, EncoderError>;
+
+    fn get_object(&self, array: &Self::Array, index: usize) -> Self::Object;
+
+    fn set_object(&self, array: &mut Self::Array, index: usize, object: Self::Object);
+}
+
+impl Host for Factory {
+    type Object = Object;
+    type Array = Array;
+
+    fn object(&self) -> Object {
+        Factory::object(self)
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(value.as_f64().ok_or(EncoderErrorKind::JsValueNotF64)?)
+    fn array(&self) -> Array {
+        Factory::array(self)
     }
-}
 
-impl JsValueEncoder for bool {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(value))
+    fn get(
+        &self,
+        object: &Object,
+        key: &str,
+        errors: &mut FieldErrors,
+    ) -> Result<Value, EncoderError> {
+        decode_value(object.get(key), errors)
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(value.as_bool().ok_or(EncoderErrorKind::JsValueNotBool)?)
+    fn set(&self, object: &mut Object, key: &str, value: Value) {
+        object.set(key, encode_value(value, self));
+    }
+
+    fn get_into(&self, object: &Object, key: &str, array: TypedArrayMut<'_>) -> bool {
+        let value = object.get(key);
+
+        copy_to_typed_arrays!(
+            value,
+            array,
+            Int8Array,
+            Int16Array,
+            Int32Array,
+            Uint8Array,
+            Uint16Array,
+            Uint32Array
+        )
+    }
+
+    fn set_into(&self, object: &mut Object, key: &str, value: Value) {
+        let existing = object.get(key);
+
+        copy_from_typed_arrays!(
+            existing,
+            value,
+            Int8Array,
+            Int16Array,
+            Int32Array,
+            Uint8Array,
+            Uint16Array,
+            Uint32Array
+        );
+
+        self.set(object, key, value);
+    }
+
+    fn get_array(&self, object: &Object, key: &str) -> Result<Array, EncoderError> {
+        let value = object.get(key);
+
+        if js_sys::Array::is_array(&value) {
+            Ok(value.unchecked_into())
+        } else {
+            Err(EncoderError::unexpected_type("an array", &value))
+        }
+    }
+
+    fn set_array(&self, object: &mut Object, key: &str, array: Array) {
+        object.set_array(key, array);
     }
-}
 
-impl JsValueEncoder for String {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(value))
+    fn length(&self, array: &Array, errors: &mut FieldErrors) -> Result<Value, EncoderError> {
+        decode_value(array.get("length"), errors)
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(value
-            .as_string()
-            .ok_or(EncoderErrorKind::JsValueNotString)?)
+    fn get_object(&self, array: &Array, index: usize) -> Object {
+        array.get_object(index)
     }
+
+    fn set_object(&self, array: &mut Array, index: usize, object: Object) {
+        array.set_object(index, object);
+    }
+}
+
+/// A struct mapped to the properties of a JS object, usually through `#[derive(JsObjectCodec)]`.
+///
+/// The derive implements the platform-neutral `Schema` of `state`, and this maps every `Schema` onto the emulator's
+/// JS objects.
+pub(crate) trait JsObjectCodec
+where
+    Self: Sized,
+{
+    fn decode(factory: &Factory, object: &Object, errors: &mut FieldErrors) -> Self;
+    /// Like `decode`, but into `self`, copying typed arrays into the buffers it already has.
+    fn decode_into(&mut self, factory: &Factory, object: &Object, errors: &mut FieldErrors);
+    fn encode(self, factory: &Factory) -> Object;
+    /// Like `encode`, but into `object`, copying typed arrays into the ones it has.
+    fn encode_into(self, factory: &Factory, object: Object);
 }
 
-impl JsValueEncoder for Vec<i8> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Int8Array::from(value.as_ref())))
+impl<T: Schema> JsObjectCodec for T {
+    fn decode(factory: &Factory, object: &Object, errors: &mut FieldErrors) -> Self {
+        decode(factory, object, errors)
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Int8Array::from(value).to_vec())
+    fn decode_into(&mut self, factory: &Factory, object: &Object, errors: &mut FieldErrors) {
+        decode_into(factory, object, self, errors);
+    }
+
+    fn encode(self, factory: &Factory) -> Object {
+        encode(self, factory)
+    }
+
+    fn encode_into(self, factory: &Factory, object: Object) {
+        encode_into(self, factory, object);
     }
 }
 
-impl JsValueEncoder for Vec<i16> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Int16Array::from(value.as_ref())))
+/// Decodes a `Schema` struct from `object`, recording every property that fails.
+pub(crate) fn decode<H: Host, T: Schema>(
+    host: &H,
+    object: &H::Object,
+    errors: &mut FieldErrors,
+) -> T {
+    T::decode(&mut ObjectDecoder {
+        host,
+        object,
+        errors,
+    })
+}
+
+/// Like `decode`, but into `value`, copying typed arrays into the buffers it already has.
+pub(crate) fn decode_into<H: Host, T: Schema>(
+    host: &H,
+    object: &H::Object,
+    value: &mut T,
+    errors: &mut FieldErrors,
+) {
+    value.decode_into(&mut ObjectDecoder {
+        host,
+        object,
+        errors,
+    });
+}
+
+pub(crate) fn encode<H: Host, T: Schema>(value: T, host: &H) -> H::Object {
+    let mut encoder = ObjectEncoder {
+        object: host.object(),
+        host,
+        in_place: false,
+    };
+    value.encode(&mut encoder);
+
+    encoder.object
+}
+
+/// Like `encode`, but into `object`, copying typed arrays into the ones it has, see `Host::set_into`.
+///
+/// Arrays of objects are replaced, they are small.
+pub(crate) fn encode_into<H: Host, T: Schema>(value: T, host: &H, object: H::Object) -> H::Object {
+    let mut encoder = ObjectEncoder {
+        object,
+        host,
+        in_place: true,
+    };
+    value.encode(&mut encoder);
+
+    encoder.object
+}
+
+struct ObjectDecoder<'a, H: Host> {
+    host: &'a H,
+    object: &'a H::Object,
+    errors: &'a mut FieldErrors,
+}
+
+impl<H: Host> Decoder for ObjectDecoder<'_, H> {
+    fn field<T: FieldValue>(&mut self, key: &str) -> T {
+        let result = decode_field(key, self.errors, |errors| {
+            self.host.get(self.object, key, errors)
+        });
+
+        self.errors.record(key, result)
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Int16Array::from(value).to_vec())
+    fn field_into<T: FieldValue>(&mut self, key: &str, value: &mut T) {
+        if let Some(array) = value.typed_array_mut() {
+            if self.host.get_into(self.object, key, array) {
+                return;
+            }
+        }
+
+        *value = self.field(key);
     }
+
+    /// Decodes an array of objects, recording failures under `key.index`.
+    fn array<T: Schema>(&mut self, key: &str) -> Vec<T> {
+        let host = self.host;
+        let result = host.get_array(self.object, key).map(Some);
+        let array = match self.errors.record(key, result) {
+            Some(array) => array,
+            None => return Vec::new(),
+        };
+
+        self.errors.nested(key, |errors| {
+            let result = decode_field("length", errors, |errors| host.length(&array, errors));
+            let length: i32 = errors.record("length", result);
+
+            (0..length.max(0) as usize)
+                .map(|i| {
+                    errors.nested(&i.to_string(), |errors| {
+                        decode(host, &host.get_object(&array, i), errors)
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+struct ObjectEncoder<'a, H: Host> {
+    object: H::Object,
+    host: &'a H,
+    /// Whether to write through `Host::set_into`.
+    in_place: bool,
 }
 
-impl JsValueEncoder for Vec<i32> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Int32Array::from(value.as_ref())))
+impl<H: Host> Encoder for ObjectEncoder<'_, H> {
+    fn field<T: FieldValue>(&mut self, key: &str, value: T) {
+        if self.in_place {
+            self.host
+                .set_into(&mut self.object, key, value.into_value());
+        } else {
+            self.host.set(&mut self.object, key, value.into_value());
+        }
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Int32Array::from(value).to_vec())
+    fn array<T: Schema>(&mut self, key: &str, values: Vec<T>) {
+        let mut array = self.host.array();
+
+        for (i, value) in values.into_iter().enumerate() {
+            self.host
+                .set_object(&mut array, i, encode(value, self.host));
+        }
+
+        self.host.set_array(&mut self.object, key, array);
     }
 }
 
-impl JsValueEncoder for Vec<u8> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Uint8Array::from(value.as_ref())))
+/// Converts the value `get` reads for `key`, with the failures of nested values recorded under it.
+fn decode_field<T: FieldValue>(
+    key: &str,
+    errors: &mut FieldErrors,
+    get: impl FnOnce(&mut FieldErrors) -> Result<Value, EncoderError>,
+) -> Result<T, EncoderError> {
+    let value = errors.nested(key, get)?;
+
+    Ok(T::from_value(value)?)
+}
+
+/// Reads every own enumerable property of `object`, recording the ones of an unsupported type.
+pub(crate) fn decode_record(object: &Object, errors: &mut FieldErrors) -> Record {
+    let keys = js_sys::Object::keys(object.unchecked_ref::<js_sys::Object>());
+
+    Record(
+        keys.iter()
+            .filter_map(|key| key.as_string())
+            .map(|key| {
+                let value = decode_entry(&key, object.get(&key), errors);
+                (key, value)
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn encode_record(record: Record, factory: &Factory) -> Object {
+    let object = Factory::object(factory);
+
+    for (key, value) in record.0 {
+        object.set(&key, encode_value(value, factory));
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Uint8Array::from(value).to_vec())
+    object
+}
+
+/// Like `encode_record`, but into `object`, see `Host::set_into`.
+pub(crate) fn encode_record_into(record: Record, factory: &Factory, mut object: Object) {
+    for (key, value) in record.0 {
+        factory.set_into(&mut object, &key, value);
     }
 }
 
-impl JsValueEncoder for Vec<u16> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Uint16Array::from(value.as_ref())))
+/// Decodes the value at `key`, with the failures of nested values recorded under it.
+fn decode_entry(key: &str, value: JsValue, errors: &mut FieldErrors) -> Value {
+    let result = errors.nested(key, |errors| decode_value(value, errors));
+
+    errors.record(key, result)
+}
+
+macro_rules! decode_typed_arrays {
+    ($value:expr, $($array:ident),*) => {
+        $(
+            if let Some(array) = $value.dyn_ref::<js_sys::$array>() {
+                return Ok(Value::$array(array.to_vec()));
+            }
+        )*
+    };
+}
+
+fn decode_value(value: JsValue, errors: &mut FieldErrors) -> Result<Value, EncoderError> {
+    if value.is_undefined() {
+        return Ok(Value::Undefined);
+    }
+    if value.is_null() {
+        return Ok(Value::Null);
+    }
+    if let Some(value) = value.as_bool() {
+        return Ok(Value::Bool(value));
+    }
+    if let Some(value) = value.as_f64() {
+        return Ok(Value::Number(value));
+    }
+    if let Some(value) = value.as_string() {
+        return Ok(Value::String(value));
+    }
+
+    decode_typed_arrays!(
+        value,
+        Int8Array,
+        Uint8Array,
+        Uint8ClampedArray,
+        Int16Array,
+        Uint16Array,
+        Int32Array,
+        Uint32Array,
+        Float32Array,
+        Float64Array
+    );
+
+    if js_sys::Array::is_array(&value) {
+        let array: js_sys::Array = value.unchecked_into();
+
+        return Ok(Value::Array(
+            array
+                .iter()
+                .enumerate()
+                .map(|(index, item)| decode_entry(&index.to_string(), item, errors))
+                .collect(),
+        ));
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Uint16Array::from(value).to_vec())
+    if value.is_object() {
+        return Ok(Value::Object(decode_record(value.unchecked_ref(), errors)));
+    }
+
+    Err(EncoderError::unexpected_type(
+        "a number, boolean, string, typed array, array or object",
+        &value,
+    ))
+}
+
+fn encode_value(value: Value, factory: &Factory) -> JsValue {
+    match value {
+        Value::Undefined => JsValue::UNDEFINED,
+        Value::Null => JsValue::NULL,
+        Value::Bool(value) => JsValue::from(value),
+        Value::Number(value) => JsValue::from(value),
+        Value::String(value) => JsValue::from(value),
+        Value::Int8Array(value) => js_sys::Int8Array::from(value.as_ref()).into(),
+        Value::Uint8Array(value) => js_sys::Uint8Array::from(value.as_ref()).into(),
+        Value::Uint8ClampedArray(value) => js_sys::Uint8ClampedArray::from(value.as_ref()).into(),
+        Value::Int16Array(value) => js_sys::Int16Array::from(value.as_ref()).into(),
+        Value::Uint16Array(value) => js_sys::Uint16Array::from(value.as_ref()).into(),
+        Value::Int32Array(value) => js_sys::Int32Array::from(value.as_ref()).into(),
+        Value::Uint32Array(value) => js_sys::Uint32Array::from(value.as_ref()).into(),
+        Value::Float32Array(value) => js_sys::Float32Array::from(value.as_ref()).into(),
+        Value::Float64Array(value) => js_sys::Float64Array::from(value.as_ref()).into(),
+        Value::Array(values) => {
+            let array = Factory::array(factory);
+
+            for (index, value) in values.into_iter().enumerate() {
+                array.set(index, encode_value(value, factory));
+            }
+
+            array.into()
+        }
+        Value::Object(record) => encode_record(record, factory).into(),
     }
 }
 
-impl JsValueEncoder for Vec<u32> {
-    fn encode(value: Self) -> Result<JsValue, EncoderError> {
-        Ok(JsValue::from(js_sys::Uint32Array::from(value.as_ref())))
+#[cfg(test)]
+mod tests {
+    use ::proptest::collection::vec;
+    use ::proptest::prelude::*;
+
+    use ::state::generic::Value;
+    use ::state::schema::{Decoder, Encoder, FieldValue, Schema};
+    use ::state::{format, OamTableEntry, SaveState};
+
+    use ::std::collections::HashSet;
+    use ::std::sync::OnceLock;
+
+    use crate::encoder::mock::{MockHost, MockObject};
+    use crate::encoder::{decode, decode_into, encode, FieldErrors};
+
+    const OAM_TABLE: &str = "IOCore.gfxRenderer.renderer.objRenderer.OAMTable";
+
+    /// Makes up a value for every field, of the type its default encodes to.
+    struct RandomDecoder {
+        state: u64,
+        /// Whether `Option`s are sometimes `None`.
+        nulls: bool,
+        /// Arrays of objects have up to this many entries.
+        entries: u64,
+    }
+
+    impl RandomDecoder {
+        /// xorshift64*, `state` must not be 0.
+        fn next(&mut self) -> u64 {
+            self.state ^= self.state >> 12;
+            self.state ^= self.state << 25;
+            self.state ^= self.state >> 27;
+            self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+        }
+
+        fn bytes(&mut self) -> Vec<u8> {
+            let len = self.next() % 16;
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+
+        /// A value of the same type as `like`.
+        fn value(&mut self, like: Value) -> Value {
+            let bytes = self.bytes();
+
+            match like {
+                Value::Number(_) => Value::Number(self.next() as i32 as f64),
+                Value::Bool(_) => Value::Bool(self.next().is_multiple_of(2)),
+                Value::String(_) => Value::String(format!("{:x}", self.next())),
+                Value::Int8Array(_) => Value::Int8Array(bytes.iter().map(|b| *b as i8).collect()),
+                Value::Uint8Array(_) => Value::Uint8Array(bytes),
+                Value::Int16Array(_) => {
+                    Value::Int16Array(bytes.iter().map(|b| (*b as i16) << 7).collect())
+                }
+                Value::Uint16Array(_) => {
+                    Value::Uint16Array(bytes.iter().map(|b| (*b as u16) << 8).collect())
+                }
+                Value::Int32Array(_) => {
+                    Value::Int32Array(bytes.iter().map(|b| (*b as i32) << 23).collect())
+                }
+                Value::Uint32Array(_) => {
+                    Value::Uint32Array(bytes.iter().map(|b| (*b as u32) << 24).collect())
+                }
+                like => panic!("no field holds {:?}", like),
+            }
+        }
+    }
+
+    impl Decoder for RandomDecoder {
+        fn field<T: FieldValue>(&mut self, key: &str) -> T {
+            let value = match T::default().into_value() {
+                /* Only `Option`s default to `undefined`, and those of a save state hold bytes. */
+                Value::Undefined if self.nulls && self.next().is_multiple_of(4) => Value::Null,
+                Value::Undefined => self.value(Value::Uint8Array(Vec::new())),
+                like => self.value(like),
+            };
+
+            T::from_value(value).unwrap_or_else(|error| panic!("{}: {}", key, error))
+        }
+
+        fn array<T: Schema>(&mut self, _key: &str) -> Vec<T> {
+            let len = self.next() % (self.entries + 1);
+            (0..len).map(|_| T::decode(self)).collect()
+        }
+    }
+
+    fn random_save_state(seed: u64, nulls: bool, entries: u64) -> SaveState {
+        SaveState::decode(&mut RandomDecoder {
+            state: seed | 1,
+            nulls,
+            entries,
+        })
+    }
+
+    fn decode_save_state(object: &MockObject) -> SaveState {
+        let mut errors = FieldErrors::new();
+        let save_state = decode(&MockHost, object, &mut errors);
+        assert!(errors.is_empty(), "{}", errors);
+
+        save_state
+    }
+
+    /// `value` with a different value of the same type.
+    fn changed(value: &Value) -> Value {
+        match value.clone() {
+            Value::Number(f) => Value::Number((f as i32).wrapping_add(1) as f64),
+            Value::Bool(b) => Value::Bool(!b),
+            Value::String(s) => Value::String(s + "!"),
+            Value::Int8Array(values) => Value::Int8Array([values, vec![-1]].concat()),
+            Value::Uint8Array(values) => Value::Uint8Array([values, vec![1]].concat()),
+            Value::Uint16Array(values) => Value::Uint16Array([values, vec![1]].concat()),
+            Value::Int32Array(values) => Value::Int32Array([values, vec![-1]].concat()),
+            value => panic!("no field holds {:?}", value),
+        }
+    }
+
+    /// Collects the keys a `Schema` struct decodes, which are all it encodes but its views.
+    #[derive(Default)]
+    struct KeyDecoder {
+        keys: Vec<String>,
+    }
+
+    impl Decoder for KeyDecoder {
+        fn field<T: FieldValue>(&mut self, key: &str) -> T {
+            self.keys.push(key.to_string());
+            T::default()
+        }
+
+        fn array<T: Schema>(&mut self, _key: &str) -> Vec<T> {
+            Vec::new()
+        }
+    }
+
+    /// Drops the properties encoded from another one, which decoding ignores.
+    fn without_views(object: &MockObject) -> MockObject {
+        static KEYS: OnceLock<HashSet<String>> = OnceLock::new();
+        let keys = KEYS.get_or_init(|| {
+            let mut decoder = KeyDecoder::default();
+            SaveState::decode(&mut decoder);
+
+            decoder.keys.into_iter().collect()
+        });
+
+        MockObject {
+            values: object
+                .values
+                .iter()
+                .filter(|(key, _)| keys.contains(*key))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+            arrays: object.arrays.clone(),
+        }
+    }
+
+    /// Decodes `changed` and checks it encodes back to itself, so every property was read from its own key.
+    fn assert_decodes_to(changed: &MockObject, key: &str) {
+        let encoded = encode(decode_save_state(changed), &MockHost);
+
+        assert!(
+            without_views(&encoded) == without_views(changed),
+            "{} was not decoded",
+            key
+        );
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn test_round_trip(seed in any::<u64>()) {
+            let save_state = random_save_state(seed, true, 128);
+            let bytes = format::write(&save_state).unwrap();
+
+            let object = encode(save_state, &MockHost);
+            let decoded = decode_save_state(&object);
+
+            prop_assert_eq!(format::write(&decoded).unwrap(), bytes);
+            prop_assert_eq!(encode(decoded, &MockHost), object);
+        }
+
+        #[test]
+        fn test_decode_into_matches_decode(previous in any::<u64>(), seed in any::<u64>()) {
+            let mut save_state = random_save_state(previous, true, 128);
+            let object = encode(random_save_state(seed, true, 128), &MockHost);
+
+            let mut errors = FieldErrors::new();
+            decode_into(&MockHost, &object, &mut save_state, &mut errors);
+            prop_assert!(errors.is_empty());
+
+            prop_assert_eq!(
+                format::write(&save_state).unwrap(),
+                format::write(&decode_save_state(&object)).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_typed_array_widths(
+            i8s in vec(any::<i8>(), 0..64),
+            i16s in vec(any::<i16>(), 0..64),
+            i32s in vec(any::<i32>(), 0..64),
+            u8s in vec(any::<u8>(), 0..64),
+            u16s in vec(any::<u16>(), 0..64),
+            u32s in vec(any::<u32>(), 0..64),
+            optional in proptest::option::of(vec(any::<u8>(), 0..64)),
+        ) {
+            let widths = Widths {
+                i8s: i8s.clone(),
+                i16s: i16s.clone(),
+                i32s: i32s.clone(),
+                u8s: u8s.clone(),
+                u16s: u16s.clone(),
+                u32s: u32s.clone(),
+                optional: optional.clone(),
+            };
+
+            let object = encode(widths, &MockHost);
+            prop_assert_eq!(&object.values["i8s"], &Value::Int8Array(i8s.clone()));
+            prop_assert_eq!(&object.values["i16s"], &Value::Int16Array(i16s.clone()));
+            prop_assert_eq!(&object.values["i32s"], &Value::Int32Array(i32s.clone()));
+            prop_assert_eq!(&object.values["u8s"], &Value::Uint8Array(u8s.clone()));
+            prop_assert_eq!(&object.values["u16s"], &Value::Uint16Array(u16s.clone()));
+            prop_assert_eq!(&object.values["u32s"], &Value::Uint32Array(u32s.clone()));
+
+            let mut errors = FieldErrors::new();
+            let decoded: Widths = decode(&MockHost, &object, &mut errors);
+            prop_assert!(errors.is_empty());
+            prop_assert_eq!(decoded.i8s, i8s);
+            prop_assert_eq!(decoded.i16s, i16s);
+            prop_assert_eq!(decoded.i32s, i32s);
+            prop_assert_eq!(decoded.u8s, u8s);
+            prop_assert_eq!(decoded.u16s, u16s);
+            prop_assert_eq!(decoded.u32s, u32s);
+            prop_assert_eq!(decoded.optional, optional);
+        }
     }
 
-    fn decode(value: JsValue) -> Result<Self, EncoderError> {
-        Ok(js_sys::Uint32Array::from(value).to_vec())
+    proptest! {
+        /* Each case decodes the state once per property. */
+        #![proptest_config(ProptestConfig::with_cases(4))]
+
+        #[test]
+        fn test_every_field_decodes_from_its_key(seed in any::<u64>()) {
+            let object = encode(random_save_state(seed, false, 4), &MockHost);
+            let fields = without_views(&object);
+
+            for (key, value) in &fields.values {
+                let mut changed_object = object.clone();
+                changed_object.values.insert(key.clone(), changed(value));
+
+                assert_decodes_to(&changed_object, key);
+            }
+
+            for (index, entry) in object.arrays[OAM_TABLE].iter().enumerate() {
+                for (key, value) in &entry.values {
+                    let mut changed_object = object.clone();
+                    changed_object.arrays.get_mut(OAM_TABLE).unwrap()[index]
+                        .values
+                        .insert(key.clone(), changed(value));
+
+                    assert_decodes_to(&changed_object, &format!("{}.{}.{}", OAM_TABLE, index, key));
+                }
+            }
+        }
+    }
+
+    /// A field of every typed array, as no struct of `state` has them all.
+    struct Widths {
+        i8s: Vec<i8>,
+        i16s: Vec<i16>,
+        i32s: Vec<i32>,
+        u8s: Vec<u8>,
+        u16s: Vec<u16>,
+        u32s: Vec<u32>,
+        optional: Option<Vec<u8>>,
+    }
+
+    impl Schema for Widths {
+        fn decode<D: Decoder>(decoder: &mut D) -> Self {
+            Widths {
+                i8s: decoder.field("i8s"),
+                i16s: decoder.field("i16s"),
+                i32s: decoder.field("i32s"),
+                u8s: decoder.field("u8s"),
+                u16s: decoder.field("u16s"),
+                u32s: decoder.field("u32s"),
+                optional: decoder.field("optional"),
+            }
+        }
+
+        fn encode<E: Encoder>(self, encoder: &mut E) {
+            encoder.field("i8s", self.i8s);
+            encoder.field("i16s", self.i16s);
+            encoder.field("i32s", self.i32s);
+            encoder.field("u8s", self.u8s);
+            encoder.field("u16s", self.u16s);
+            encoder.field("u32s", self.u32s);
+            encoder.field("optional", self.optional);
+        }
+    }
+
+    #[test]
+    fn test_oam_table_entries() {
+        let save_state = SaveState {
+            iocore_gfx_renderer_renderer_obj_renderer_oamtable: (0..128)
+                .map(|i| OamTableEntry {
+                    ycoord: i,
+                    palette_number: (i % 16) << 4,
+                    ..OamTableEntry::default()
+                })
+                .collect(),
+            ..SaveState::default()
+        };
+
+        let object = encode(save_state, &MockHost);
+        let entries = &object.arrays[OAM_TABLE];
+        assert_eq!(entries.len(), 128);
+        assert_eq!(entries[100].values["ycoord"], Value::Number(100.0));
+        assert_eq!(entries[100].values["paletteNumber"], Value::Number(64.0));
+
+        let decoded = decode_save_state(&object);
+        let table = &decoded.iocore_gfx_renderer_renderer_obj_renderer_oamtable;
+        assert_eq!(table.len(), 128);
+        assert_eq!(table[127].ycoord, 127);
+        assert_eq!(table[127].palette_number, 0xF0);
+    }
+
+    #[test]
+    fn test_views_follow_their_field() {
+        let save_state = SaveState {
+            iocore_memory_internal_ram: vec![1, 2, 3, 4, 5, 6, 7, 8],
+            iocore_sound_channel3_pcm: vec![-1, 2, -3, 4],
+            ..SaveState::default()
+        };
+
+        let object = encode(save_state, &MockHost);
+        assert_eq!(
+            object.values["IOCore.memory.internalRAM16"],
+            Value::Uint16Array(vec![
+                u16::from_ne_bytes([1, 2]),
+                u16::from_ne_bytes([3, 4]),
+                u16::from_ne_bytes([5, 6]),
+                u16::from_ne_bytes([7, 8]),
+            ])
+        );
+        assert_eq!(
+            object.values["IOCore.memory.internalRAM32"],
+            Value::Int32Array(vec![
+                i32::from_ne_bytes([1, 2, 3, 4]),
+                i32::from_ne_bytes([5, 6, 7, 8]),
+            ])
+        );
+        assert_eq!(
+            object.values["IOCore.sound.channel3.PCM32"],
+            Value::Int32Array(vec![i32::from_ne_bytes([0xFF, 2, 0xFD, 4])])
+        );
+    }
+
+    #[test]
+    fn test_failures_are_recorded_by_path() {
+        let save_state = SaveState {
+            iocore_gfx_renderer_renderer_obj_renderer_oamtable: (0..2)
+                .map(|_| OamTableEntry::default())
+                .collect(),
+            ..SaveState::default()
+        };
+        let mut object = encode(save_state, &MockHost);
+        object
+            .values
+            .insert("IOCore.cartridge.name".to_string(), Value::Number(1.0));
+        object.arrays.get_mut(OAM_TABLE).unwrap()[1]
+            .values
+            .insert("ycoord".to_string(), Value::Bool(true));
+
+        let mut errors = FieldErrors::new();
+        let _: SaveState = decode(&MockHost, &object, &mut errors);
+        let message = errors.to_string();
+
+        assert!(message.starts_with("2 properties failed:"), "{}", message);
+        assert!(
+            message.contains("\n  IOCore.cartridge.name: "),
+            "{}",
+            message
+        );
+        assert!(
+            message.contains(&format!("\n  {}.1.ycoord: ", OAM_TABLE)),
+            "{}",
+            message
+        );
+
+        let mut errors = FieldErrors::new();
+        let _: SaveState = decode(&MockHost, &MockObject::default(), &mut errors);
+        assert!(errors.to_string().contains(&format!(
+            "\n  {}: expected an array but got undefined",
+            OAM_TABLE
+        )));
+    }
+
+    #[test]
+    fn test_decode_into_reuses_buffers() {
+        let object = encode(
+            SaveState {
+                iocore_memory_external_ram: vec![7; 0x40000],
+                iocore_saves_sramchip_saves: Some(vec![1; 0x8000]),
+                ..SaveState::default()
+            },
+            &MockHost,
+        );
+
+        let mut save_state = SaveState {
+            iocore_memory_external_ram: vec![0; 0x40000],
+            iocore_saves_sramchip_saves: Some(Vec::with_capacity(0x8000)),
+            ..SaveState::default()
+        };
+        let ewram = save_state.iocore_memory_external_ram.as_ptr();
+        let sram = save_state
+            .iocore_saves_sramchip_saves
+            .as_ref()
+            .unwrap()
+            .as_ptr();
+
+        let mut errors = FieldErrors::new();
+        decode_into(&MockHost, &object, &mut save_state, &mut errors);
+        assert!(errors.is_empty());
+
+        assert_eq!(save_state.iocore_memory_external_ram, vec![7; 0x40000]);
+        assert_eq!(save_state.iocore_memory_external_ram.as_ptr(), ewram);
+        let saves = save_state.iocore_saves_sramchip_saves.unwrap();
+        assert_eq!(saves, vec![1; 0x8000]);
+        assert_eq!(saves.as_ptr(), sram);
     }
 }
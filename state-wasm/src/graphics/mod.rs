@@ -0,0 +1,198 @@
+//! `state::graphics` for a VRAM debugging panel in the browser.
+
+use ::state::format;
+use ::state::graphics::{self, Background, Depth, Palette, Sprite};
+use ::state::screenshot::Screenshot;
+use ::state::SaveState;
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::js_error;
+
+/// An RGBA picture, 4 bytes per pixel in rows from the top, e.g. for `ImageData`.
+#[wasm_bindgen]
+pub struct Image(Screenshot);
+
+#[wasm_bindgen]
+impl Image {
+    pub fn width(&self) -> u32 {
+        self.0.width()
+    }
+
+    pub fn height(&self) -> u32 {
+        self.0.height()
+    }
+
+    pub fn rgba(&self) -> Vec<u8> {
+        self.0.rgba().to_vec()
+    }
+
+    pub fn png(&self) -> Result<Vec<u8>, JsValue> {
+        self.0.to_png().map_err(js_error)
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct BackgroundSummary {
+    pub affine: bool,
+    pub enabled: bool,
+    pub priority: u32,
+    pub char_base: usize,
+    pub screen_base: usize,
+    /// 4 or 8.
+    pub bits_per_pixel: u32,
+    pub mosaic: bool,
+    pub wrap: bool,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl From<&Background> for BackgroundSummary {
+    fn from(background: &Background) -> Self {
+        BackgroundSummary {
+            affine: background.affine,
+            enabled: background.enabled,
+            priority: background.priority,
+            char_base: background.char_base,
+            screen_base: background.screen_base,
+            bits_per_pixel: bits_per_pixel(background.depth),
+            mosaic: background.mosaic,
+            wrap: background.wrap,
+            width: background.width,
+            height: background.height,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteSummary {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub tile: usize,
+    pub priority: u32,
+    pub bank: usize,
+    /// 4 or 8.
+    pub bits_per_pixel: u32,
+    /// `undefined` unless the sprite is rotated and scaled.
+    pub affine: Option<usize>,
+    pub double_size: bool,
+    pub disabled: bool,
+    pub horizontal_flip: bool,
+    pub vertical_flip: bool,
+    /// 0 normal, 1 semi-transparent, 2 an object window.
+    pub mode: u32,
+    pub mosaic: bool,
+}
+
+impl From<&Sprite> for SpriteSummary {
+    fn from(sprite: &Sprite) -> Self {
+        SpriteSummary {
+            x: sprite.x,
+            y: sprite.y,
+            width: sprite.width,
+            height: sprite.height,
+            tile: sprite.tile,
+            priority: sprite.priority,
+            bank: sprite.bank,
+            bits_per_pixel: bits_per_pixel(sprite.depth),
+            affine: sprite.affine,
+            double_size: sprite.double_size,
+            disabled: sprite.disabled,
+            horizontal_flip: sprite.horizontal_flip,
+            vertical_flip: sprite.vertical_flip,
+            mode: sprite.mode,
+            mosaic: sprite.mosaic,
+        }
+    }
+}
+
+/// The video memory of a decoded state.
+#[wasm_bindgen]
+pub struct GraphicsView {
+    save_state: SaveState,
+    sprites: Vec<Sprite>,
+}
+
+#[wasm_bindgen]
+impl GraphicsView {
+    /// Decodes a state written by `Snapshotter::serialize_to_uint8array`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[u8]) -> Result<GraphicsView, JsValue> {
+        let save_state = format::read(data).map_err(js_error)?;
+        let sprites = graphics::sprites(&save_state);
+
+        Ok(GraphicsView {
+            save_state,
+            sprites,
+        })
+    }
+
+    /// Background colors in the top 16 rows and sprite colors in the bottom 16, 8 pixels per color.
+    pub fn palettes(&self) -> Image {
+        Image(graphics::palettes(&self.save_state))
+    }
+
+    /// All of VRAM as 4 or 8 `bits_per_pixel` tiles, 32 per row, in the colors of sprites if `objects` is set.
+    /// `bank` picks the colors of 4 bit tiles.
+    pub fn tiles(&self, bits_per_pixel: u32, objects: bool, bank: usize) -> Result<Image, JsValue> {
+        let depth = match bits_per_pixel {
+            4 => Depth::Bpp4,
+            8 => Depth::Bpp8,
+            _ => {
+                return Err(js_error(format_args!(
+                    "Tiles have 4 or 8 bits per pixel, not {}",
+                    bits_per_pixel
+                )))
+            }
+        };
+        let palette = if objects {
+            Palette::Object
+        } else {
+            Palette::Background
+        };
+
+        Ok(Image(graphics::tiles(
+            &self.save_state,
+            depth,
+            palette,
+            bank,
+        )))
+    }
+
+    /// `undefined` if the video mode has no tiled layer `index`.
+    pub fn background(&self, index: usize) -> Option<BackgroundSummary> {
+        Background::of(&self.save_state, index).map(|background| (&background).into())
+    }
+
+    /// The whole map of layer `index`, `undefined` if the video mode has no tiled layer there.
+    pub fn background_image(&self, index: usize) -> Option<Image> {
+        Background::of(&self.save_state, index)
+            .map(|background| Image(background.render(&self.save_state)))
+    }
+
+    pub fn sprite_count(&self) -> usize {
+        self.sprites.len()
+    }
+
+    pub fn sprite(&self, index: usize) -> Option<SpriteSummary> {
+        self.sprites.get(index).map(SpriteSummary::from)
+    }
+
+    /// The sprite's tiles at their size, flipped but not rotated or scaled.
+    pub fn sprite_image(&self, index: usize) -> Option<Image> {
+        self.sprites
+            .get(index)
+            .map(|sprite| Image(sprite.render(&self.save_state)))
+    }
+}
+
+fn bits_per_pixel(depth: Depth) -> u32 {
+    match depth {
+        Depth::Bpp4 => 4,
+        Depth::Bpp8 => 8,
+    }
+}
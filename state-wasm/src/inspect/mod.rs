@@ -0,0 +1,150 @@
+//! `state::inspect` for debug overlays in the browser.
+
+use ::state::format;
+use ::state::inspect::{self, Mode, FIQ_DISABLED, IRQ_DISABLED, THUMB};
+use ::state::SaveState;
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::js_error;
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct TimerSummary {
+    pub enabled: bool,
+    pub counter: i32,
+    pub reload: i32,
+    pub prescalar: i32,
+    /// Counts the overflows of the previous timer, never set for timer 0.
+    pub count_up: bool,
+    pub irq: bool,
+}
+
+impl From<inspect::Timer> for TimerSummary {
+    fn from(timer: inspect::Timer) -> Self {
+        TimerSummary {
+            enabled: timer.enabled,
+            counter: timer.counter,
+            reload: timer.reload,
+            prescalar: timer.prescalar,
+            count_up: timer.count_up,
+            irq: timer.irq,
+        }
+    }
+}
+
+/// Fields are `undefined` where the emulator's state does not include the value for the channel.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct DmaSummary {
+    pub enabled: Option<bool>,
+    pub source: i32,
+    pub destination: i32,
+    pub word_count: Option<i32>,
+    /// 0 immediately, 1 at v-blank, 2 at h-blank, 3 special.
+    pub timing: Option<i32>,
+    pub repeat: Option<bool>,
+    pub is32_bit: Option<bool>,
+}
+
+impl From<inspect::Dma> for DmaSummary {
+    fn from(dma: inspect::Dma) -> Self {
+        DmaSummary {
+            enabled: dma.enabled,
+            source: dma.source,
+            destination: dma.destination,
+            word_count: dma.word_count,
+            timing: dma.timing,
+            repeat: dma.repeat,
+            is32_bit: dma.is32_bit,
+        }
+    }
+}
+
+/// A decoded state for debug overlays.
+#[wasm_bindgen]
+pub struct SaveStateView {
+    save_state: SaveState,
+}
+
+#[wasm_bindgen]
+impl SaveStateView {
+    /// Decodes a state written by `Snapshotter::serialize_to_uint8array`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[u8]) -> Result<SaveStateView, JsValue> {
+        let save_state = format::read(data).map_err(js_error)?;
+
+        Ok(SaveStateView { save_state })
+    }
+
+    pub fn cartridge_name(&self) -> String {
+        self.save_state.iocore_cartridge_name.clone()
+    }
+
+    pub fn save_type(&self) -> String {
+        inspect::save_type_name(self.save_state.iocore_saves_save_type).to_string()
+    }
+
+    /// The current mode, e.g. `"svc"`, or `"invalid"` for mode bits no ARM mode uses.
+    pub fn mode(&self) -> String {
+        Mode::from_flags(self.save_state.iocore_cpu_mode_flags)
+            .map_or("invalid", Mode::name)
+            .to_string()
+    }
+
+    /// The mode, T, F and I bits of the CPSR. The condition flags are not part of the state.
+    pub fn cpsr_mode_flags(&self) -> i32 {
+        self.save_state.iocore_cpu_mode_flags
+    }
+
+    pub fn thumb(&self) -> bool {
+        self.save_state.iocore_cpu_mode_flags & THUMB != 0
+    }
+
+    pub fn fiq_disabled(&self) -> bool {
+        self.save_state.iocore_cpu_mode_flags & FIQ_DISABLED != 0
+    }
+
+    pub fn irq_disabled(&self) -> bool {
+        self.save_state.iocore_cpu_mode_flags & IRQ_DISABLED != 0
+    }
+
+    /// R0-R15 as seen in `mode` (`"usr"`, `"fiq"`, `"irq"`, `"svc"`, `"abt"`, `"und"` or `"sys"`).
+    pub fn registers(&self, mode: &str) -> Result<Vec<i32>, JsValue> {
+        Ok(inspect::registers(&self.save_state, parse_mode(mode)?))
+    }
+
+    /// The SPSR of `mode`, `undefined` for User and System.
+    pub fn spsr(&self, mode: &str) -> Result<Option<u16>, JsValue> {
+        Ok(inspect::spsr(&self.save_state, parse_mode(mode)?))
+    }
+
+    /// Fetch, decode and execute of the pipeline of the current instruction set.
+    pub fn pipeline(&self) -> Vec<i32> {
+        inspect::pipeline(&self.save_state).to_vec()
+    }
+
+    pub fn scanline(&self) -> i32 {
+        self.save_state.iocore_gfx_state_current_scan_line
+    }
+
+    pub fn irqs_enabled(&self) -> Vec<String> {
+        inspect::irq_names(self.save_state.iocore_irq_interrupts_enabled)
+    }
+
+    pub fn irqs_requested(&self) -> Vec<String> {
+        inspect::irq_names(self.save_state.iocore_irq_interrupts_requested)
+    }
+
+    pub fn timer(&self, index: usize) -> Option<TimerSummary> {
+        inspect::timer(&self.save_state, index).map(TimerSummary::from)
+    }
+
+    pub fn dma(&self, index: usize) -> Option<DmaSummary> {
+        inspect::dma(&self.save_state, index).map(DmaSummary::from)
+    }
+}
+
+fn parse_mode(mode: &str) -> Result<Mode, JsValue> {
+    Mode::from_name(mode).ok_or_else(|| js_error(format_args!("Unknown mode {:?}", mode)))
+}
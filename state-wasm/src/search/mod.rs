@@ -0,0 +1,74 @@
+//! `state::search` for cheat hunting in the browser.
+
+use ::state::format;
+use ::state::search::{Filter, Search, ValueType};
+
+use ::wasm_bindgen::prelude::*;
+
+use crate::js_error;
+
+/// A RAM search over states written by `Snapshotter::serialize_to_uint8array`.
+#[wasm_bindgen]
+pub struct RamSearch {
+    search: Search,
+}
+
+#[wasm_bindgen]
+impl RamSearch {
+    /// Starts a search for `value_type` (`"u8"`, `"i8"`, `"u16"`, `"i16"`, `"u32"` or `"i32"`) in a state.
+    #[wasm_bindgen(constructor)]
+    pub fn new(state: &[u8], value_type: &str) -> Result<RamSearch, JsValue> {
+        let value_type = ValueType::from_name(value_type)
+            .ok_or_else(|| js_error(format_args!("Unknown value type {:?}", value_type)))?;
+        let save_state = format::read(state).map_err(js_error)?;
+
+        Ok(RamSearch {
+            search: Search::new(&save_state, value_type),
+        })
+    }
+
+    /// Keeps the candidates that are `"equal"`, `"changed"`, `"increased"` or `"decreased"` since the last state.
+    pub fn narrow(&mut self, state: &[u8], filter: &str) -> Result<usize, JsValue> {
+        let filter = Filter::from_name(filter)
+            .ok_or_else(|| js_error(format_args!("Unknown filter {:?}", filter)))?;
+
+        self.narrow_by(state, filter)
+    }
+
+    /// Keeps the candidates that have `value` in `state`.
+    pub fn narrow_to_value(&mut self, state: &[u8], value: f64) -> Result<usize, JsValue> {
+        self.narrow_by(state, Filter::Value(value as i64))
+    }
+
+    pub fn count(&self) -> usize {
+        self.search.len()
+    }
+
+    /// Bus addresses of the first `limit` candidates, e.g. `0x03000100`.
+    pub fn addresses(&self, limit: usize) -> Vec<u32> {
+        self.search
+            .candidates(limit)
+            .iter()
+            .map(|candidate| candidate.address)
+            .collect()
+    }
+
+    /// Values of the first `limit` candidates in the last state, in the order of `addresses`.
+    pub fn values(&self, limit: usize) -> Vec<f64> {
+        self.search
+            .candidates(limit)
+            .iter()
+            .map(|candidate| candidate.value as f64)
+            .collect()
+    }
+}
+
+impl RamSearch {
+    /// Narrows the search, returning how many candidates are left.
+    fn narrow_by(&mut self, state: &[u8], filter: Filter) -> Result<usize, JsValue> {
+        let save_state = format::read(state).map_err(js_error)?;
+        self.search.narrow(&save_state, filter);
+
+        Ok(self.search.len())
+    }
+}
@@ -0,0 +1,31 @@
+//! `state::validate` for checking states before they are handed to the emulator.
+
+use ::wasm_bindgen::prelude::*;
+
+/// Every problem found in a state, empty if it is fine to load.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct ValidationReport(::state::validate::ValidationReport);
+
+impl From<::state::validate::ValidationReport> for ValidationReport {
+    fn from(report: ::state::validate::ValidationReport) -> Self {
+        ValidationReport(report)
+    }
+}
+
+#[wasm_bindgen]
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.0.is_valid()
+    }
+
+    /// One line per problem, e.g. "`iocore_arm_registers` has 15 entries, expected [16]".
+    pub fn issues(&self) -> Vec<String> {
+        self.0.issues()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+}
@@ -0,0 +1,154 @@
+//! How long a network snapshot of a full-size state takes, allocating every buffer versus reusing the last one's.
+//!
+//! The emulator's side, copying out of the JS typed arrays, is a `copyTo` per memory on top of `decode_into`.
+
+use ::criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use ::state::format;
+use ::state::generic::Value;
+use ::state::schema::{Decoder, Encoder, FieldValue, Schema, TypedArrayMut};
+use ::state::{OamTableEntry, SaveState};
+
+/// The memories of a running game, at their sizes on the GBA.
+fn save_state() -> SaveState {
+    SaveState {
+        iocore_memory_external_ram: vec![0x11; 0x40000],
+        iocore_memory_internal_ram: vec![0x22; 0x8000],
+        iocore_gfx_renderer_renderer_vram: vec![0x33; 0x18000],
+        iocore_gfx_renderer_renderer_palette_ram: vec![0x44; 0x400],
+        iocore_gfx_renderer_renderer_obj_renderer_oamram: vec![0x55; 0x400],
+        iocore_gfx_renderer_renderer_frame_buffer: vec![0x66; 240 * 160],
+        iocore_gfx_renderer_renderer_swizzled_frame: vec![0x77; 240 * 160 * 3],
+        iocore_gfx_renderer_renderer_obj_renderer_oamtable: (0..128)
+            .map(|_| OamTableEntry::default())
+            .collect(),
+        ..SaveState::default()
+    }
+}
+
+/// The emulator's state object as the properties the schema writes, in order.
+#[derive(Default)]
+struct Properties {
+    values: Vec<(String, Value)>,
+    arrays: Vec<Vec<Properties>>,
+}
+
+impl Encoder for Properties {
+    fn field<T: FieldValue>(&mut self, key: &str, value: T) {
+        self.values.push((key.to_string(), value.into_value()));
+    }
+
+    fn array<T: Schema>(&mut self, _key: &str, values: Vec<T>) {
+        let objects = values
+            .into_iter()
+            .map(|value| {
+                let mut object = Properties::default();
+                value.encode(&mut object);
+                object
+            })
+            .collect();
+
+        self.arrays.push(objects);
+    }
+}
+
+/// Reads `Properties` like the JS decoder reads the emulator's object, copying typed arrays.
+///
+/// Properties are found by scanning forward, as the schema reads them in the order it wrote them, so the benchmark
+/// measures the copies rather than a map.
+struct PropertiesDecoder<'a> {
+    properties: &'a Properties,
+    value: usize,
+    array: usize,
+}
+
+impl<'a> PropertiesDecoder<'a> {
+    fn new(properties: &'a Properties) -> Self {
+        PropertiesDecoder {
+            properties,
+            value: 0,
+            array: 0,
+        }
+    }
+
+    fn next(&mut self, key: &str) -> Option<&'a Value> {
+        let values = &self.properties.values;
+        let index = self.value + values[self.value..].iter().position(|(k, _)| k == key)?;
+        self.value = index + 1;
+
+        Some(&values[index].1)
+    }
+}
+
+impl Decoder for PropertiesDecoder<'_> {
+    fn field<T: FieldValue>(&mut self, key: &str) -> T {
+        let value = self.next(key).cloned().unwrap_or_default();
+
+        T::from_value(value).unwrap_or_default()
+    }
+
+    fn field_into<T: FieldValue>(&mut self, key: &str, value: &mut T) {
+        let source = self.next(key);
+
+        match (value.typed_array_mut(), source) {
+            (Some(TypedArrayMut::Uint8Array(values)), Some(Value::Uint8Array(source))) => {
+                values.clear();
+                values.extend_from_slice(source);
+            }
+            (Some(TypedArrayMut::Int32Array(values)), Some(Value::Int32Array(source))) => {
+                values.clear();
+                values.extend_from_slice(source);
+            }
+            _ => *value = T::from_value(source.cloned().unwrap_or_default()).unwrap_or_default(),
+        }
+    }
+
+    fn array<T: Schema>(&mut self, _key: &str) -> Vec<T> {
+        let objects = &self.properties.arrays[self.array];
+        self.array += 1;
+
+        objects
+            .iter()
+            .map(|object| T::decode(&mut PropertiesDecoder::new(object)))
+            .collect()
+    }
+}
+
+fn bench_write(c: &mut Criterion) {
+    let save_state = save_state();
+    let mut group = c.benchmark_group("write_compact");
+
+    group.bench_function("allocating", |b| {
+        b.iter(|| format::write_compact(black_box(&save_state)).unwrap())
+    });
+
+    let mut buffer = Vec::new();
+    group.bench_function("into reused buffer", |b| {
+        b.iter(|| {
+            buffer.clear();
+            format::write_compact_into(black_box(&save_state), &mut buffer).unwrap();
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut properties = Properties::default();
+    save_state().encode(&mut properties);
+    let mut group = c.benchmark_group("decode");
+
+    group.bench_function("allocating", |b| {
+        b.iter(|| SaveState::decode(&mut PropertiesDecoder::new(black_box(&properties))))
+    });
+
+    let mut scratch = SaveState::default();
+    group.bench_function("into previous state", |b| {
+        b.iter(|| scratch.decode_into(&mut PropertiesDecoder::new(black_box(&properties))))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_write, bench_decode);
+criterion_main!(benches);
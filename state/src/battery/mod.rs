@@ -0,0 +1,212 @@
+//! Battery saves in the raw `.sav` layout other emulators and flash carts use.
+//!
+//! IodineGBA keeps every save chip as its bytes in address order, EEPROM blocks in the order their bits are shifted
+//! out, which is that layout already. Which chip the cartridge uses is `iocore_saves_save_type`, see
+//! `referenceSave` in `IodineGBA/core/Saves.js`.
+
+use ::backtrace::Backtrace;
+
+use ::std::fmt;
+
+use crate::SaveState;
+
+const SRAM_SIZE: usize = 0x8000;
+
+/// `load` in `IodineGBA/core/cartridge/EEPROM.js`, 4 Kbit and 64 Kbit chips.
+const EEPROM_SIZES: [usize; 2] = [0x200, 0x2000];
+
+#[derive(Debug)]
+#[allow(dead_code)] // Only read through `Debug`.
+pub struct BatteryError {
+    kind: BatteryErrorKind,
+    stack: Backtrace,
+}
+
+impl BatteryError {
+    fn new(kind: BatteryErrorKind) -> Self {
+        BatteryError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+}
+
+impl From<BatteryErrorKind> for BatteryError {
+    fn from(kind: BatteryErrorKind) -> Self {
+        BatteryError::new(kind)
+    }
+}
+
+impl fmt::Display for BatteryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            BatteryErrorKind::Undetermined => {
+                write!(f, "the game has not used its save chip yet")
+            }
+            BatteryErrorKind::UnknownSaveType(save_type) => {
+                write!(f, "unknown save type {}", save_type)
+            }
+            BatteryErrorKind::Empty(save_type) => {
+                write!(f, "the {} chip holds no data yet", save_type.name())
+            }
+            BatteryErrorKind::Size {
+                save_type,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "a {} save has {} bytes, expected one of {:?}",
+                save_type.name(),
+                actual,
+                expected
+            ),
+        }
+    }
+}
+
+impl ::std::error::Error for BatteryError {}
+
+#[derive(Debug)]
+pub(crate) enum BatteryErrorKind {
+    /// The emulator only picks a chip when the game first accesses one.
+    Undetermined,
+    UnknownSaveType(i32),
+    /// The chip was picked but not allocated, which happens on its first access too.
+    Empty(SaveType),
+    Size {
+        save_type: SaveType,
+        expected: Vec<usize>,
+        actual: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveType {
+    Sram,
+    Flash,
+    Eeprom,
+}
+
+impl SaveType {
+    pub fn of(save_state: &SaveState) -> Result<SaveType, BatteryError> {
+        match save_state.iocore_saves_save_type {
+            0 => Err(BatteryErrorKind::Undetermined)?,
+            1 => Ok(SaveType::Sram),
+            2 => Ok(SaveType::Flash),
+            3 => Ok(SaveType::Eeprom),
+            save_type => Err(BatteryErrorKind::UnknownSaveType(save_type))?,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SaveType::Sram => "SRAM",
+            SaveType::Flash => "Flash",
+            SaveType::Eeprom => "EEPROM",
+        }
+    }
+
+    /// The sizes a `.sav` for the chip of `save_state` can have.
+    ///
+    /// Flash has to keep the size the cartridge was detected with, games read it from the chip's ID.
+    pub fn sizes(self, save_state: &SaveState) -> Vec<usize> {
+        match self {
+            SaveType::Sram => vec![SRAM_SIZE],
+            SaveType::Flash => {
+                vec![save_state.iocore_saves_flashchip_largest_size_possible as usize]
+            }
+            SaveType::Eeprom => EEPROM_SIZES.to_vec(),
+        }
+    }
+}
+
+/// The battery save of `save_state` as a `.sav`.
+pub fn export(save_state: &SaveState) -> Result<&[u8], BatteryError> {
+    let save_type = SaveType::of(save_state)?;
+
+    let saves = match save_type {
+        SaveType::Sram => &save_state.iocore_saves_sramchip_saves,
+        SaveType::Flash => &save_state.iocore_saves_flashchip_saves,
+        SaveType::Eeprom => &save_state.iocore_saves_eepromchip_saves,
+    };
+
+    saves
+        .as_deref()
+        .ok_or_else(|| BatteryErrorKind::Empty(save_type).into())
+}
+
+/// Replaces the battery save of `save_state` with a `.sav` of the chip the cartridge uses.
+pub fn import(save_state: &mut SaveState, sav: &[u8]) -> Result<(), BatteryError> {
+    let save_type = SaveType::of(save_state)?;
+    let expected = save_type.sizes(save_state);
+
+    if !expected.contains(&sav.len()) {
+        Err(BatteryErrorKind::Size {
+            save_type,
+            expected,
+            actual: sav.len(),
+        })?
+    }
+
+    let saves = Some(sav.to_vec());
+
+    match save_type {
+        SaveType::Sram => save_state.iocore_saves_sramchip_saves = saves,
+        SaveType::Flash => save_state.iocore_saves_flashchip_saves = saves,
+        SaveType::Eeprom => {
+            /* The address width the game uses follows the size, see `allocate`. */
+            save_state.iocore_saves_eepromchip_largest_size_possible = sav.len() as i32;
+            save_state.iocore_saves_eepromchip_saves = saves;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::battery::{export, import};
+    use crate::SaveState;
+
+    fn save_state(save_type: i32) -> SaveState {
+        SaveState {
+            iocore_saves_save_type: save_type,
+            iocore_saves_flashchip_largest_size_possible: 0x20000,
+            iocore_saves_eepromchip_largest_size_possible: 0x200,
+            ..SaveState::default()
+        }
+    }
+
+    #[test]
+    fn test_round_trip_per_chip() {
+        for (save_type, size) in [(1, 0x8000), (2, 0x20000), (3, 0x200), (3, 0x2000)] {
+            let mut s = save_state(save_type);
+            let sav: Vec<u8> = (0..size).map(|i| i as u8).collect();
+
+            assert!(export(&s).is_err());
+
+            import(&mut s, &sav).unwrap();
+            assert_eq!(export(&s).unwrap(), sav.as_slice());
+        }
+
+        let mut s = save_state(3);
+        import(&mut s, &[0; 0x2000]).unwrap();
+        assert_eq!(s.iocore_saves_eepromchip_largest_size_possible, 0x2000);
+    }
+
+    #[test]
+    fn test_import_checks_the_size_of_the_chip() {
+        assert!(import(&mut save_state(1), &[0; 0x10000]).is_err());
+        assert!(import(&mut save_state(2), &[0; 0x10000]).is_err());
+        assert!(import(&mut save_state(3), &[0; 0x400]).is_err());
+    }
+
+    #[test]
+    fn test_undetermined_save_type() {
+        let mut s = save_state(0);
+
+        assert!(export(&s).is_err());
+        assert!(import(&mut s, &[0; 0x8000]).is_err());
+        assert!(s.iocore_saves_sramchip_saves.is_none());
+    }
+}
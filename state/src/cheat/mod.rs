@@ -0,0 +1,1075 @@
+//! Cheat codes for the GameShark, Action Replay and CodeBreaker devices.
+//!
+//! Codes decode into `Operation`s on the work RAMs. The operations can change a `SaveState`, or become the `Patch`es
+//! the emulator applies every frame, the way the devices rewrite RAM on each VBlank. Code types follow VBA-M's
+//! `Cheats.cpp`. Codes that patch ROM, hook the game or read the joypad have no RAM equivalent, so they are rejected.
+
+use ::backtrace::Backtrace;
+
+use ::serde::{Deserialize, Serialize};
+
+use ::std::{collections::BTreeMap, fmt};
+
+use crate::search::{EWRAM, IWRAM};
+use crate::SaveState;
+
+/// The TEA key of GameShark v1/v2 and Action Replay v1/v2.
+const SEEDS_V1: [u32; 4] = [0x09F4_FBBD, 0x9681_884A, 0x3520_27E9, 0xF3DE_E5A7];
+/// The TEA key of GameShark v3 and Action Replay v3.
+const SEEDS_V3: [u32; 4] = [0x7AA9_648F, 0x7FAE_6994, 0xC0EF_AAD5, 0x4271_2C57];
+
+const TEA_DELTA: u32 = 0x9E37_79B9;
+const TEA_ROUNDS: u32 = 32;
+
+/// The second word of a code naming the game a list is for, it changes nothing.
+const GAME_ID: u32 = 0x001D_C0DE;
+
+/// Makes GameShark v1 reseed its encryption from the next code.
+const RESEED: u32 = 0xDEAD_FACE;
+
+#[derive(Debug)]
+#[allow(dead_code)] // Only read through `Debug`.
+pub struct CheatError {
+    kind: CheatErrorKind,
+    stack: Backtrace,
+}
+
+impl CheatError {
+    fn new(kind: CheatErrorKind) -> Self {
+        CheatError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+}
+
+impl From<CheatErrorKind> for CheatError {
+    fn from(kind: CheatErrorKind) -> Self {
+        CheatError::new(kind)
+    }
+}
+
+impl From<::bincode::Error> for CheatError {
+    fn from(error: ::bincode::Error) -> Self {
+        CheatError::new(CheatErrorKind::Bincode(error))
+    }
+}
+
+impl fmt::Display for CheatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            CheatErrorKind::Syntax { line } => {
+                write!(f, "line {} is not a code of two hexadecimal words", line)
+            }
+            CheatErrorKind::Unsupported {
+                line,
+                address,
+                value,
+            } => write!(
+                f,
+                "line {}: the code type of {:08X} {:08X} is not supported",
+                line, address, value
+            ),
+            CheatErrorKind::Incomplete { line } => {
+                write!(
+                    f,
+                    "line {}: the code continues on lines that are missing",
+                    line
+                )
+            }
+            CheatErrorKind::Bincode(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl ::std::error::Error for CheatError {}
+
+#[derive(Debug)]
+pub(crate) enum CheatErrorKind {
+    /// `line` counts from 1, including blank lines.
+    Syntax {
+        line: usize,
+    },
+    /// The code after decryption.
+    Unsupported {
+        line: usize,
+        address: u32,
+        value: u32,
+    },
+    Incomplete {
+        line: usize,
+    },
+    Bincode(::bincode::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Device {
+    /// GameShark Advance and Action Replay v1/v2, `XXXXXXXX YYYYYYYY` encrypted.
+    GameSharkV1,
+    /// GameShark SP and Action Replay v3, `XXXXXXXX YYYYYYYY` encrypted.
+    GameSharkV3,
+    /// `XXXXXXXX YYYY` unencrypted.
+    CodeBreaker,
+}
+
+impl Device {
+    /// `"gameshark-v1"`, `"gameshark-v3"` or `"codebreaker"`, the Action Replays as `"actionreplay-v1"` and
+    /// `"actionreplay-v3"`.
+    pub fn from_name(name: &str) -> Option<Device> {
+        match name.to_ascii_lowercase().as_str() {
+            "gameshark-v1" | "actionreplay-v1" => Some(Device::GameSharkV1),
+            "gameshark-v3" | "actionreplay-v3" => Some(Device::GameSharkV3),
+            "codebreaker" => Some(Device::CodeBreaker),
+            _ => None,
+        }
+    }
+
+    fn seeds(self) -> Option<&'static [u32; 4]> {
+        match self {
+            Device::GameSharkV1 => Some(&SEEDS_V1),
+            Device::GameSharkV3 => Some(&SEEDS_V3),
+            Device::CodeBreaker => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Width {
+    Byte,
+    Half,
+    Word,
+}
+
+impl Width {
+    pub fn bytes(self) -> u32 {
+        match self {
+            Width::Byte => 1,
+            Width::Half => 2,
+            Width::Word => 4,
+        }
+    }
+
+    fn mask(self) -> u32 {
+        match self {
+            Width::Byte => 0xFF,
+            Width::Half => 0xFFFF,
+            Width::Word => 0xFFFF_FFFF,
+        }
+    }
+
+    fn signed(self, value: u32) -> i32 {
+        match self {
+            Width::Byte => value as u8 as i8 as i32,
+            Width::Half => value as u16 as i16 as i32,
+            Width::Word => value as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+    Equal,
+    NotEqual,
+    LessSigned,
+    GreaterSigned,
+    Less,
+    Greater,
+    /// Any bit of the value is set in memory.
+    And,
+}
+
+impl Condition {
+    fn holds(self, width: Width, memory: u32, value: u32) -> bool {
+        match self {
+            Condition::Equal => memory == value,
+            Condition::NotEqual => memory != value,
+            Condition::LessSigned => width.signed(memory) < width.signed(value),
+            Condition::GreaterSigned => width.signed(memory) > width.signed(value),
+            Condition::Less => memory < value,
+            Condition::Greater => memory > value,
+            Condition::And => memory & value != 0,
+        }
+    }
+}
+
+/// A change to the work RAMs, addresses are the GBA's bus addresses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Stores `value` into `count` consecutive values from `address`.
+    Write {
+        address: u32,
+        width: Width,
+        value: u32,
+        count: u32,
+    },
+    /// Adds `value` to the value at `address`, wrapping around.
+    Add {
+        address: u32,
+        width: Width,
+        value: u32,
+    },
+    Or {
+        address: u32,
+        width: Width,
+        value: u32,
+    },
+    And {
+        address: u32,
+        width: Width,
+        value: u32,
+    },
+    /// Skips the next `skip` operations unless the value at `address` passes `condition`.
+    If {
+        address: u32,
+        width: Width,
+        condition: Condition,
+        value: u32,
+        skip: usize,
+    },
+}
+
+/// A byte the emulator has to write to EWRAM or IWRAM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Patch {
+    pub address: u32,
+    pub value: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cheat {
+    pub name: String,
+    pub device: Device,
+    /// One code per line, as the device shows them.
+    pub codes: String,
+    pub enabled: bool,
+}
+
+impl Cheat {
+    pub fn operations(&self) -> Result<Vec<Operation>, CheatError> {
+        let mut codes = parse(&self.codes, self.device)?.into_iter();
+        let mut decoded = Vec::new();
+
+        while let Some(code) = codes.next() {
+            decoded.push(match self.device {
+                Device::GameSharkV1 => decode_v1(code, &mut codes)?,
+                Device::GameSharkV3 => decode_v3(code)?,
+                Device::CodeBreaker => decode_code_breaker(code, &mut codes)?,
+            });
+        }
+
+        Ok(resolve(decoded))
+    }
+}
+
+/// The cheats a session shares.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheatList {
+    pub cheats: Vec<Cheat>,
+}
+
+impl CheatList {
+    pub fn from_bytes(data: &[u8]) -> Result<CheatList, CheatError> {
+        Ok(::bincode::deserialize(data)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CheatError> {
+        Ok(::bincode::serialize(self)?)
+    }
+
+    /// The operations of every enabled cheat, in order. Conditions never skip into the next cheat.
+    pub fn operations(&self) -> Result<Vec<Operation>, CheatError> {
+        let mut operations = Vec::new();
+
+        for cheat in self.cheats.iter().filter(|cheat| cheat.enabled) {
+            operations.extend(cheat.operations()?);
+        }
+
+        Ok(operations)
+    }
+}
+
+/// Runs `operations` on the work RAMs of `save_state`.
+pub fn apply(operations: &[Operation], save_state: &mut SaveState) {
+    run(
+        operations,
+        &mut WorkRam {
+            ewram: &mut save_state.iocore_memory_external_ram,
+            iwram: &mut save_state.iocore_memory_internal_ram,
+        },
+    );
+}
+
+/// The bytes running `operations` on the emulator's EWRAM and IWRAM would change, by address.
+///
+/// Conditions see the writes of the operations before them, as if they were applied in place.
+pub fn patches(operations: &[Operation], ewram: &[u8], iwram: &[u8]) -> Vec<Patch> {
+    patches_from(
+        operations,
+        (ewram.len(), |offset| ewram[offset]),
+        (iwram.len(), |offset| iwram[offset]),
+    )
+}
+
+/// Like `patches`, but reads the work RAMs byte by byte as `(length, read)`, with `read` taking an offset into them.
+///
+/// Only the bytes the operations touch are read, so the RAMs do not have to be copied first.
+pub fn patches_from(
+    operations: &[Operation],
+    ewram: (usize, impl Fn(usize) -> u8),
+    iwram: (usize, impl Fn(usize) -> u8),
+) -> Vec<Patch> {
+    let mut overlay = Overlay {
+        ewram,
+        iwram,
+        written: BTreeMap::new(),
+    };
+
+    run(operations, &mut overlay);
+
+    let written = ::std::mem::take(&mut overlay.written);
+    written
+        .into_iter()
+        .filter(|(address, value)| overlay.base(*address) != Some(*value))
+        .map(|(address, value)| Patch { address, value })
+        .collect()
+}
+
+/// A line of a cheat: the address and value words as VBA-M names them, decrypted.
+#[derive(Debug, Clone, Copy)]
+struct Code {
+    line: usize,
+    address: u32,
+    value: u32,
+}
+
+impl Code {
+    fn unsupported(self) -> CheatError {
+        CheatErrorKind::Unsupported {
+            line: self.line,
+            address: self.address,
+            value: self.value,
+        }
+        .into()
+    }
+}
+
+/// What a code decodes into, before conditions know how many operations they skip.
+enum Decoded {
+    Operations(Vec<Operation>),
+    /// A condition on the next `codes` codes.
+    If {
+        address: u32,
+        width: Width,
+        condition: Condition,
+        value: u32,
+        codes: usize,
+    },
+    /// Codes for the device itself, such as master codes and game IDs.
+    Ignored,
+}
+
+fn parse(codes: &str, device: Device) -> Result<Vec<Code>, CheatError> {
+    let mut parsed = Vec::new();
+
+    for (index, text) in codes.lines().enumerate() {
+        let line = index + 1;
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        let (address, value) = match words.as_slice() {
+            [] => continue,
+            [address, value] if address.len() == 8 && value.len() <= 8 => (
+                u32::from_str_radix(address, 16),
+                u32::from_str_radix(value, 16),
+            ),
+            _ => Err(CheatErrorKind::Syntax { line })?,
+        };
+
+        let (mut address, mut value) = match (address, value) {
+            (Ok(address), Ok(value)) => (address, value),
+            _ => Err(CheatErrorKind::Syntax { line })?,
+        };
+
+        match device.seeds() {
+            Some(seeds) => decrypt(&mut address, &mut value, seeds),
+            None if value > 0xFFFF => Err(CheatErrorKind::Syntax { line })?,
+            None => {}
+        }
+
+        parsed.push(Code {
+            line,
+            address,
+            value,
+        });
+    }
+
+    Ok(parsed)
+}
+
+fn decrypt(address: &mut u32, value: &mut u32, seeds: &[u32; 4]) {
+    let mut rolling = TEA_DELTA.wrapping_mul(TEA_ROUNDS);
+
+    for _ in 0..TEA_ROUNDS {
+        *value = value.wrapping_sub(tea_round(*address, rolling, seeds[2], seeds[3]));
+        *address = address.wrapping_sub(tea_round(*value, rolling, seeds[0], seeds[1]));
+        rolling = rolling.wrapping_sub(TEA_DELTA);
+    }
+}
+
+fn tea_round(word: u32, rolling: u32, first: u32, second: u32) -> u32 {
+    (word << 4).wrapping_add(first) ^ word.wrapping_add(rolling) ^ (word >> 5).wrapping_add(second)
+}
+
+fn write(address: u32, width: Width, value: u32) -> Decoded {
+    Decoded::Operations(vec![Operation::Write {
+        address,
+        width,
+        value: value & width.mask(),
+        count: 1,
+    }])
+}
+
+fn decode_v1(code: Code, rest: &mut impl Iterator<Item = Code>) -> Result<Decoded, CheatError> {
+    let address = code.address & 0x0FFF_FFFF;
+
+    if code.address == RESEED {
+        return Err(code.unsupported());
+    }
+
+    if code.value == GAME_ID {
+        return Ok(Decoded::Ignored);
+    }
+
+    Ok(match code.address >> 28 {
+        0x0 => write(address, Width::Byte, code.value),
+        0x1 => write(address, Width::Half, code.value),
+        0x2 => write(address, Width::Word, code.value),
+        0x3 => {
+            /* `3000cccc xxxxxxxx` writes to the `cccc` addresses on the following lines, two per line. */
+            let count = (code.address & 0xFFFF) as usize;
+            let mut addresses = Vec::with_capacity(count);
+
+            while addresses.len() < count {
+                let line = rest
+                    .next()
+                    .ok_or(CheatErrorKind::Incomplete { line: code.line })?;
+
+                addresses.push(line.address);
+                addresses.push(line.value);
+            }
+
+            Decoded::Operations(
+                addresses
+                    .into_iter()
+                    .take(count)
+                    .map(|address| Operation::Write {
+                        address: address & 0x0FFF_FFFF,
+                        width: Width::Word,
+                        value: code.value,
+                        count: 1,
+                    })
+                    .collect(),
+            )
+        }
+        0xD => Decoded::If {
+            address,
+            width: Width::Half,
+            condition: Condition::Equal,
+            value: code.value & 0xFFFF,
+            codes: 1,
+        },
+        0xE => Decoded::If {
+            address: code.value & 0x0FFF_FFFF,
+            width: Width::Half,
+            condition: Condition::Equal,
+            value: code.address & 0xFFFF,
+            codes: ((code.address >> 16) & 0xFF) as usize,
+        },
+        /* The hook the device patches into the game. */
+        0xF => Decoded::Ignored,
+        _ => return Err(code.unsupported()),
+    })
+}
+
+fn decode_v3(code: Code) -> Result<Decoded, CheatError> {
+    if code.address == 0 {
+        return match code.value {
+            0 | GAME_ID => Ok(Decoded::Ignored),
+            _ => Err(code.unsupported()),
+        };
+    }
+
+    let kind = ((code.address >> 25) & 0x7F) | ((code.address >> 17) & 0x80);
+    let address = ((code.address & 0x00F0_0000) << 4) | (code.address & 0x0003_FFFF);
+    let width = match kind & 0x07 {
+        0 => Width::Byte,
+        2 => Width::Half,
+        4 => Width::Word,
+        _ => return Err(code.unsupported()),
+    };
+
+    Ok(match kind {
+        0x00 => Decoded::Operations(vec![Operation::Write {
+            address,
+            width,
+            value: code.value & 0xFF,
+            count: (code.value >> 8) + 1,
+        }]),
+        0x02 => Decoded::Operations(vec![Operation::Write {
+            address,
+            width,
+            value: code.value & 0xFFFF,
+            count: (code.value >> 16) + 1,
+        }]),
+        0x04 => write(address, width, code.value),
+        0x80 | 0x82 | 0x84 => Decoded::Operations(vec![Operation::Add {
+            address,
+            width,
+            value: code.value & width.mask(),
+        }]),
+        /* The master code, which hooks the device into the game. */
+        0xC4 => Decoded::Ignored,
+        0x08..=0x7E if kind & 0x38 != 0 => Decoded::If {
+            address,
+            width,
+            condition: match (kind >> 3) & 0x07 {
+                1 => Condition::Equal,
+                2 => Condition::NotEqual,
+                3 => Condition::LessSigned,
+                4 => Condition::GreaterSigned,
+                5 => Condition::Less,
+                6 => Condition::Greater,
+                _ => Condition::And,
+            },
+            value: code.value & width.mask(),
+            codes: if kind & 0x40 == 0 { 1 } else { 2 },
+        },
+        _ => return Err(code.unsupported()),
+    })
+}
+
+fn decode_code_breaker(
+    code: Code,
+    rest: &mut impl Iterator<Item = Code>,
+) -> Result<Decoded, CheatError> {
+    let address = code.address & 0x0FFF_FFFF;
+    let value = code.value;
+    let condition = |condition| Decoded::If {
+        address,
+        width: Width::Half,
+        condition,
+        value,
+        codes: 1,
+    };
+
+    Ok(match code.address >> 28 {
+        /* The master code and the game ID. */
+        0x0 | 0x1 => Decoded::Ignored,
+        0x2 => Decoded::Operations(vec![Operation::Or {
+            address,
+            width: Width::Half,
+            value,
+        }]),
+        0x3 => write(address, Width::Byte, value),
+        0x4 => {
+            /* `4aaaaaaa vvvv` followed by `iiiicccc ssss` writes `cccc` values from `vvvv`, adding `iiii` to the
+            value and `ssss` to the address each time. */
+            let slide = rest
+                .next()
+                .ok_or(CheatErrorKind::Incomplete { line: code.line })?;
+            let increment = slide.address >> 16;
+            let count = slide.address & 0xFFFF;
+            let step = slide.value;
+
+            Decoded::Operations(
+                (0..count)
+                    .map(|index| Operation::Write {
+                        address: address.wrapping_add(index.wrapping_mul(step)),
+                        width: Width::Half,
+                        value: value.wrapping_add(index.wrapping_mul(increment)) & 0xFFFF,
+                        count: 1,
+                    })
+                    .collect(),
+            )
+        }
+        0x6 => Decoded::Operations(vec![Operation::And {
+            address,
+            width: Width::Half,
+            value,
+        }]),
+        0x7 => condition(Condition::Equal),
+        0x8 => write(address, Width::Half, value),
+        0xA => condition(Condition::NotEqual),
+        0xB => condition(Condition::Greater),
+        0xC => condition(Condition::Less),
+        0xE => Decoded::Operations(vec![Operation::Add {
+            address,
+            width: Width::Half,
+            value,
+        }]),
+        0xF => condition(Condition::And),
+        _ => return Err(code.unsupported()),
+    })
+}
+
+/// Turns the codes a condition covers into the number of operations it skips.
+fn resolve(decoded: Vec<Decoded>) -> Vec<Operation> {
+    let decoded: Vec<Decoded> = decoded
+        .into_iter()
+        .filter(|decoded| !matches!(decoded, Decoded::Ignored))
+        .collect();
+
+    let lengths: Vec<usize> = decoded
+        .iter()
+        .map(|decoded| match decoded {
+            Decoded::Operations(operations) => operations.len(),
+            _ => 1,
+        })
+        .collect();
+
+    decoded
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, decoded)| match decoded {
+            Decoded::Operations(operations) => operations,
+            Decoded::If {
+                address,
+                width,
+                condition,
+                value,
+                codes,
+            } => vec![Operation::If {
+                address,
+                width,
+                condition,
+                value,
+                skip: lengths.iter().skip(index + 1).take(codes).sum(),
+            }],
+            Decoded::Ignored => Vec::new(),
+        })
+        .collect()
+}
+
+/// Byte access to EWRAM and IWRAM by bus address. Addresses past the end of a memory are not mirrored.
+trait Memory {
+    fn read_byte(&self, address: u32) -> Option<u8>;
+
+    /// Returns `false` if there is no memory at `address`.
+    fn write_byte(&mut self, address: u32, value: u8) -> bool;
+
+    fn read(&self, address: u32, width: Width) -> Option<u32> {
+        (0..width.bytes()).rev().try_fold(0u32, |value, index| {
+            let byte = self.read_byte(address.wrapping_add(index))?;
+            Some((value << 8) | byte as u32)
+        })
+    }
+
+    fn store(&mut self, address: u32, width: Width, value: u32) -> bool {
+        if self.read(address, width).is_none() {
+            return false;
+        }
+
+        for index in 0..width.bytes() {
+            self.write_byte(address.wrapping_add(index), (value >> (index * 8)) as u8);
+        }
+
+        true
+    }
+}
+
+/// Where `address` is in `ewram` or `iwram`.
+fn locate(address: u32, ewram_len: usize, iwram_len: usize) -> Option<(bool, usize)> {
+    let (is_ewram, offset, len) = match address & 0xFF00_0000 {
+        EWRAM => (true, (address - EWRAM) as usize, ewram_len),
+        IWRAM => (false, (address - IWRAM) as usize, iwram_len),
+        _ => return None,
+    };
+
+    (offset < len).then_some((is_ewram, offset))
+}
+
+struct WorkRam<'a> {
+    ewram: &'a mut [u8],
+    iwram: &'a mut [u8],
+}
+
+impl Memory for WorkRam<'_> {
+    fn read_byte(&self, address: u32) -> Option<u8> {
+        match locate(address, self.ewram.len(), self.iwram.len())? {
+            (true, offset) => Some(self.ewram[offset]),
+            (false, offset) => Some(self.iwram[offset]),
+        }
+    }
+
+    fn write_byte(&mut self, address: u32, value: u8) -> bool {
+        match locate(address, self.ewram.len(), self.iwram.len()) {
+            Some((true, offset)) => self.ewram[offset] = value,
+            Some((false, offset)) => self.iwram[offset] = value,
+            None => return false,
+        }
+
+        true
+    }
+}
+
+/// The emulator's memory with the bytes the operations wrote on top.
+struct Overlay<E, I> {
+    ewram: (usize, E),
+    iwram: (usize, I),
+    written: BTreeMap<u32, u8>,
+}
+
+impl<E: Fn(usize) -> u8, I: Fn(usize) -> u8> Overlay<E, I> {
+    fn base(&self, address: u32) -> Option<u8> {
+        match locate(address, self.ewram.0, self.iwram.0)? {
+            (true, offset) => Some((self.ewram.1)(offset)),
+            (false, offset) => Some((self.iwram.1)(offset)),
+        }
+    }
+}
+
+impl<E: Fn(usize) -> u8, I: Fn(usize) -> u8> Memory for Overlay<E, I> {
+    fn read_byte(&self, address: u32) -> Option<u8> {
+        match self.written.get(&address) {
+            Some(value) => Some(*value),
+            None => self.base(address),
+        }
+    }
+
+    fn write_byte(&mut self, address: u32, value: u8) -> bool {
+        if locate(address, self.ewram.0, self.iwram.0).is_none() {
+            return false;
+        }
+
+        self.written.insert(address, value);
+        true
+    }
+}
+
+fn run(operations: &[Operation], memory: &mut impl Memory) {
+    let mut index = 0;
+
+    while let Some(operation) = operations.get(index) {
+        index += 1;
+
+        match *operation {
+            Operation::Write {
+                address,
+                width,
+                value,
+                count,
+            } => {
+                for offset in 0..count {
+                    let address = address.wrapping_add(offset.wrapping_mul(width.bytes()));
+
+                    if !memory.store(address, width, value) {
+                        break;
+                    }
+                }
+            }
+            Operation::Add {
+                address,
+                width,
+                value,
+            } => {
+                if let Some(current) = memory.read(address, width) {
+                    memory.store(address, width, current.wrapping_add(value));
+                }
+            }
+            Operation::Or {
+                address,
+                width,
+                value,
+            } => {
+                if let Some(current) = memory.read(address, width) {
+                    memory.store(address, width, current | value);
+                }
+            }
+            Operation::And {
+                address,
+                width,
+                value,
+            } => {
+                if let Some(current) = memory.read(address, width) {
+                    memory.store(address, width, current & value);
+                }
+            }
+            Operation::If {
+                address,
+                width,
+                condition,
+                value,
+                skip,
+            } => {
+                let holds = memory
+                    .read(address, width)
+                    .is_some_and(|current| condition.holds(width, current, value));
+
+                if !holds {
+                    index += skip;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cheat::{
+        apply, decrypt, patches, patches_from, tea_round, Cheat, CheatList, Condition, Device, Operation, Patch,
+        Width, SEEDS_V1, SEEDS_V3, TEA_DELTA, TEA_ROUNDS,
+    };
+    use crate::SaveState;
+
+    use ::std::cell::RefCell;
+
+    fn encrypt(mut address: u32, mut value: u32, seeds: &[u32; 4]) -> (u32, u32) {
+        let mut rolling = TEA_DELTA;
+
+        for _ in 0..TEA_ROUNDS {
+            address = address.wrapping_add(tea_round(value, rolling, seeds[0], seeds[1]));
+            value = value.wrapping_add(tea_round(address, rolling, seeds[2], seeds[3]));
+            rolling = rolling.wrapping_add(TEA_DELTA);
+        }
+
+        (address, value)
+    }
+
+    /// Codes as a device shows them, from decrypted codes.
+    fn codes(codes: &[(u32, u32)], seeds: &[u32; 4]) -> String {
+        codes
+            .iter()
+            .map(|(address, value)| {
+                let (address, value) = encrypt(*address, *value, seeds);
+                format!("{:08X} {:08X}\n", address, value)
+            })
+            .collect()
+    }
+
+    /// The first word of an Action Replay v3 code.
+    fn v3(kind: u32, address: u32) -> u32 {
+        ((kind & 0x7F) << 25)
+            | ((kind & 0x80) << 17)
+            | ((address & 0x0F00_0000) >> 4)
+            | (address & 0x0003_FFFF)
+    }
+
+    fn cheat(device: Device, codes: String) -> Cheat {
+        Cheat {
+            name: "Test".to_string(),
+            device,
+            codes,
+            enabled: true,
+        }
+    }
+
+    fn save_state() -> SaveState {
+        SaveState {
+            iocore_memory_external_ram: vec![0; 0x40000],
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            ..SaveState::default()
+        }
+    }
+
+    #[test]
+    fn test_encryption_round_trip() {
+        for seeds in [&SEEDS_V1, &SEEDS_V3] {
+            let (mut address, mut value) = encrypt(0x3200_1234, 0x0000_0063, seeds);
+            assert_ne!((address, value), (0x3200_1234, 0x0000_0063));
+
+            decrypt(&mut address, &mut value, seeds);
+            assert_eq!((address, value), (0x3200_1234, 0x0000_0063));
+        }
+    }
+
+    #[test]
+    fn test_encryption_matches_reference_tea() {
+        /* The published TEA test vector, an all-zero key and block, pins the rounds, delta and halves. */
+        assert_eq!(encrypt(0, 0, &[0; 4]), (0x41EA_3A0A, 0x94BA_A940));
+
+        let (mut address, mut value) = (0x41EA_3A0A, 0x94BA_A940);
+        decrypt(&mut address, &mut value, &[0; 4]);
+        assert_eq!((address, value), (0, 0));
+    }
+
+    #[test]
+    fn test_game_shark_v1_codes() {
+        let codes = codes(
+            &[
+                (0xF800_0000, 0x0000_0000),
+                (0x0300_0010, 0x0000_0063),
+                (0x3000_0003, 0x1122_3344),
+                (0x0200_0000, 0x0200_0004),
+                (0x0200_0008, 0x0000_0000),
+                (0xE002_0001, 0x0300_0010),
+                (0x1300_0020, 0x0000_BEEF),
+                (0x2300_0024, 0x0000_0001),
+            ],
+            &SEEDS_V1,
+        );
+        let operations = cheat(Device::GameSharkV1, codes).operations().unwrap();
+
+        assert_eq!(operations.len(), 7);
+        assert_eq!(
+            operations[4],
+            Operation::If {
+                address: 0x0300_0010,
+                width: Width::Half,
+                condition: Condition::Equal,
+                value: 0x0001,
+                skip: 2,
+            }
+        );
+
+        let mut s = save_state();
+        apply(&operations, &mut s);
+
+        assert_eq!(s.iocore_memory_internal_ram[0x10], 0x63);
+        assert_eq!(s.iocore_memory_external_ram[4..8], [0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(s.iocore_memory_internal_ram[0x20..0x22], [0, 0]);
+
+        /* The condition reads the 0x63 the first code wrote. */
+        s.iocore_memory_internal_ram[0x11] = 0xFF;
+        let operations = cheat(
+            Device::GameSharkV1,
+            self::codes(
+                &[(0xE002_0063, 0x0300_0010), (0x1300_0020, 0xBEEF)],
+                &SEEDS_V1,
+            ),
+        )
+        .operations()
+        .unwrap();
+        apply(&operations, &mut s);
+        assert_eq!(s.iocore_memory_internal_ram[0x20..0x22], [0, 0]);
+
+        s.iocore_memory_internal_ram[0x11] = 0x00;
+        apply(&operations, &mut s);
+        assert_eq!(s.iocore_memory_internal_ram[0x20..0x22], [0xEF, 0xBE]);
+    }
+
+    #[test]
+    fn test_action_replay_v3_conditions_skip_their_codes() {
+        /* If the byte at 0x02000100 is not 5, skip the next two codes: a fill of four bytes and an add. */
+        let codes = codes(
+            &[
+                (0x0000_0000, 0x001D_C0DE),
+                (v3(0x48, 0x0200_0100), 0x0000_0005),
+                (v3(0x00, 0x0300_0000), 0x0000_0307),
+                (v3(0x82, 0x0300_0010), 0x0000_0002),
+                (v3(0x04, 0x0300_0020), 0xCAFE_F00D),
+            ],
+            &SEEDS_V3,
+        );
+        let operations = cheat(Device::GameSharkV3, codes).operations().unwrap();
+        assert_eq!(operations.len(), 4);
+
+        let mut s = save_state();
+        s.iocore_memory_external_ram[0x100] = 5;
+        apply(&operations, &mut s);
+        assert_eq!(s.iocore_memory_internal_ram[0..5], [7, 7, 7, 7, 0]);
+        assert_eq!(s.iocore_memory_internal_ram[0x10], 2);
+        assert_eq!(s.iocore_memory_internal_ram[0x20], 0x0D);
+
+        s.iocore_memory_external_ram[0x100] = 4;
+        s.iocore_memory_internal_ram[0..5].fill(0);
+        apply(&operations, &mut s);
+        assert_eq!(s.iocore_memory_internal_ram[0..5], [0; 5]);
+        assert_eq!(s.iocore_memory_internal_ram[0x10], 2);
+    }
+
+    #[test]
+    fn test_code_breaker_patches() {
+        let codes = "
+            00000000 0000
+            83000010 1234
+            73000010 1234
+            33000012 00FF
+            43000020 0001
+            00010003 0004
+            D3000000 0000
+        ";
+        assert!(cheat(Device::CodeBreaker, codes.to_string())
+            .operations()
+            .is_err());
+
+        let codes = codes.replace("D3000000 0000", "");
+        let operations = cheat(Device::CodeBreaker, codes).operations().unwrap();
+
+        let ewram = vec![0; 0x40000];
+        let mut iwram = vec![0; 0x8000];
+        iwram[0x11] = 0x12;
+
+        assert_eq!(
+            patches(&operations, &ewram, &iwram),
+            [
+                Patch {
+                    address: 0x0300_0010,
+                    value: 0x34,
+                },
+                Patch {
+                    address: 0x0300_0012,
+                    value: 0xFF,
+                },
+                Patch {
+                    address: 0x0300_0020,
+                    value: 0x01,
+                },
+                Patch {
+                    address: 0x0300_0024,
+                    value: 0x02,
+                },
+                Patch {
+                    address: 0x0300_0028,
+                    value: 0x03,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_patches_only_read_touched_bytes() {
+        let operations = cheat(Device::CodeBreaker, "33000012 00FF".to_string())
+            .operations()
+            .unwrap();
+
+        let reads = RefCell::new(Vec::new());
+        let patched = patches_from(
+            &operations,
+            (0x40000, |_| panic!("EWRAM is never touched")),
+            (0x8000, |offset| {
+                reads.borrow_mut().push(offset);
+                0
+            }),
+        );
+
+        assert_eq!(
+            patched,
+            [Patch {
+                address: 0x0300_0012,
+                value: 0xFF,
+            }]
+        );
+        assert!(reads.into_inner().iter().all(|offset| *offset == 0x12));
+    }
+
+    #[test]
+    fn test_cheat_list_round_trip() {
+        let list = CheatList {
+            cheats: vec![
+                cheat(Device::CodeBreaker, "83000010 0063".to_string()),
+                Cheat {
+                    enabled: false,
+                    ..cheat(Device::CodeBreaker, "83000012 0063".to_string())
+                },
+            ],
+        };
+
+        let read = CheatList::from_bytes(&list.to_bytes().unwrap()).unwrap();
+        assert_eq!(read, list);
+        assert_eq!(read.operations().unwrap().len(), 1);
+
+        assert!(CheatList::from_bytes(&[0xFF]).is_err());
+        assert!(cheat(Device::CodeBreaker, "8300001 0063".to_string())
+            .operations()
+            .is_err());
+    }
+}
@@ -0,0 +1,354 @@
+//! Compact states leave out the fields the emulator derives from other fields, and `rebuild` restores them on read.
+//!
+//! Most of them are views into the renderer's `buffer` (see `initialize` of the renderers in `IodineGBA/core/graphics`)
+//! and come back exactly. The palette caches and `OAMTable` are decoded again the way `writePalette16`/`writeOAM16` do.
+//! `swizzledFrame` is converted from `frameBuffer` again, which only differs from the stored one for lines drawn since
+//! the last v-blank, and those are converted anyway at the next one.
+
+use crate::{OamTableEntry, SaveState};
+
+/// The fields `write_compact` leaves out.
+pub(crate) const DERIVED_FIELDS: &[&str] = &[
+    "iocore_gfx_renderer_renderer_line_buffer",
+    "iocore_gfx_renderer_renderer_bg0_renderer_scratch_buffer",
+    "iocore_gfx_renderer_renderer_bg0_renderer_tile_fetched",
+    "iocore_gfx_renderer_renderer_bg1_renderer_scratch_buffer",
+    "iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched",
+    "iocore_gfx_renderer_renderer_bg2_text_renderer_scratch_buffer",
+    "iocore_gfx_renderer_renderer_bg2_text_renderer_tile_fetched",
+    "iocore_gfx_renderer_renderer_bg3_text_renderer_scratch_buffer",
+    "iocore_gfx_renderer_renderer_bg3_text_renderer_tile_fetched",
+    "iocore_gfx_renderer_renderer_bg_affine_renderer0_scratch_buffer",
+    "iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer",
+    "iocore_gfx_renderer_renderer_obj_renderer_scratch_buffer",
+    "iocore_gfx_renderer_renderer_obj_renderer_scratch_objbuffer",
+    "iocore_gfx_renderer_renderer_obj_window_renderer_compositor_objwindow_buffer",
+    "iocore_gfx_renderer_renderer_palette16",
+    "iocore_gfx_renderer_renderer_palette256",
+    "iocore_gfx_renderer_renderer_palette_obj16",
+    "iocore_gfx_renderer_renderer_palette_obj256",
+    "iocore_gfx_renderer_renderer_bg2_matrix_renderer_palette",
+    "iocore_gfx_renderer_renderer_obj_renderer_oamtable",
+    "iocore_gfx_renderer_renderer_swizzled_frame",
+];
+
+const BUFFER_SIZE: usize = 0x680;
+const PALETTE_RAM_SIZE: usize = 0x400;
+const OAM_RAM_SIZE: usize = 0x400;
+const FRAME_SIZE: usize = 240 * 160;
+
+/// The flag the renderer sets on the transparent first color of each palette.
+const TRANSPARENT: i32 = 0x3800000;
+
+/// Fills every empty derived field whose source is present.
+///
+/// Full states already have them, so this is a no-op for anything but compact states.
+pub(crate) fn rebuild(save_state: &mut SaveState) {
+    rebuild_buffer_views(save_state);
+    rebuild_palettes(save_state);
+    rebuild_oam_table(save_state);
+    rebuild_swizzled_frame(save_state);
+}
+
+fn fill<T: Clone>(field: &mut Vec<T>, value: &[T]) {
+    if field.is_empty() {
+        *field = value.to_vec();
+    }
+}
+
+fn rebuild_buffer_views(s: &mut SaveState) {
+    if s.iocore_gfx_renderer_renderer_buffer.len() < BUFFER_SIZE {
+        return;
+    }
+
+    let buffer = &s.iocore_gfx_renderer_renderer_buffer;
+
+    /* Text layer `n` renders at `0x100 * (n + 1)`, with its fetched tile right after the 248 pixel scratch. */
+    let text = |layer: usize| {
+        let offset = (layer << 8) + 0x100;
+        (
+            &buffer[offset..offset + 248],
+            &buffer[offset + 0xF8..offset + 0x100],
+        )
+    };
+    let affine = |layer: usize| {
+        let offset = (layer << 8) + 0x100;
+        &buffer[offset..offset + 240]
+    };
+
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_line_buffer,
+        &buffer[0..240],
+    );
+
+    let (scratch, tile) = text(0);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg0_renderer_scratch_buffer,
+        scratch,
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg0_renderer_tile_fetched,
+        tile,
+    );
+    let (scratch, tile) = text(1);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg1_renderer_scratch_buffer,
+        scratch,
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched,
+        tile,
+    );
+    let (scratch, tile) = text(2);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg2_text_renderer_scratch_buffer,
+        scratch,
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg2_text_renderer_tile_fetched,
+        tile,
+    );
+    let (scratch, tile) = text(3);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg3_text_renderer_scratch_buffer,
+        scratch,
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg3_text_renderer_tile_fetched,
+        tile,
+    );
+
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg_affine_renderer0_scratch_buffer,
+        affine(2),
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer,
+        affine(3),
+    );
+
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_obj_renderer_scratch_buffer,
+        &buffer[0x500..0x5F0],
+    );
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_obj_renderer_scratch_objbuffer,
+        &buffer[0x600..0x680],
+    );
+
+    /* The OBJ window compositor shares the OBJ renderer's window buffer. */
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_obj_window_renderer_compositor_objwindow_buffer,
+        &s.iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer,
+    );
+}
+
+fn rebuild_palettes(s: &mut SaveState) {
+    if s.iocore_gfx_renderer_renderer_palette_ram.len() != PALETTE_RAM_SIZE {
+        return;
+    }
+
+    let mut palette256 = vec![0; 0x100];
+    let mut palette_obj256 = vec![0; 0x100];
+    let mut palette16 = vec![0; 0x100];
+    let mut palette_obj16 = vec![0; 0x100];
+
+    let colors = s.iocore_gfx_renderer_renderer_palette_ram.chunks_exact(2);
+
+    for (address, color) in colors.enumerate() {
+        let color = i32::from(u16::from_le_bytes([color[0], color[1]]) & 0x7FFF);
+        let index = address & 0xFF;
+
+        let color256 = if index == 0 {
+            TRANSPARENT | color
+        } else {
+            color
+        };
+        let color16 = if address & 0xF == 0 {
+            TRANSPARENT | color
+        } else {
+            color
+        };
+
+        if address < 0x100 {
+            palette256[index] = color256;
+            palette16[index] = color16;
+        } else {
+            palette_obj256[index] = color256;
+            palette_obj16[index] = color16;
+        }
+    }
+
+    fill(&mut s.iocore_gfx_renderer_renderer_palette256, &palette256);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_palette_obj256,
+        &palette_obj256,
+    );
+    fill(&mut s.iocore_gfx_renderer_renderer_palette16, &palette16);
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_palette_obj16,
+        &palette_obj16,
+    );
+
+    /* The BG2 matrix renderer shares `palette256`. */
+    fill(
+        &mut s.iocore_gfx_renderer_renderer_bg2_matrix_renderer_palette,
+        &s.iocore_gfx_renderer_renderer_palette256,
+    );
+}
+
+fn rebuild_oam_table(s: &mut SaveState) {
+    if s.iocore_gfx_renderer_renderer_obj_renderer_oamram.len() != OAM_RAM_SIZE
+        || !s
+            .iocore_gfx_renderer_renderer_obj_renderer_oamtable
+            .is_empty()
+    {
+        return;
+    }
+
+    /* Each of the 128 sprites has three attributes followed by a quarter of a matrix parameter. */
+    s.iocore_gfx_renderer_renderer_obj_renderer_oamtable = s
+        .iocore_gfx_renderer_renderer_obj_renderer_oamram
+        .chunks_exact(8)
+        .map(|sprite| {
+            let attribute =
+                |n: usize| i32::from(u16::from_le_bytes([sprite[2 * n], sprite[2 * n + 1]]));
+            let (attribute0, attribute1, attribute2) = (attribute(0), attribute(1), attribute(2));
+
+            OamTableEntry {
+                ycoord: attribute0 & 0xFF,
+                matrix2_d: attribute0 & 0x100,
+                double_size_or_disabled: (attribute0 & 0x200) >> 9,
+                mode: (attribute0 >> 10) & 0x3,
+                mosaic: attribute0 & 0x1000,
+                monolithic_palette: attribute0 & 0x2000,
+                shape: attribute0 >> 14,
+                xcoord: attribute1 & 0x1FF,
+                matrix_parameters: (attribute1 >> 7) & 0x7C,
+                horizontal_flip: attribute1 & 0x1000,
+                vertical_flip: attribute1 & 0x2000,
+                size: attribute1 >> 14,
+                tile_number: attribute2 & 0x3FF,
+                priority: (attribute2 >> 10) & 0x3,
+                palette_number: (attribute2 >> 8) & 0xF0,
+            }
+        })
+        .collect();
+}
+
+fn rebuild_swizzled_frame(s: &mut SaveState) {
+    if s.iocore_gfx_renderer_renderer_frame_buffer.len() != FRAME_SIZE
+        || !s.iocore_gfx_renderer_renderer_swizzled_frame.is_empty()
+    {
+        return;
+    }
+
+    s.iocore_gfx_renderer_renderer_swizzled_frame = s
+        .iocore_gfx_renderer_renderer_frame_buffer
+        .iter()
+        .flat_map(|pixel| {
+            [
+                ((pixel & 0x1F) << 3) as u8,
+                ((pixel & 0x3E0) >> 2) as u8,
+                ((pixel & 0x7C00) >> 7) as u8,
+            ]
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::compact::{rebuild, TRANSPARENT};
+    use crate::format::{read, write, write_compact};
+    use crate::SaveState;
+
+    fn save_state() -> SaveState {
+        let mut palette_ram = vec![0; 0x400];
+        palette_ram[0x02..0x04].copy_from_slice(&0x7FFFu16.to_le_bytes());
+        palette_ram[0x200..0x202].copy_from_slice(&0x801Fu16.to_le_bytes());
+
+        let mut oamram = vec![0; 0x400];
+        oamram[8..14].copy_from_slice(&[0x20, 0x21, 0x40, 0x31, 0x05, 0x3C]);
+
+        let mut save_state = SaveState {
+            iocore_gfx_renderer_renderer_buffer: (0..0x680).collect(),
+            iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer: vec![7; 240],
+            iocore_gfx_renderer_renderer_palette_ram: palette_ram,
+            iocore_gfx_renderer_renderer_obj_renderer_oamram: oamram,
+            iocore_gfx_renderer_renderer_frame_buffer: vec![0x7C1F; 240 * 160],
+            ..SaveState::default()
+        };
+        rebuild(&mut save_state);
+
+        save_state
+    }
+
+    #[test]
+    fn test_rebuild() {
+        let s = save_state();
+
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_line_buffer,
+            (0..240).collect::<Vec<i32>>()
+        );
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched,
+            (0x2F8..0x300).collect::<Vec<i32>>()
+        );
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer[0],
+            0x400
+        );
+
+        assert_eq!(s.iocore_gfx_renderer_renderer_palette256[0], TRANSPARENT);
+        assert_eq!(s.iocore_gfx_renderer_renderer_palette256[1], 0x7FFF);
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_palette_obj16[0],
+            TRANSPARENT | 0x1F
+        );
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_palette_obj16[0x10],
+            TRANSPARENT
+        );
+        assert_eq!(s.iocore_gfx_renderer_renderer_palette_obj256[0x10], 0);
+
+        let sprite = &s.iocore_gfx_renderer_renderer_obj_renderer_oamtable[1];
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_obj_renderer_oamtable.len(),
+            128
+        );
+        assert_eq!(
+            (
+                sprite.ycoord,
+                sprite.matrix2_d,
+                sprite.double_size_or_disabled
+            ),
+            (0x20, 0x100, 0)
+        );
+        assert_eq!(
+            (sprite.xcoord, sprite.horizontal_flip, sprite.size),
+            (0x140, 0x1000, 0)
+        );
+        assert_eq!(
+            (sprite.tile_number, sprite.priority, sprite.palette_number),
+            (0x5, 3, 0x30)
+        );
+
+        assert_eq!(
+            s.iocore_gfx_renderer_renderer_swizzled_frame[0..3],
+            [0xF8, 0, 0xF8]
+        );
+    }
+
+    #[test]
+    fn test_compact_round_trip() {
+        let compact = write_compact(&save_state()).unwrap();
+        let full = write(&save_state()).unwrap();
+
+        assert!(full.len() - compact.len() > 240 * 160 * 3);
+        assert_eq!(
+            bincode::serialize(&read(&compact).unwrap()).unwrap(),
+            bincode::serialize(&save_state()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Structural deltas between two `SaveState`s.
+//!
+//! Both states are split into the same named fields as the file format, so a field that changes size only affects
+//! itself. Small fields are replaced whole, large ones such as the memories are patched by byte ranges of their
+//! encoding.
+
+use ::serde::{Deserialize, Serialize};
+
+use crate::format::{Field, Fields, FormatError, FormatErrorKind};
+use crate::SaveState;
+
+/// Fields up to this size are always replaced whole. It covers every scalar and the short strings.
+const REPLACE_LIMIT: usize = 16;
+
+/// A `Range` costs its offset and length on top of its bytes, so shorter runs of unchanged bytes are sent along.
+const MERGE_GAP: usize = 12;
+
+/// The changes that turn one `SaveState` into another.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct SaveStateDelta {
+    changes: Vec<Change>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Change {
+    /// The new encoding of a small field, or of one whose size changed.
+    Replace { field: String, data: Vec<u8> },
+    /// The changed bytes of a large field whose size did not change.
+    Patch { field: String, ranges: Vec<Range> },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Range {
+    offset: u32,
+    data: Vec<u8>,
+}
+
+impl SaveStateDelta {
+    pub fn new(old: &SaveState, new: &SaveState) -> Result<SaveStateDelta, FormatError> {
+        let old = Fields::from_save_state(old)?;
+        let new = Fields::from_save_state(new)?;
+
+        let changes = new
+            .0
+            .into_iter()
+            .filter_map(|Field { name, data }| {
+                match old.0.iter().find(|field| field.name == name) {
+                    Some(old) if old.data == data => None,
+                    Some(old) if old.data.len() == data.len() && data.len() > REPLACE_LIMIT => {
+                        Some(Change::Patch {
+                            field: name,
+                            ranges: diff(&old.data, &data),
+                        })
+                    }
+                    _ => Some(Change::Replace { field: name, data }),
+                }
+            })
+            .collect();
+
+        Ok(SaveStateDelta { changes })
+    }
+
+    /// The names of the fields the delta changes.
+    pub fn fields(&self) -> impl Iterator<Item = &str> {
+        self.changes.iter().map(|change| match change {
+            Change::Replace { field, .. } | Change::Patch { field, .. } => field.as_str(),
+        })
+    }
+
+    /// Applies the delta to the state it was created against.
+    ///
+    /// Fails without changing `save_state` if a patch does not fit the field it is for.
+    pub fn apply(&self, save_state: &mut SaveState) -> Result<(), FormatError> {
+        let mut fields = Fields::from_save_state(save_state)?;
+
+        for change in &self.changes {
+            match change {
+                Change::Replace { field, data } => {
+                    fields.0.retain(|old| old.name != *field);
+                    fields.0.push(Field {
+                        name: field.clone(),
+                        data: data.clone(),
+                    });
+                }
+                Change::Patch { field, ranges } => {
+                    let old = fields
+                        .0
+                        .iter_mut()
+                        .find(|old| old.name == *field)
+                        .ok_or_else(|| FormatErrorKind::DeltaMismatch(field.clone()))?;
+
+                    for Range { offset, data } in ranges {
+                        let offset = *offset as usize;
+
+                        old.data
+                            .get_mut(offset..offset + data.len())
+                            .ok_or_else(|| FormatErrorKind::DeltaMismatch(field.clone()))?
+                            .copy_from_slice(data);
+                    }
+                }
+            }
+        }
+
+        *save_state = fields.into_save_state()?;
+
+        Ok(())
+    }
+}
+
+/// The ranges of `new` that differ from `old`, which has the same length.
+fn diff(old: &[u8], new: &[u8]) -> Vec<Range> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for (index, _) in old
+        .iter()
+        .zip(new)
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+    {
+        match ranges.last_mut() {
+            Some((_, end)) if index - *end <= MERGE_GAP => *end = index + 1,
+            _ => ranges.push((index, index + 1)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| Range {
+            offset: start as u32,
+            data: new[start..end].to_vec(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::delta::{Change, SaveStateDelta};
+    use crate::SaveState;
+
+    fn save_state() -> SaveState {
+        SaveState {
+            iocore_arm_registers: vec![1, 2, 3],
+            iocore_gfx_renderer_renderer_vram: vec![0; 0x18000],
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            ..SaveState::default()
+        }
+    }
+
+    fn assert_same(a: &SaveState, b: &SaveState) {
+        assert_eq!(
+            bincode::serialize(a).unwrap(),
+            bincode::serialize(b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_unchanged_state_has_empty_delta() {
+        let delta = SaveStateDelta::new(&save_state(), &save_state()).unwrap();
+
+        assert!(delta.changes.is_empty());
+    }
+
+    #[test]
+    fn test_scalars_are_replaced_and_memories_patched() {
+        let old = save_state();
+        let mut new = save_state();
+        new.clock_cycles_since_start = 5;
+        new.iocore_gfx_renderer_renderer_vram[0x100] = 1;
+        new.iocore_gfx_renderer_renderer_vram[0x108] = 2;
+        new.iocore_memory_internal_ram[0x7FFF] = 3;
+
+        let delta = SaveStateDelta::new(&old, &new).unwrap();
+
+        assert_eq!(delta.changes.len(), 3);
+        assert_eq!(
+            delta.fields().collect::<Vec<_>>(),
+            [
+                "clock_cycles_since_start",
+                "iocore_gfx_renderer_renderer_vram",
+                "iocore_memory_internal_ram"
+            ]
+        );
+        assert!(delta.changes.iter().any(|change| matches!(
+            change,
+            Change::Replace { field, .. } if field == "clock_cycles_since_start"
+        )));
+        assert!(delta.changes.iter().any(|change| matches!(
+            change,
+            Change::Patch { field, ranges } if field == "iocore_gfx_renderer_renderer_vram"
+                && ranges.len() == 1
+                && ranges[0].data.len() == 9
+        )));
+        assert!(bincode::serialize(&delta).unwrap().len() < 200);
+
+        let mut applied = old;
+        delta.apply(&mut applied).unwrap();
+        assert_same(&applied, &new);
+    }
+
+    #[test]
+    fn test_resized_array_only_replaces_itself() {
+        let old = save_state();
+        let mut new = save_state();
+        new.iocore_arm_registers.push(4);
+        new.iocore_memory_internal_ram[0] = 1;
+
+        let delta = SaveStateDelta::new(&old, &new).unwrap();
+        let delta =
+            bincode::deserialize::<SaveStateDelta>(&bincode::serialize(&delta).unwrap()).unwrap();
+
+        assert_eq!(delta.changes.len(), 2);
+
+        let mut applied = old;
+        delta.apply(&mut applied).unwrap();
+        assert_same(&applied, &new);
+    }
+
+    #[test]
+    fn test_patch_for_other_state_is_rejected() {
+        let mut new = save_state();
+        new.iocore_gfx_renderer_renderer_vram[0x17FFF] = 1;
+
+        let delta = SaveStateDelta::new(&save_state(), &new).unwrap();
+
+        let mut other = SaveState::default();
+        assert!(delta.apply(&mut other).is_err());
+        assert_same(&other, &SaveState::default());
+    }
+}
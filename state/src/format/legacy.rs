@@ -0,0 +1,597 @@
+//! The field layout of states written before the header existed.
+//!
+//! Those files are a bare bincode of the `SaveState` of the time, so the only way to split them into named fields is to
+//! know the order and width of every field. This list must never change.
+
+use crate::format::Width;
+
+pub(crate) const LEGACY_LAYOUT: &[(&str, Width)] = &[
+    ("clock_cycles_since_start", Width::Fixed(4)),
+    ("iocore_accumulated_clocks", Width::Fixed(4)),
+    ("iocore_arm_decode", Width::Fixed(4)),
+    ("iocore_arm_execute", Width::Fixed(4)),
+    ("iocore_arm_fetch", Width::Fixed(4)),
+    ("iocore_arm_registers", Width::Array(4)),
+    ("iocore_arm_registers_usr", Width::Array(4)),
+    ("iocore_cartridge_eepromstart", Width::Fixed(4)),
+    ("iocore_cartridge_flash_is128", Width::Fixed(1)),
+    ("iocore_cartridge_flash_is_atmel", Width::Fixed(1)),
+    ("iocore_cartridge_name", Width::Array(1)),
+    ("iocore_cpu_mode_flags", Width::Fixed(4)),
+    ("iocore_cpu_mul64_result_high", Width::Fixed(4)),
+    ("iocore_cpu_mul64_result_low", Width::Fixed(4)),
+    ("iocore_cpu_registers_abt", Width::Array(4)),
+    ("iocore_cpu_registers_fiq", Width::Array(4)),
+    ("iocore_cpu_registers_irq", Width::Array(4)),
+    ("iocore_cpu_registers_svc", Width::Array(4)),
+    ("iocore_cpu_registers_und", Width::Array(4)),
+    ("iocore_cpu_spsr", Width::Array(2)),
+    ("iocore_cpu_triggered_irq", Width::Fixed(4)),
+    ("iocore_cycles_overiterated_previously", Width::Fixed(4)),
+    ("iocore_cycles_to_iterate", Width::Fixed(4)),
+    ("iocore_dma_current_match", Width::Fixed(4)),
+    ("iocore_dma_fetch", Width::Fixed(4)),
+    ("iocore_dma_channel0_destination", Width::Fixed(4)),
+    ("iocore_dma_channel0_destination_control", Width::Fixed(4)),
+    ("iocore_dma_channel0_destination_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel0_dma_type", Width::Fixed(4)),
+    ("iocore_dma_channel0_enabled", Width::Fixed(4)),
+    ("iocore_dma_channel0_irq_flagging", Width::Fixed(4)),
+    ("iocore_dma_channel0_is32_bit", Width::Fixed(4)),
+    ("iocore_dma_channel0_pending", Width::Fixed(4)),
+    ("iocore_dma_channel0_repeat", Width::Fixed(4)),
+    ("iocore_dma_channel0_source", Width::Fixed(4)),
+    ("iocore_dma_channel0_source_control", Width::Fixed(4)),
+    ("iocore_dma_channel0_source_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel0_word_count", Width::Fixed(4)),
+    ("iocore_dma_channel0_word_count_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel1_destination", Width::Fixed(4)),
+    ("iocore_dma_channel1_destination_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel1_dma_type", Width::Fixed(4)),
+    ("iocore_dma_channel1_enabled", Width::Fixed(4)),
+    ("iocore_dma_channel1_is32_bit", Width::Fixed(4)),
+    ("iocore_dma_channel1_repeat", Width::Fixed(4)),
+    ("iocore_dma_channel1_source", Width::Fixed(4)),
+    ("iocore_dma_channel1_source_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel1_word_count", Width::Fixed(4)),
+    ("iocore_dma_channel1_word_count_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel2_destination", Width::Fixed(4)),
+    ("iocore_dma_channel2_destination_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel2_enabled", Width::Fixed(4)),
+    ("iocore_dma_channel2_source", Width::Fixed(4)),
+    ("iocore_dma_channel2_source_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel3_destination", Width::Fixed(4)),
+    ("iocore_dma_channel3_destination_shadow", Width::Fixed(4)),
+    (
+        "iocore_dma_channel3_display_sync_enable_delay",
+        Width::Fixed(4),
+    ),
+    ("iocore_dma_channel3_game_pak_dma", Width::Fixed(4)),
+    ("iocore_dma_channel3_source", Width::Fixed(4)),
+    ("iocore_dma_channel3_source_control", Width::Fixed(4)),
+    ("iocore_dma_channel3_source_shadow", Width::Fixed(4)),
+    ("iocore_dma_channel3_word_count", Width::Fixed(4)),
+    ("iocore_gfx_renderer_iodata16", Width::Array(2)),
+    ("iocore_gfx_renderer_iodata32", Width::Array(4)),
+    ("iocore_gfx_renderer_iodata8", Width::Array(1)),
+    ("iocore_gfx_renderer_renderer_backdrop", Width::Fixed(4)),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_bgcharacter_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_bglayer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_bgxcoord",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_bgycoord",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_do256",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_do_mosaic",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_offset",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_priority_flag",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_tile_fetched",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg0_renderer_tile_mode",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_bglayer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_bgxcoord",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_bgycoord",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_offset",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_priority_flag",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_frame_buffer_renderer_frame_select",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgcharacter_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgdisplay_overflow",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_map_size",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_map_size_comparer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_matrix_renderer_palette",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_bgcharacter_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_bglayer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_bgycoord",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_offset",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_priority_flag",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_tile_fetched",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg2_text_renderer_tile_mode",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_matrix_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_matrix_renderer_map_size",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_matrix_renderer_map_size_comparer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_bglayer",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_bgscreen_base_block",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_offset",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_priority_flag",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg3_text_renderer_tile_fetched",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdmx",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdmy",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdx",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdy",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgreference_x",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_bgreference_y",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_pb",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_pd",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer0_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_bgdmy",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_bgdx",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_bgreference_x",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_bgreference_y",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_pb",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_pd",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer",
+        Width::Array(4),
+    ),
+    ("iocore_gfx_renderer_renderer_buffer", Width::Array(4)),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_alpha_blend_amount_target1",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_alpha_blend_amount_target2",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_brightness_effect_amount",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_color_effects_type",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_effects_target1",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_color_effects_renderer_effects_target2",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_compositor_do_effects",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_display", Width::Fixed(4)),
+    (
+        "iocore_gfx_renderer_renderer_display_control",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_frame_buffer", Width::Array(4)),
+    ("iocore_gfx_renderer_renderer_green_swap", Width::Fixed(4)),
+    (
+        "iocore_gfx_renderer_renderer_last_unrendered_line",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_line_buffer", Width::Array(4)),
+    (
+        "iocore_gfx_renderer_renderer_mosaic_renderer_bgmosaic_hsize",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_mosaic_renderer_bgmosaic_vsize",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_mosaic_renderer_objmosaic_hsize",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_mosaic_renderer_objmosaic_vsize",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_cycles_to_render",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_oamram",
+        Width::Array(1),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_oamtable",
+        Width::Array(OAM_TABLE_ENTRY_WIDTH),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_objmatrix_parameters",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_scratch_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_scratch_objbuffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_window_renderer_compositor_objwindow_buffer",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_obj_window_renderer_winobjoutside",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_palette16", Width::Array(4)),
+    ("iocore_gfx_renderer_renderer_palette256", Width::Array(4)),
+    (
+        "iocore_gfx_renderer_renderer_palette_obj16",
+        Width::Array(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_palette_obj256",
+        Width::Array(4),
+    ),
+    ("iocore_gfx_renderer_renderer_palette_ram", Width::Array(1)),
+    (
+        "iocore_gfx_renderer_renderer_queued_scan_lines",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_swizzled_frame",
+        Width::Array(1),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_total_lines_passed",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_vram", Width::Array(1)),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_compositor_do_effects",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_window_display_control",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_winxcoord_left",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_winxcoord_right",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_winycoord_bottom",
+        Width::Fixed(4),
+    ),
+    (
+        "iocore_gfx_renderer_renderer_window0_renderer_winycoord_top",
+        Width::Fixed(4),
+    ),
+    ("iocore_gfx_renderer_renderer_winoutside", Width::Fixed(4)),
+    ("iocore_gfx_state_current_scan_line", Width::Fixed(4)),
+    ("iocore_gfx_state_irqflags", Width::Fixed(4)),
+    ("iocore_gfx_state_lcdticks", Width::Fixed(4)),
+    ("iocore_gfx_state_rendered_scan_line", Width::Fixed(1)),
+    ("iocore_gfx_state_status_flags", Width::Fixed(4)),
+    ("iocore_gfx_state_vcounter", Width::Fixed(4)),
+    ("iocore_irq_interrupts_enabled", Width::Fixed(4)),
+    ("iocore_irq_interrupts_requested", Width::Fixed(4)),
+    ("iocore_joypad_key_input", Width::Fixed(4)),
+    ("iocore_joypad_key_interrupt", Width::Fixed(4)),
+    ("iocore_memory_external_ram", Width::Array(1)),
+    ("iocore_memory_internal_ram", Width::Array(1)),
+    ("iocore_memory_irq_ime", Width::Fixed(4)),
+    ("iocore_memory_last_biosread", Width::Fixed(4)),
+    ("iocore_memory_wramcontrol_flags", Width::Fixed(4)),
+    ("iocore_next_event_clocks", Width::Fixed(4)),
+    ("iocore_saves_eepromchip_address", Width::Fixed(4)),
+    ("iocore_saves_eepromchip_bits_processed", Width::Fixed(4)),
+    ("iocore_saves_eepromchip_buffer", Width::Array(1)),
+    (
+        "iocore_saves_eepromchip_largest_size_possible",
+        Width::Fixed(4),
+    ),
+    ("iocore_saves_eepromchip_mode", Width::Fixed(4)),
+    ("iocore_saves_gpiochip_data", Width::Fixed(4)),
+    ("iocore_saves_gpiochip_direction", Width::Fixed(4)),
+    ("iocore_saves_gpiochip_read_write", Width::Fixed(4)),
+    ("iocore_saves_gpiochip_type", Width::Fixed(4)),
+    ("iocore_saves_save_type", Width::Fixed(4)),
+    ("iocore_saves_undetermined_possible", Width::Fixed(4)),
+    ("iocore_serial_joybus_cntl_flags", Width::Fixed(4)),
+    ("iocore_serial_joybus_irq", Width::Fixed(4)),
+    ("iocore_serial_joybus_recv0", Width::Fixed(4)),
+    ("iocore_serial_joybus_recv1", Width::Fixed(4)),
+    ("iocore_serial_joybus_recv2", Width::Fixed(4)),
+    ("iocore_serial_joybus_recv3", Width::Fixed(4)),
+    ("iocore_serial_joybus_send0", Width::Fixed(4)),
+    ("iocore_serial_joybus_send1", Width::Fixed(4)),
+    ("iocore_serial_joybus_send2", Width::Fixed(4)),
+    ("iocore_serial_joybus_send3", Width::Fixed(4)),
+    ("iocore_serial_joybus_stat", Width::Fixed(4)),
+    ("iocore_serial_rcntdata_bit_flow", Width::Fixed(4)),
+    ("iocore_serial_rcntdata_bits", Width::Fixed(4)),
+    ("iocore_serial_rcntirq", Width::Fixed(1)),
+    ("iocore_serial_rcntmode", Width::Fixed(4)),
+    ("iocore_serial_serial_bits_shifted", Width::Fixed(4)),
+    ("iocore_serial_shift_clocks", Width::Fixed(4)),
+    ("iocore_serial_siobaud_rate", Width::Fixed(4)),
+    ("iocore_serial_siocnt_irq", Width::Fixed(4)),
+    ("iocore_serial_siocnt_mode", Width::Fixed(4)),
+    ("iocore_serial_siocnt_uart_cts", Width::Fixed(1)),
+    ("iocore_serial_siocnt_uart_fifo", Width::Fixed(4)),
+    ("iocore_serial_siocnt_uart_fifo_enable", Width::Fixed(1)),
+    ("iocore_serial_siocnt_uart_misc", Width::Fixed(4)),
+    ("iocore_serial_siocnt_uart_parity_enable", Width::Fixed(1)),
+    ("iocore_serial_siocnt_uart_recv_enable", Width::Fixed(1)),
+    ("iocore_serial_siocnt_uart_send_enable", Width::Fixed(1)),
+    ("iocore_serial_siocnt0_data", Width::Fixed(4)),
+    ("iocore_serial_siocommerror", Width::Fixed(1)),
+    ("iocore_serial_siodata_a", Width::Fixed(4)),
+    ("iocore_serial_siodata_b", Width::Fixed(4)),
+    ("iocore_serial_siodata_c", Width::Fixed(4)),
+    ("iocore_serial_siodata_d", Width::Fixed(4)),
+    ("iocore_serial_siodata8", Width::Fixed(4)),
+    ("iocore_serial_siomult_player_number", Width::Fixed(4)),
+    ("iocore_serial_sioshift_clock_divider", Width::Fixed(4)),
+    ("iocore_serial_sioshift_clock_external", Width::Fixed(4)),
+    ("iocore_serial_siotransfer_started", Width::Fixed(1)),
+    ("iocore_serial_clocks", Width::Fixed(4)),
+    ("iocore_system_status", Width::Fixed(4)),
+    ("iocore_thumb_decode", Width::Fixed(4)),
+    ("iocore_thumb_execute", Width::Fixed(4)),
+    ("iocore_thumb_fetch", Width::Fixed(4)),
+    ("iocore_timer_timer0_control", Width::Fixed(4)),
+    ("iocore_timer_timer0_counter", Width::Fixed(4)),
+    ("iocore_timer_timer0_enabled", Width::Fixed(1)),
+    ("iocore_timer_timer0_irq", Width::Fixed(1)),
+    ("iocore_timer_timer0_precounter", Width::Fixed(4)),
+    ("iocore_timer_timer0_prescalar", Width::Fixed(4)),
+    ("iocore_timer_timer0_prescalar_shifted", Width::Fixed(4)),
+    ("iocore_timer_timer0_reload", Width::Fixed(4)),
+    ("iocore_timer_timer1_control", Width::Fixed(4)),
+    ("iocore_timer_timer1_counter", Width::Fixed(4)),
+    ("iocore_timer_timer1_count_up", Width::Fixed(1)),
+    ("iocore_timer_timer1_enabled", Width::Fixed(1)),
+    ("iocore_timer_timer1_irq", Width::Fixed(1)),
+    ("iocore_timer_timer1_precounter", Width::Fixed(4)),
+    ("iocore_timer_timer1_prescalar", Width::Fixed(4)),
+    ("iocore_timer_timer1_prescalar_shifted", Width::Fixed(4)),
+    ("iocore_timer_timer1_reload", Width::Fixed(4)),
+    ("iocore_timer_timer1_use_chained_clocks", Width::Fixed(1)),
+    ("iocore_timer_timer1_use_main_clocks", Width::Fixed(1)),
+    ("iocore_timer_timer2_control", Width::Fixed(4)),
+    ("iocore_timer_timer2_counter", Width::Fixed(4)),
+    ("iocore_timer_timer2_count_up", Width::Fixed(1)),
+    ("iocore_timer_timer2_enabled", Width::Fixed(1)),
+    ("iocore_timer_timer2_irq", Width::Fixed(1)),
+    ("iocore_timer_timer2_precounter", Width::Fixed(4)),
+    ("iocore_timer_timer2_prescalar", Width::Fixed(4)),
+    ("iocore_timer_timer2_prescalar_shifted", Width::Fixed(4)),
+    ("iocore_timer_timer2_reload", Width::Fixed(4)),
+    ("iocore_timer_timer2_use_chained_clocks", Width::Fixed(1)),
+    ("iocore_timer_timer2_use_main_clocks", Width::Fixed(1)),
+    ("iocore_timer_timer3_control", Width::Fixed(4)),
+    ("iocore_timer_timer3_counter", Width::Fixed(4)),
+    ("iocore_timer_timer3_count_up", Width::Fixed(1)),
+    ("iocore_timer_timer3_enabled", Width::Fixed(1)),
+    ("iocore_timer_timer3_irq", Width::Fixed(1)),
+    ("iocore_timer_timer3_precounter", Width::Fixed(4)),
+    ("iocore_timer_timer3_prescalar", Width::Fixed(4)),
+    ("iocore_timer_timer3_prescalar_shifted", Width::Fixed(4)),
+    ("iocore_timer_timer3_reload", Width::Fixed(4)),
+    ("iocore_timer_timer3_use_chained_clocks", Width::Fixed(1)),
+    ("iocore_timer_timer3_use_main_clocks", Width::Fixed(1)),
+    ("iocore_timer_clocks", Width::Fixed(4)),
+    ("iocore_wait_buffer", Width::Fixed(4)),
+    ("iocore_wait_clocks", Width::Fixed(4)),
+    ("iocore_wait_is_oamrendering", Width::Fixed(4)),
+    ("iocore_wait_is_rendering", Width::Fixed(4)),
+    ("iocore_wait_non_sequential", Width::Fixed(4)),
+    ("iocore_wait_postboot", Width::Fixed(4)),
+    ("iocore_wait_sramwait_state", Width::Fixed(4)),
+    ("iocore_wait_waitcnt0", Width::Fixed(4)),
+    ("iocore_wait_waitcnt1", Width::Fixed(4)),
+    ("iocore_wait_wait_state_clocks16", Width::Array(1)),
+    ("iocore_wait_wait_state_clocks32", Width::Array(1)),
+    ("iocore_wait_wramconfiguration", Width::Fixed(4)),
+    ("iocore_wait_wramwait_state", Width::Fixed(4)),
+    ("last_timestamp", Width::Fixed(4)),
+    ("metric_start", Width::Fixed(4)),
+];
+
+/// Fifteen `i32`s.
+const OAM_TABLE_ENTRY_WIDTH: usize = 15 * 4;
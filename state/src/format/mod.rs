@@ -0,0 +1,897 @@
+//! The versioned file format of a `SaveState`.
+//!
+//! A file starts with a `Header` and stores every field as a named record holding that field's own bincode. Fields
+//! the reader does not know are dropped, fields the file does not have keep their default, and anything else that
+//! changes between layouts is handled by a migration for the format version that changed it.
+//!
+//! States written before the header existed are a bare bincode of `SaveState` and are read as format version 0.
+//!
+//! `write_compact` leaves out fields the emulator derives from others. They are missing like any other field, and
+//! `read` rebuilds them from their sources.
+
+use ::backtrace::Backtrace;
+
+use ::bincode::Options;
+
+use ::serde::{de, de::DeserializeOwned, ser, Deserialize, Serialize};
+
+use ::std::fmt;
+
+use crate::SaveState;
+
+mod compact;
+
+mod delta;
+pub use delta::SaveStateDelta;
+
+mod legacy;
+use legacy::LEGACY_LAYOUT;
+
+pub const MAGIC: [u8; 4] = *b"IGBS";
+
+/// Bump this and append to `MIGRATIONS` whenever the layout of `SaveState` changes.
+pub const FORMAT_VERSION: u16 = 5;
+
+/// `MIGRATIONS[n]` upgrades the fields of format version `n` to version `n + 1`.
+const MIGRATIONS: [Migration; FORMAT_VERSION as usize] = [
+    migrate_legacy,
+    migrate_sound,
+    migrate_flash,
+    migrate_sram,
+    migrate_eeprom,
+];
+
+type Migration = fn(&mut Fields) -> Result<(), FormatError>;
+
+#[derive(Debug)]
+#[allow(dead_code)] // `stack` is only read through `Debug`.
+pub struct FormatError {
+    kind: FormatErrorKind,
+    stack: Backtrace,
+}
+
+impl FormatError {
+    fn new(kind: FormatErrorKind) -> Self {
+        FormatError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+}
+
+impl From<FormatErrorKind> for FormatError {
+    fn from(kind: FormatErrorKind) -> Self {
+        FormatError::new(kind)
+    }
+}
+
+impl From<::bincode::Error> for FormatError {
+    fn from(error: ::bincode::Error) -> Self {
+        FormatError::new(FormatErrorKind::Bincode(error))
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl ::std::error::Error for FormatError {}
+
+impl ser::Error for FormatError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        FormatError::new(FormatErrorKind::Serde(message.to_string()))
+    }
+}
+
+impl de::Error for FormatError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        FormatError::new(FormatErrorKind::Serde(message.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum FormatErrorKind {
+    Bincode(::bincode::Error),
+    /// The file was written by a newer format than this build understands.
+    UnsupportedVersion(u16),
+    /// A headerless state ended inside the named field.
+    Truncated(&'static str),
+    /// A headerless state had bytes left after its last field.
+    TrailingBytes(usize),
+    /// The file does not start with the magic of the format it was read as.
+    BadMagic,
+    /// A `SaveStateDelta` patches the named field beyond its end, so it was made for another state.
+    DeltaMismatch(String),
+    /// Only structs can be split into fields.
+    NotAStruct,
+    Serde(String),
+}
+
+impl fmt::Display for FormatErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatErrorKind::Bincode(error) => write!(f, "{}", error),
+            FormatErrorKind::UnsupportedVersion(version) => write!(
+                f,
+                "format version {} is newer than the supported {}",
+                version, FORMAT_VERSION
+            ),
+            FormatErrorKind::Truncated(field) => write!(f, "state ends inside `{}`", field),
+            FormatErrorKind::TrailingBytes(length) => {
+                write!(f, "{} bytes left after the last field", length)
+            }
+            FormatErrorKind::BadMagic => write!(f, "not a file of this format"),
+            FormatErrorKind::DeltaMismatch(field) => {
+                write!(f, "delta patches `{}` beyond its end", field)
+            }
+            FormatErrorKind::NotAStruct => write!(f, "only structs can be split into fields"),
+            FormatErrorKind::Serde(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Header {
+    magic: [u8; 4],
+    format_version: u16,
+    /// Version of the `state` crate that wrote the file.
+    emulator_version: String,
+}
+
+impl Header {
+    fn current() -> Header {
+        Header {
+            magic: MAGIC,
+            format_version: FORMAT_VERSION,
+            emulator_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Field {
+    name: String,
+    data: Vec<u8>,
+}
+
+/// The fields of a `SaveState`, each holding its own bincode.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub(crate) struct Fields(Vec<Field>);
+
+impl Fields {
+    fn from_save_state(save_state: &SaveState) -> Result<Fields, FormatError> {
+        let mut collector = FieldCollector { sink: Vec::new() };
+        save_state.serialize(&mut collector)?;
+
+        Ok(Fields(collector.sink))
+    }
+
+    fn into_save_state(self) -> Result<SaveState, FormatError> {
+        SaveState::deserialize(FieldsDeserializer { fields: &self.0 })
+    }
+
+    fn from_legacy(data: &[u8]) -> Result<Fields, FormatError> {
+        let mut fields = Vec::with_capacity(LEGACY_LAYOUT.len());
+        let mut offset = 0;
+
+        for (name, width) in LEGACY_LAYOUT {
+            let length = match width {
+                Width::Fixed(length) => Some(*length),
+                Width::Array(element) => data
+                    .get(offset..offset + 8)
+                    .map(|count| u64::from_le_bytes(count.try_into().expect("Slice of 8 bytes")))
+                    .and_then(|count| (count as usize).checked_mul(*element))
+                    .and_then(|length| length.checked_add(8)),
+            };
+
+            let field = length
+                .and_then(|length| data.get(offset..offset.checked_add(length)?))
+                .ok_or(FormatErrorKind::Truncated(name))?;
+
+            offset += field.len();
+            fields.push(Field {
+                name: name.to_string(),
+                data: field.to_vec(),
+            });
+        }
+
+        if offset != data.len() {
+            Err(FormatErrorKind::TrailingBytes(data.len() - offset))?
+        }
+
+        Ok(Fields(fields))
+    }
+
+    pub(crate) fn get<T: DeserializeOwned>(&self, name: &str) -> Result<Option<T>, FormatError> {
+        match self.0.iter().find(|field| field.name == name) {
+            Some(field) => Ok(Some(bincode::deserialize(&field.data)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Adds `name` or replaces its value.
+    pub(crate) fn insert<T: Serialize>(
+        &mut self,
+        name: &str,
+        value: &T,
+    ) -> Result<(), FormatError> {
+        let data = bincode::serialize(value)?;
+
+        match self.0.iter_mut().find(|field| field.name == name) {
+            Some(field) => field.data = data,
+            None => self.0.push(Field {
+                name: name.to_string(),
+                data,
+            }),
+        }
+
+        Ok(())
+    }
+
+    /// Removes `name`, returning whether it was there.
+    pub(crate) fn remove(&mut self, name: &str) -> bool {
+        let length = self.0.len();
+        self.0.retain(|field| field.name != name);
+
+        self.0.len() != length
+    }
+
+    #[allow(dead_code)] // For migrations.
+    pub(crate) fn rename(&mut self, old: &str, new: &str) {
+        self.remove(new);
+
+        if let Some(field) = self.0.iter_mut().find(|field| field.name == old) {
+            field.name = new.to_string();
+        }
+    }
+}
+
+/// How many bytes a field of a headerless state takes.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Width {
+    Fixed(usize),
+    /// A `u64` element count followed by elements of this many bytes.
+    Array(usize),
+}
+
+#[derive(Serialize, Deserialize)]
+struct File {
+    header: Header,
+    fields: Fields,
+}
+
+pub fn write(save_state: &SaveState) -> Result<Vec<u8>, FormatError> {
+    let mut buffer = Vec::new();
+    write_into(save_state, &mut buffer)?;
+
+    Ok(buffer)
+}
+
+/// Like `write`, but leaves out the fields the emulator derives from others, see `compact`.
+pub fn write_compact(save_state: &SaveState) -> Result<Vec<u8>, FormatError> {
+    let mut buffer = Vec::new();
+    write_compact_into(save_state, &mut buffer)?;
+
+    Ok(buffer)
+}
+
+/// Like `write`, but appends to `buffer`. Fields are encoded straight into it, so writing into a buffer kept from the
+/// previous state allocates nothing for them.
+pub fn write_into(save_state: &SaveState, buffer: &mut Vec<u8>) -> Result<(), FormatError> {
+    write_fields_into(save_state, &[], buffer)
+}
+
+/// `write_compact` into `buffer`, like `write_into`.
+pub fn write_compact_into(save_state: &SaveState, buffer: &mut Vec<u8>) -> Result<(), FormatError> {
+    write_fields_into(save_state, compact::DERIVED_FIELDS, buffer)
+}
+
+/// Writes the bincode of a `File` of every field of `save_state` but `skip`.
+fn write_fields_into(
+    save_state: &SaveState,
+    skip: &'static [&'static str],
+    buffer: &mut Vec<u8>,
+) -> Result<(), FormatError> {
+    bincode::serialize_into(&mut *buffer, &Header::current())?;
+
+    /* The fields are a `Vec`, whose length is only known once they are written. */
+    let count_offset = buffer.len();
+    bincode::serialize_into(&mut *buffer, &0u64)?;
+
+    let mut collector = FieldCollector {
+        sink: FieldWriter {
+            buffer,
+            skip,
+            count: 0,
+        },
+    };
+    save_state.serialize(&mut collector)?;
+
+    let FieldWriter { buffer, count, .. } = collector.sink;
+    buffer[count_offset..count_offset + 8].copy_from_slice(&count.to_le_bytes());
+
+    Ok(())
+}
+
+/// The name and encoded size of every field of `save_state`, as `write` stores them.
+pub fn field_sizes(save_state: &SaveState) -> Result<Vec<(String, usize)>, FormatError> {
+    let fields = Fields::from_save_state(save_state)?;
+
+    Ok(fields
+        .0
+        .into_iter()
+        .map(|Field { name, data }| (name, data.len()))
+        .collect())
+}
+
+/// The format version of a state and the version of the build that wrote it, `None` for headerless states.
+pub fn version(data: &[u8]) -> Result<(u16, Option<String>), FormatError> {
+    if !data.starts_with(&MAGIC) {
+        return Ok((0, None));
+    }
+
+    let header: Header = bincode::deserialize(data)?;
+
+    Ok((header.format_version, Some(header.emulator_version)))
+}
+
+/// Reads a state of any format version up to `FORMAT_VERSION`, migrating it to the current layout.
+pub fn read(data: &[u8]) -> Result<SaveState, FormatError> {
+    let (version, mut fields) = if data.starts_with(&MAGIC) {
+        let file: File = bincode::deserialize(data)?;
+
+        if file.header.format_version > FORMAT_VERSION {
+            Err(FormatErrorKind::UnsupportedVersion(
+                file.header.format_version,
+            ))?
+        }
+        if file.header.format_version < FORMAT_VERSION {
+            log::info!(
+                "Migrating state from format {} written by {}.",
+                file.header.format_version,
+                file.header.emulator_version
+            );
+        }
+
+        (file.header.format_version, file.fields)
+    } else {
+        log::info!("Migrating headerless state.");
+
+        (0, Fields::from_legacy(data)?)
+    };
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(&mut fields)?;
+    }
+
+    let mut save_state = fields.into_save_state()?;
+    compact::rebuild(&mut save_state);
+
+    Ok(save_state)
+}
+
+/// Headerless states split into the same fields as format version 1.
+fn migrate_legacy(_fields: &mut Fields) -> Result<(), FormatError> {
+    Ok(())
+}
+
+/// Version 2 added the sound unit. Its buffers stay empty, which `fastLoad` skips, so the emulator keeps its own.
+fn migrate_sound(_fields: &mut Fields) -> Result<(), FormatError> {
+    Ok(())
+}
+
+/// Version 3 added the flash chip. Its contents stay unset, so the emulator keeps its own, but its size and maker
+/// follow the cartridge like they do when the emulator creates the chip.
+fn migrate_flash(fields: &mut Fields) -> Result<(), FormatError> {
+    let is128: bool = fields.get("iocore_cartridge_flash_is128")?.unwrap_or(false);
+    let is_atmel: bool = fields
+        .get("iocore_cartridge_flash_is_atmel")?
+        .unwrap_or(false);
+
+    fields.insert(
+        "iocore_saves_flashchip_largest_size_possible",
+        &if is128 { 0x20000i32 } else { 0x10000i32 },
+    )?;
+    fields.insert("iocore_saves_flashchip_not_atmel", &!is_atmel)
+}
+
+/// Version 4 added SRAM. Its contents stay unset, so the emulator keeps its own.
+fn migrate_sram(_fields: &mut Fields) -> Result<(), FormatError> {
+    Ok(())
+}
+
+/// Version 5 added the EEPROM contents. They stay unset, so the emulator keeps its own.
+fn migrate_eeprom(_fields: &mut Fields) -> Result<(), FormatError> {
+    Ok(())
+}
+
+/// Serializes a struct into one `Field` per struct field.
+struct FieldCollector<S: FieldSink> {
+    sink: S,
+}
+
+/// Where `FieldCollector` puts the fields of a struct.
+trait FieldSink {
+    fn field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), FormatError>;
+}
+
+impl FieldSink for Vec<Field> {
+    fn field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), FormatError> {
+        self.push(Field {
+            name: name.to_string(),
+            data: bincode::serialize(value)?,
+        });
+
+        Ok(())
+    }
+}
+
+/// Encodes fields as the bincode of a `Field` without building one.
+struct FieldWriter<'a> {
+    buffer: &'a mut Vec<u8>,
+    skip: &'static [&'static str],
+    count: u64,
+}
+
+impl FieldSink for FieldWriter<'_> {
+    fn field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), FormatError> {
+        if self.skip.contains(&name) {
+            return Ok(());
+        }
+
+        bincode::serialize_into(&mut *self.buffer, name)?;
+
+        /* `data` is a `Vec<u8>` whose length is only known once it is written. */
+        let length_offset = self.buffer.len();
+        bincode::serialize_into(&mut *self.buffer, &0u64)?;
+        bincode::serialize_into(&mut *self.buffer, value)?;
+
+        let length = (self.buffer.len() - length_offset - 8) as u64;
+        self.buffer[length_offset..length_offset + 8].copy_from_slice(&length.to_le_bytes());
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+impl<S: FieldSink> ser::Serializer for &mut FieldCollector<S> {
+    type Ok = ();
+    type Error = FormatError;
+
+    type SerializeSeq = ser::Impossible<(), FormatError>;
+    type SerializeTuple = ser::Impossible<(), FormatError>;
+    type SerializeTupleStruct = ser::Impossible<(), FormatError>;
+    type SerializeTupleVariant = ser::Impossible<(), FormatError>;
+    type SerializeMap = ser::Impossible<(), FormatError>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = ser::Impossible<(), FormatError>;
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, FormatError> {
+        Ok(self)
+    }
+
+    fn serialize_bool(self, _: bool) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_i8(self, _: i8) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_i16(self, _: i16) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_i32(self, _: i32) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_i64(self, _: i64) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_u8(self, _: u8) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_u16(self, _: u16) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_u32(self, _: u32) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_u64(self, _: u64) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_f32(self, _: f32) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_f64(self, _: f64) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_char(self, _: char) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_str(self, _: &str) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_none(self) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_unit(self) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+}
+
+impl<S: FieldSink> ser::SerializeStruct for &mut FieldCollector<S> {
+    type Ok = ();
+    type Error = FormatError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), FormatError> {
+        self.sink.field(name, value)
+    }
+
+    fn end(self) -> Result<(), FormatError> {
+        Ok(())
+    }
+}
+
+/// Deserializes a struct from `Field`s, skipping the ones it does not have.
+struct FieldsDeserializer<'de> {
+    fields: &'de [Field],
+}
+
+impl<'de> de::Deserializer<'de> for FieldsDeserializer<'de> {
+    type Error = FormatError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, _: V) -> Result<V::Value, FormatError> {
+        Err(FormatErrorKind::NotAStruct)?
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        names: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, FormatError> {
+        visitor.visit_map(FieldsAccess {
+            fields: self
+                .fields
+                .iter()
+                .filter(move |field| names.contains(&field.name.as_str())),
+            value: None,
+        })
+    }
+
+    ::serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+struct FieldsAccess<'de, I> {
+    fields: I,
+    value: Option<&'de [u8]>,
+}
+
+impl<'de, I: Iterator<Item = &'de Field>> de::MapAccess<'de> for FieldsAccess<'de, I> {
+    type Error = FormatError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, FormatError> {
+        match self.fields.next() {
+            Some(field) => {
+                self.value = Some(&field.data);
+                seed.deserialize(de::value::StrDeserializer::<FormatError>::new(&field.name))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, FormatError> {
+        let data = self.value.take().expect("Value is read after its key");
+        let options = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+
+        Ok(seed.deserialize(&mut bincode::Deserializer::from_slice(data, options))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{
+        compact, read, version, write, write_compact_into, write_into, Field, Fields, File, Header,
+        FORMAT_VERSION, LEGACY_LAYOUT, MAGIC,
+    };
+    use crate::validate::{tests::save_state as valid_save_state, validate};
+    use crate::{OamTableEntry, SaveState};
+
+    fn save_state() -> SaveState {
+        SaveState {
+            clock_cycles_since_start: 1234,
+            iocore_arm_registers: vec![1, 2, 3],
+            iocore_cartridge_name: "POKEMON EMER".to_string(),
+            iocore_cartridge_flash_is128: true,
+            iocore_gfx_renderer_renderer_obj_renderer_oamtable: (0..2)
+                .map(|_| OamTableEntry::default())
+                .collect(),
+            ..SaveState::default()
+        }
+    }
+
+    fn file(format_version: u16, fields: Fields) -> Vec<u8> {
+        let file = File {
+            header: Header {
+                magic: MAGIC,
+                format_version,
+                emulator_version: "0.0.1".to_string(),
+            },
+            fields,
+        };
+
+        bincode::serialize(&file).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = write(&save_state()).unwrap();
+
+        assert!(data.starts_with(&MAGIC));
+        assert_eq!(
+            bincode::serialize(&read(&data).unwrap()).unwrap(),
+            bincode::serialize(&save_state()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_headerless_state_is_migrated() {
+        /* A bare bincode of the fields `SaveState` had back then. */
+        let fields = Fields::from_save_state(&save_state()).unwrap();
+        let data: Vec<u8> = LEGACY_LAYOUT
+            .iter()
+            .flat_map(|(name, _)| {
+                let field = fields.0.iter().find(|field| field.name == *name).unwrap();
+                field.data.clone()
+            })
+            .collect();
+
+        let save_state = read(&data).unwrap();
+        assert_eq!(version(&data).unwrap(), (0, None));
+        assert_eq!(save_state.clock_cycles_since_start, 1234);
+        assert_eq!(save_state.iocore_arm_registers, vec![1, 2, 3]);
+        assert_eq!(save_state.iocore_cartridge_name, "POKEMON EMER");
+        assert_eq!(
+            save_state
+                .iocore_gfx_renderer_renderer_obj_renderer_oamtable
+                .len(),
+            2
+        );
+        assert!(save_state.iocore_sound_fifoabuffer_buffer.is_empty());
+        assert!(read(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_missing_fields_default_and_unknown_fields_are_dropped() {
+        let mut fields = Fields::from_save_state(&save_state()).unwrap();
+        assert!(fields.remove("iocore_arm_registers"));
+        fields.insert("iocore_removed_long_ago", &7i32).unwrap();
+        fields.rename("clock_cycles_since_start", "last_timestamp");
+
+        let save_state = read(&file(FORMAT_VERSION, fields)).unwrap();
+
+        assert!(save_state.iocore_arm_registers.is_empty());
+        assert_eq!(save_state.clock_cycles_since_start, 0);
+        assert_eq!(save_state.last_timestamp, 1234);
+        assert_eq!(save_state.iocore_cartridge_name, "POKEMON EMER");
+    }
+
+    #[test]
+    fn test_flash_chip_follows_cartridge_before_version_3() {
+        let mut fields = Fields::from_save_state(&save_state()).unwrap();
+        fields.remove("iocore_saves_flashchip_largest_size_possible");
+        fields.remove("iocore_saves_flashchip_not_atmel");
+        fields.remove("iocore_saves_flashchip_saves");
+
+        let data = file(2, fields);
+        assert_eq!(version(&data).unwrap(), (2, Some("0.0.1".to_string())));
+
+        let save_state = read(&data).unwrap();
+
+        assert_eq!(
+            save_state.iocore_saves_flashchip_largest_size_possible,
+            0x20000
+        );
+        assert!(save_state.iocore_saves_flashchip_not_atmel);
+        assert!(save_state.iocore_saves_flashchip_saves.is_none());
+    }
+
+    #[test]
+    fn test_migrated_state_is_valid() {
+        /* Format version 1 had the fields of headerless states. */
+        let mut fields = Fields::from_save_state(&valid_save_state()).unwrap();
+        fields
+            .0
+            .retain(|field| LEGACY_LAYOUT.iter().any(|(name, _)| field.name == *name));
+
+        let save_state = read(&file(1, fields)).unwrap();
+        let report = validate(&save_state);
+
+        assert!(save_state.iocore_sound_channel3_pcm.is_empty());
+        assert!(report.is_valid(), "{}", report);
+    }
+
+    #[test]
+    fn test_newer_format_is_rejected() {
+        let fields = Fields(vec![Field {
+            name: "clock_cycles_since_start".to_string(),
+            data: bincode::serialize(&1i32).unwrap(),
+        }]);
+
+        assert!(read(&file(FORMAT_VERSION, Fields(Vec::new()))).is_ok());
+        assert!(read(&file(FORMAT_VERSION + 1, fields)).is_err());
+    }
+
+    #[test]
+    fn test_write_into_encodes_a_file_of_fields() {
+        let mut fields = Fields::from_save_state(&save_state()).unwrap();
+        let file = |fields| {
+            bincode::serialize(&File {
+                header: Header::current(),
+                fields,
+            })
+            .unwrap()
+        };
+
+        /* A reused buffer is appended to, callers clear it between states. */
+        let mut buffer = vec![0xFF];
+        write_into(&save_state(), &mut buffer).unwrap();
+        assert_eq!(
+            buffer[1..],
+            file(Fields::from_save_state(&save_state()).unwrap())
+        );
+
+        buffer.clear();
+        write_compact_into(&save_state(), &mut buffer).unwrap();
+        for name in compact::DERIVED_FIELDS {
+            fields.remove(name);
+        }
+        assert_eq!(buffer, file(fields));
+    }
+
+    #[test]
+    fn test_memories_keep_the_layout_of_byte_vectors() {
+        let fields = Fields::from_save_state(&SaveState {
+            iocore_memory_internal_ram: vec![1, 2, 3],
+            iocore_saves_sramchip_saves: Some(vec![4]),
+            ..SaveState::default()
+        })
+        .unwrap();
+
+        assert_eq!(
+            fields.get::<Vec<u8>>("iocore_memory_internal_ram").unwrap(),
+            Some(vec![1, 2, 3])
+        );
+        assert_eq!(
+            fields
+                .0
+                .iter()
+                .find(|field| field.name == "iocore_saves_sramchip_saves")
+                .unwrap()
+                .data,
+            bincode::serialize(&Some(vec![4u8])).unwrap()
+        );
+    }
+}
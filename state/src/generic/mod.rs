@@ -0,0 +1,141 @@
+//! A schema-less state for emulator fields `SaveState` does not know yet.
+//!
+//! The binding layer walks the JS save-state object key by key and stores every value with its JS type, so decoding
+//! rebuilds an object of the same shape without any knowledge of the emulator. Unlike `format` there are no
+//! migrations: the emulator that loads the state has to understand the keys it was saved with.
+
+use ::serde::{Deserialize, Serialize};
+
+use crate::format::{FormatError, FormatErrorKind};
+
+pub const MAGIC: [u8; 4] = *b"IGBG";
+
+/// A JS value of one of the types the emulator saves.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub enum Value {
+    #[default]
+    Undefined,
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Int8Array(Vec<i8>),
+    Uint8Array(Vec<u8>),
+    Uint8ClampedArray(Vec<u8>),
+    Int16Array(Vec<i16>),
+    Uint16Array(Vec<u16>),
+    Int32Array(Vec<i32>),
+    Uint32Array(Vec<u32>),
+    Float32Array(Vec<f32>),
+    Float64Array(Vec<f64>),
+    Array(Vec<Value>),
+    Object(Record),
+}
+
+/// The properties of a JS object, in the order of `Object.keys`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Record(pub Vec<(String, Value)>);
+
+#[derive(Serialize, Deserialize, Debug)]
+struct File {
+    magic: [u8; 4],
+    /// Version of the `state` crate that wrote the file.
+    emulator_version: String,
+    record: Record,
+}
+
+/// Whether `data` was written by `write` rather than `format::write`.
+pub fn is_generic(data: &[u8]) -> bool {
+    data.starts_with(&MAGIC)
+}
+
+pub fn write(record: Record) -> Result<Vec<u8>, FormatError> {
+    let file = File {
+        magic: MAGIC,
+        emulator_version: env!("CARGO_PKG_VERSION").to_string(),
+        record,
+    };
+
+    Ok(bincode::serialize(&file)?)
+}
+
+pub fn read(data: &[u8]) -> Result<Record, FormatError> {
+    if !is_generic(data) {
+        Err(FormatErrorKind::BadMagic)?
+    }
+
+    let file: File = bincode::deserialize(data)?;
+
+    Ok(file.record)
+}
+
+impl Value {
+    /// The JS type the value came from, for errors.
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Value::Undefined => "undefined",
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Int8Array(_) => "Int8Array",
+            Value::Uint8Array(_) => "Uint8Array",
+            Value::Uint8ClampedArray(_) => "Uint8ClampedArray",
+            Value::Int16Array(_) => "Int16Array",
+            Value::Uint16Array(_) => "Uint16Array",
+            Value::Int32Array(_) => "Int32Array",
+            Value::Uint32Array(_) => "Uint32Array",
+            Value::Float32Array(_) => "Float32Array",
+            Value::Float64Array(_) => "Float64Array",
+            Value::Array(_) => "Array",
+            Value::Object(_) => "Object",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generic::{is_generic, read, write, Record, Value};
+
+    fn record() -> Record {
+        Record(vec![
+            ("IOCore.ARM.decode".to_string(), Value::Number(-3.0)),
+            (
+                "IOCore.cartridge.name".to_string(),
+                Value::String("POKEMON EMER".to_string()),
+            ),
+            ("IOCore.saves.FLASHChip.saves".to_string(), Value::Null),
+            (
+                "IOCore.memory.internalRAM".to_string(),
+                Value::Uint8Array(vec![1, 2, 3]),
+            ),
+            (
+                "IOCore.sound.channel3.PCM".to_string(),
+                Value::Int8Array(vec![-1, 1]),
+            ),
+            (
+                "IOCore.gfxRenderer.renderer.objRenderer.OAMTable".to_string(),
+                Value::Array(vec![Value::Object(Record(vec![
+                    ("ycoord".to_string(), Value::Number(160.0)),
+                    ("horizontalFlip".to_string(), Value::Bool(true)),
+                ]))]),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = write(record()).unwrap();
+
+        assert!(is_generic(&data));
+        assert_eq!(read(&data).unwrap(), record());
+    }
+
+    #[test]
+    fn test_typed_state_is_rejected() {
+        let data = crate::format::write(&crate::SaveState::default()).unwrap();
+
+        assert!(!is_generic(&data));
+        assert!(read(&data).is_err());
+    }
+}
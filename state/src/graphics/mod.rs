@@ -0,0 +1,594 @@
+//! Pictures of the video memory of a `SaveState` for debugging: palettes, tile sheets, background maps and sprites.
+//!
+//! Everything is drawn from VRAM, palette RAM, the parsed OAM table and the display registers in `IOData16`, the way
+//! `IodineGBA/core/graphics` reads them, without effects such as mosaic, blending or rotation. Palette entry 0 of tiles
+//! is transparent.
+
+use crate::screenshot::{bgr_to_rgba, Screenshot};
+use crate::{OamTableEntry, SaveState};
+
+/// Pixels per side of a color in `palettes`.
+pub const SWATCH: u32 = 8;
+
+/// Tiles per row of `tiles`.
+pub const SHEET_TILES: u32 = 32;
+
+/// Where sprite tiles start in VRAM.
+const OBJ_TILES: usize = 0x10000;
+
+/// The first sprite color in palette RAM, which holds 512 colors.
+const OBJ_PALETTE: usize = 0x100;
+
+/// Screen blocks of text backgrounds are 32×32 entries.
+const SCREEN_BLOCK: usize = 0x800;
+
+const TRANSPARENT: [u8; 4] = [0; 4];
+
+/// `lookupXSize` and `lookupYSize` in `IodineGBA/core/graphics/OBJ.js`, by shape and size.
+const SPRITE_SIZES: [[(u32, u32); 4]; 3] = [
+    [(8, 8), (16, 16), (32, 32), (64, 64)],
+    [(16, 8), (32, 8), (32, 16), (64, 32)],
+    [(8, 16), (8, 32), (16, 32), (32, 64)],
+];
+
+/// Bits per pixel of a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Depth {
+    /// 16 colors out of one of the 16 palette banks.
+    Bpp4,
+    /// 256 colors.
+    Bpp8,
+}
+
+impl Depth {
+    fn tile_bytes(self) -> usize {
+        match self {
+            Depth::Bpp4 => 32,
+            Depth::Bpp8 => 64,
+        }
+    }
+}
+
+/// Which half of palette RAM colors come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Palette {
+    Background,
+    Object,
+}
+
+impl Palette {
+    fn offset(self) -> usize {
+        match self {
+            Palette::Background => 0,
+            Palette::Object => OBJ_PALETTE,
+        }
+    }
+}
+
+/// A tiled background layer as `DISPCNT` and its `BGxCNT` set it up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Background {
+    /// 0 to 3.
+    pub index: usize,
+    /// Affine layers are rotated and scaled, their maps are bytes and their tiles always 256 colors.
+    pub affine: bool,
+    /// Whether `DISPCNT` shows the layer.
+    pub enabled: bool,
+    pub priority: u32,
+    /// Where tiles start in VRAM.
+    pub char_base: usize,
+    /// Where the map starts in VRAM.
+    pub screen_base: usize,
+    pub depth: Depth,
+    pub mosaic: bool,
+    /// Affine layers repeat outside of their map instead of being transparent.
+    pub wrap: bool,
+    /// Of the whole map in pixels.
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Background {
+    /// Layer `index` of the current video mode, `None` if the mode has no tiled layer there.
+    pub fn of(save_state: &SaveState, index: usize) -> Option<Background> {
+        let display_control = display_control(save_state);
+        let affine = match (display_control & 0x7, index) {
+            (0, 0..=3) | (1, 0..=1) => false,
+            (1, 2) | (2, 2..=3) => true,
+            _ => return None,
+        };
+
+        let control = *save_state.iocore_gfx_renderer_iodata16.get(2 + index)? as u32;
+        let size = control >> 14;
+        let (width, height) = match (affine, size) {
+            (true, size) => (128 << size, 128 << size),
+            (false, 0) => (256, 256),
+            (false, 1) => (512, 256),
+            (false, 2) => (256, 512),
+            (false, _) => (512, 512),
+        };
+
+        Some(Background {
+            index,
+            affine,
+            enabled: display_control & (0x100 << index) != 0,
+            priority: control & 0x3,
+            char_base: ((control >> 2) & 0x3) as usize * 0x4000,
+            screen_base: ((control >> 8) & 0x1F) as usize * SCREEN_BLOCK,
+            depth: if affine || control & 0x80 != 0 {
+                Depth::Bpp8
+            } else {
+                Depth::Bpp4
+            },
+            mosaic: control & 0x40 != 0,
+            wrap: affine && control & 0x2000 != 0,
+            width,
+            height,
+        })
+    }
+
+    /// The whole map, unscrolled and untransformed.
+    pub fn render(&self, save_state: &SaveState) -> Screenshot {
+        let vram = &save_state.iocore_gfx_renderer_renderer_vram;
+        let palette_ram = &save_state.iocore_gfx_renderer_renderer_palette_ram;
+        let mut canvas = Canvas::new(self.width, self.height);
+        let tiles_per_row = self.width as usize / 8;
+
+        for tile_y in 0..self.height as usize / 8 {
+            for tile_x in 0..tiles_per_row {
+                let tile = if self.affine {
+                    let entry = vram
+                        .get(self.screen_base + tile_y * tiles_per_row + tile_x)
+                        .copied()
+                        .unwrap_or(0);
+
+                    Tile {
+                        address: self.char_base + entry as usize * Depth::Bpp8.tile_bytes(),
+                        depth: Depth::Bpp8,
+                        bank: 0,
+                        horizontal_flip: false,
+                        vertical_flip: false,
+                    }
+                } else {
+                    /* Screen blocks follow each other left to right, then top to bottom. */
+                    let block = (tile_y / 32) * (tiles_per_row / 32) + tile_x / 32;
+                    let address = self.screen_base
+                        + block * SCREEN_BLOCK
+                        + ((tile_y % 32) * 32 + tile_x % 32) * 2;
+                    let entry = read_u16(vram, address);
+
+                    Tile {
+                        address: self.char_base
+                            + (entry & 0x3FF) as usize * self.depth.tile_bytes(),
+                        depth: self.depth,
+                        bank: (entry >> 12) as usize,
+                        horizontal_flip: entry & 0x400 != 0,
+                        vertical_flip: entry & 0x800 != 0,
+                    }
+                };
+
+                tile.draw(
+                    &mut canvas,
+                    tile_x as u32 * 8,
+                    tile_y as u32 * 8,
+                    vram,
+                    palette_ram,
+                    Palette::Background,
+                );
+            }
+        }
+
+        canvas.into_screenshot()
+    }
+}
+
+/// An OAM entry, positions and sizes in pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sprite {
+    /// 0 to 127.
+    pub index: usize,
+    /// 0 to 511, sprites past the right edge wrap around to the left.
+    pub x: u32,
+    /// 0 to 255, sprites past the bottom edge wrap around to the top.
+    pub y: u32,
+    /// Without `double_size`.
+    pub width: u32,
+    pub height: u32,
+    pub tile: usize,
+    pub priority: u32,
+    /// The palette bank of 16 color sprites.
+    pub bank: usize,
+    pub depth: Depth,
+    /// The rotation and scaling parameters an affine sprite uses, 0 to 31.
+    pub affine: Option<usize>,
+    /// Affine sprites are drawn into twice their size.
+    pub double_size: bool,
+    /// Only sprites that are not affine can be hidden.
+    pub disabled: bool,
+    pub horizontal_flip: bool,
+    pub vertical_flip: bool,
+    /// 0 normal, 1 semi-transparent, 2 an object window.
+    pub mode: u32,
+    pub mosaic: bool,
+}
+
+impl Sprite {
+    fn new(index: usize, entry: &OamTableEntry) -> Sprite {
+        let affine = entry.matrix2_d != 0;
+        let double_size = entry.double_size_or_disabled != 0;
+        let (width, height) = SPRITE_SIZES
+            .get(entry.shape as usize)
+            .and_then(|sizes| sizes.get(entry.size as usize))
+            .copied()
+            .unwrap_or((8, 8));
+
+        Sprite {
+            index,
+            x: entry.xcoord as u32,
+            y: entry.ycoord as u32,
+            width,
+            height,
+            tile: entry.tile_number as usize,
+            priority: entry.priority as u32,
+            bank: (entry.palette_number >> 4) as usize,
+            depth: if entry.monolithic_palette != 0 {
+                Depth::Bpp8
+            } else {
+                Depth::Bpp4
+            },
+            affine: affine.then_some((entry.matrix_parameters >> 2) as usize),
+            double_size: affine && double_size,
+            disabled: !affine && double_size,
+            horizontal_flip: !affine && entry.horizontal_flip != 0,
+            vertical_flip: !affine && entry.vertical_flip != 0,
+            mode: entry.mode as u32,
+            mosaic: entry.mosaic != 0,
+        }
+    }
+
+    /// The sprite's tiles at their size, flipped but not rotated or scaled.
+    pub fn render(&self, save_state: &SaveState) -> Screenshot {
+        let vram = &save_state.iocore_gfx_renderer_renderer_vram;
+        let palette_ram = &save_state.iocore_gfx_renderer_renderer_palette_ram;
+        let one_dimensional = display_control(save_state) & 0x40 != 0;
+        let mut canvas = Canvas::new(self.width, self.height);
+        let (columns, rows) = (self.width as usize / 8, self.height as usize / 8);
+
+        for row in 0..rows {
+            /* `tileNumberToAddress16` and `tileNumberToAddress256`, tile numbers count 32 bytes. */
+            let first = match (one_dimensional, self.depth) {
+                (true, Depth::Bpp4) => self.tile + row * columns,
+                (true, Depth::Bpp8) => self.tile + row * columns * 2,
+                (false, Depth::Bpp4) => self.tile + row * 32,
+                (false, Depth::Bpp8) => (self.tile & !1) + row * 32,
+            };
+
+            for column in 0..columns {
+                let offset = first * 32 + column * self.depth.tile_bytes();
+                let tile = Tile {
+                    address: OBJ_TILES + (offset & 0x7FFF),
+                    depth: self.depth,
+                    bank: self.bank,
+                    horizontal_flip: self.horizontal_flip,
+                    vertical_flip: self.vertical_flip,
+                };
+
+                let x = if self.horizontal_flip {
+                    columns - 1 - column
+                } else {
+                    column
+                };
+                let y = if self.vertical_flip {
+                    rows - 1 - row
+                } else {
+                    row
+                };
+
+                tile.draw(
+                    &mut canvas,
+                    x as u32 * 8,
+                    y as u32 * 8,
+                    vram,
+                    palette_ram,
+                    Palette::Object,
+                );
+            }
+        }
+
+        canvas.into_screenshot()
+    }
+}
+
+/// Every OAM entry, in OAM order.
+pub fn sprites(save_state: &SaveState) -> Vec<Sprite> {
+    save_state
+        .iocore_gfx_renderer_renderer_obj_renderer_oamtable
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| Sprite::new(index, entry))
+        .collect()
+}
+
+/// The tiled layers of the current video mode.
+pub fn backgrounds(save_state: &SaveState) -> Vec<Background> {
+    (0..4)
+        .filter_map(|index| Background::of(save_state, index))
+        .collect()
+}
+
+/// Every color of palette RAM as a `SWATCH` pixel square, 16 per row. The 16 rows of background colors come first,
+/// then the 16 of sprite colors.
+pub fn palettes(save_state: &SaveState) -> Screenshot {
+    let palette_ram = &save_state.iocore_gfx_renderer_renderer_palette_ram;
+    let mut canvas = Canvas::new(16 * SWATCH, 32 * SWATCH);
+
+    for index in 0..512 {
+        let color = bgr_to_rgba(read_u16(palette_ram, index * 2));
+        let (x, y) = ((index % 16) as u32 * SWATCH, (index / 16) as u32 * SWATCH);
+
+        for dy in 0..SWATCH {
+            for dx in 0..SWATCH {
+                canvas.set(x + dx, y + dy, color);
+            }
+        }
+    }
+
+    canvas.into_screenshot()
+}
+
+/// All of VRAM as tiles of `depth`, `SHEET_TILES` per row, in colors of `palette`. `bank` picks the colors of
+/// 16 color tiles.
+pub fn tiles(save_state: &SaveState, depth: Depth, palette: Palette, bank: usize) -> Screenshot {
+    let vram = &save_state.iocore_gfx_renderer_renderer_vram;
+    let palette_ram = &save_state.iocore_gfx_renderer_renderer_palette_ram;
+    let count = vram.len() / depth.tile_bytes();
+    let rows = count.div_ceil(SHEET_TILES as usize) as u32;
+    let mut canvas = Canvas::new(SHEET_TILES * 8, rows * 8);
+
+    for index in 0..count {
+        let tile = Tile {
+            address: index * depth.tile_bytes(),
+            depth,
+            bank,
+            horizontal_flip: false,
+            vertical_flip: false,
+        };
+
+        tile.draw(
+            &mut canvas,
+            (index as u32 % SHEET_TILES) * 8,
+            (index as u32 / SHEET_TILES) * 8,
+            vram,
+            palette_ram,
+            palette,
+        );
+    }
+
+    canvas.into_screenshot()
+}
+
+fn display_control(save_state: &SaveState) -> u32 {
+    save_state
+        .iocore_gfx_renderer_iodata16
+        .first()
+        .copied()
+        .unwrap_or(0) as u32
+}
+
+/// Little endian, 0 past the end of `bytes`.
+fn read_u16(bytes: &[u8], address: usize) -> u16 {
+    match bytes.get(address..address + 2) {
+        Some(bytes) => u16::from_le_bytes([bytes[0], bytes[1]]),
+        None => 0,
+    }
+}
+
+/// An 8×8 tile in VRAM.
+struct Tile {
+    address: usize,
+    depth: Depth,
+    bank: usize,
+    horizontal_flip: bool,
+    vertical_flip: bool,
+}
+
+impl Tile {
+    /// The palette index of a pixel, 0 past the end of VRAM.
+    fn pixel(&self, vram: &[u8], x: usize, y: usize) -> usize {
+        let x = if self.horizontal_flip { 7 - x } else { x };
+        let y = if self.vertical_flip { 7 - y } else { y };
+
+        match self.depth {
+            Depth::Bpp4 => {
+                let byte = vram.get(self.address + y * 4 + x / 2).copied().unwrap_or(0);
+
+                if x % 2 == 0 {
+                    (byte & 0xF) as usize
+                } else {
+                    (byte >> 4) as usize
+                }
+            }
+            Depth::Bpp8 => vram.get(self.address + y * 8 + x).copied().unwrap_or(0) as usize,
+        }
+    }
+
+    fn draw(
+        &self,
+        canvas: &mut Canvas,
+        left: u32,
+        top: u32,
+        vram: &[u8],
+        palette_ram: &[u8],
+        palette: Palette,
+    ) {
+        for y in 0..8 {
+            for x in 0..8 {
+                let color = match (self.pixel(vram, x, y), self.depth) {
+                    (0, _) => TRANSPARENT,
+                    (index, Depth::Bpp4) => bgr_to_rgba(read_u16(
+                        palette_ram,
+                        (palette.offset() + self.bank * 16 + index) * 2,
+                    )),
+                    (index, Depth::Bpp8) => {
+                        bgr_to_rgba(read_u16(palette_ram, (palette.offset() + index) * 2))
+                    }
+                };
+
+                canvas.set(left + x as u32, top + y as u32, color);
+            }
+        }
+    }
+}
+
+/// RGBA pixels being drawn, transparent to start with.
+struct Canvas {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32) -> Canvas {
+        Canvas {
+            width,
+            height,
+            rgba: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    fn set(&mut self, x: u32, y: u32, color: [u8; 4]) {
+        let start = (y as usize * self.width as usize + x as usize) * 4;
+        self.rgba[start..start + 4].copy_from_slice(&color);
+    }
+
+    fn into_screenshot(self) -> Screenshot {
+        Screenshot::new(self.width, self.height, self.rgba)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graphics::{
+        backgrounds, palettes, sprites, tiles, Background, Depth, Palette, SHEET_TILES, SWATCH,
+    };
+    use crate::{OamTableEntry, SaveState};
+
+    /// Mode 0 with BG0 shown, a red background color 1 in bank 2 and a green sprite color 1 in bank 0.
+    fn save_state() -> SaveState {
+        let mut s = SaveState {
+            iocore_gfx_renderer_iodata16: vec![0; 10],
+            iocore_gfx_renderer_renderer_vram: vec![0; 0x18000],
+            iocore_gfx_renderer_renderer_palette_ram: vec![0; 0x400],
+            ..SaveState::default()
+        };
+
+        s.iocore_gfx_renderer_iodata16[0] = 0x0100;
+        s.iocore_gfx_renderer_renderer_palette_ram[(2 * 16 + 1) * 2] = 0x1F;
+        s.iocore_gfx_renderer_renderer_palette_ram[(0x100 + 1) * 2] = 0xE0;
+        s.iocore_gfx_renderer_renderer_palette_ram[(0x100 + 1) * 2 + 1] = 0x03;
+
+        s
+    }
+
+    #[test]
+    fn test_palettes_and_tile_sheets() {
+        let s = save_state();
+
+        let palettes = palettes(&s);
+        assert_eq!(
+            (palettes.width(), palettes.height()),
+            (16 * SWATCH, 32 * SWATCH)
+        );
+        let red = ((2 * SWATCH * 16 * SWATCH + SWATCH) * 4) as usize;
+        assert_eq!(palettes.rgba()[red..red + 4], [0xF8, 0, 0, 0xFF]);
+
+        let mut s = s;
+        /* Tile 1 of 4bpp tiles: its top left pixel has color 1. */
+        s.iocore_gfx_renderer_renderer_vram[32] = 0x01;
+
+        let sheet = tiles(&s, Depth::Bpp4, Palette::Background, 2);
+        assert_eq!(sheet.width(), SHEET_TILES * 8);
+        assert_eq!(sheet.height(), 0x18000 / 32 / SHEET_TILES * 8);
+        assert_eq!(sheet.rgba()[0..4], [0; 4]);
+        assert_eq!(sheet.rgba()[8 * 4..8 * 4 + 4], [0xF8, 0, 0, 0xFF]);
+
+        let sheet = tiles(&s, Depth::Bpp8, Palette::Object, 0);
+        assert_eq!(sheet.height(), 0x18000 / 64 / SHEET_TILES * 8);
+    }
+
+    #[test]
+    fn test_background_layers_by_mode() {
+        let mut s = save_state();
+        /* BG0: 512×256, 4bpp, tiles at 0x4000 and the map at screen block 16. */
+        s.iocore_gfx_renderer_iodata16[2] = 0x4000 | (16 << 8) | (1 << 2);
+        s.iocore_gfx_renderer_renderer_vram[0x4000 + 32] = 0x01;
+        /* The first entry of the second screen block: tile 1, flipped horizontally, bank 2. */
+        let entry: u16 = 1 | 0x400 | (2 << 12);
+        s.iocore_gfx_renderer_renderer_vram[0x8800..0x8802].copy_from_slice(&entry.to_le_bytes());
+
+        assert_eq!(backgrounds(&s).len(), 4);
+
+        let background = Background::of(&s, 0).unwrap();
+        assert!(background.enabled);
+        assert!(!background.affine);
+        assert_eq!((background.width, background.height), (512, 256));
+
+        let map = background.render(&s);
+        assert_eq!((map.width(), map.height()), (512, 256));
+        /* Tile 1 at (256, 0), its first pixel flipped to the right edge. */
+        let pixel = |x: usize| map.rgba()[x * 4..x * 4 + 4].to_vec();
+        assert_eq!(pixel(256), [0; 4]);
+        assert_eq!(pixel(263), [0xF8, 0, 0, 0xFF]);
+
+        s.iocore_gfx_renderer_iodata16[0] = 0x0401;
+        s.iocore_gfx_renderer_iodata16[4] = 0xC000;
+        let layers = backgrounds(&s);
+        assert_eq!(layers.len(), 3);
+        assert!(layers[2].affine);
+        assert!(layers[2].enabled);
+        assert_eq!(layers[2].depth, Depth::Bpp8);
+        assert_eq!((layers[2].width, layers[2].height), (1024, 1024));
+
+        s.iocore_gfx_renderer_iodata16[0] = 0x0403;
+        assert!(backgrounds(&s).is_empty());
+    }
+
+    #[test]
+    fn test_sprites() {
+        let mut s = save_state();
+        s.iocore_gfx_renderer_renderer_obj_renderer_oamtable = (0..128)
+            .map(|_| OamTableEntry {
+                double_size_or_disabled: 1,
+                ..OamTableEntry::default()
+            })
+            .collect();
+        /* A 16×8 sprite of tiles 4 and 5, flipped vertically. */
+        s.iocore_gfx_renderer_renderer_obj_renderer_oamtable[3] = OamTableEntry {
+            shape: 1,
+            xcoord: 300,
+            ycoord: 40,
+            tile_number: 4,
+            vertical_flip: 0x2000,
+            ..OamTableEntry::default()
+        };
+        s.iocore_gfx_renderer_renderer_vram[0x10000 + 5 * 32] = 0x10;
+
+        let sprites = sprites(&s);
+        assert_eq!(sprites.len(), 128);
+        assert!(sprites[0].disabled);
+
+        let sprite = &sprites[3];
+        assert!(!sprite.disabled);
+        assert_eq!((sprite.x, sprite.y), (300, 40));
+        assert_eq!((sprite.width, sprite.height), (16, 8));
+        assert!(sprite.vertical_flip);
+        assert_eq!(sprite.affine, None);
+
+        let picture = sprite.render(&s);
+        assert_eq!((picture.width(), picture.height()), (16, 8));
+        /* The second pixel of tile 5 ends up on the bottom row. */
+        let start = ((7 * 16 + 9) * 4) as usize;
+        assert_eq!(picture.rgba()[start..start + 4], [0, 0xF8, 0, 0xFF]);
+        assert_eq!(picture.rgba()[0..4], [0; 4]);
+    }
+}
@@ -0,0 +1,351 @@
+//! Labeled, read-only values of a `SaveState` for debugging tools.
+//!
+//! Register banking follows `switchRegisterBank` in `IodineGBA/core/CPU.js`: the current mode's registers are in
+//! `registers`, the other banks hold whatever was there when their mode was left.
+
+use crate::SaveState;
+
+/// Bits of `IOCore.cpu.modeFlags` besides the mode. The condition flags are not part of the state.
+pub const THUMB: i32 = 0x20;
+pub const FIQ_DISABLED: i32 = 0x40;
+pub const IRQ_DISABLED: i32 = 0x80;
+
+/// An ARM processor mode, as found in the low five bits of the CPSR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    User,
+    Fiq,
+    Irq,
+    Supervisor,
+    Abort,
+    Undefined,
+    System,
+}
+
+impl Mode {
+    const ALL: [Mode; 7] = [
+        Mode::User,
+        Mode::Fiq,
+        Mode::Irq,
+        Mode::Supervisor,
+        Mode::Abort,
+        Mode::Undefined,
+        Mode::System,
+    ];
+
+    pub fn from_flags(mode_flags: i32) -> Option<Mode> {
+        Mode::ALL
+            .iter()
+            .copied()
+            .find(|mode| mode.bits() == mode_flags & 0x1F)
+    }
+
+    pub fn from_name(name: &str) -> Option<Mode> {
+        Mode::ALL
+            .iter()
+            .copied()
+            .find(|mode| mode.name().eq_ignore_ascii_case(name))
+    }
+
+    fn bits(self) -> i32 {
+        match self {
+            Mode::User => 0x10,
+            Mode::Fiq => 0x11,
+            Mode::Irq => 0x12,
+            Mode::Supervisor => 0x13,
+            Mode::Abort => 0x17,
+            Mode::Undefined => 0x1B,
+            Mode::System => 0x1F,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Mode::User => "usr",
+            Mode::Fiq => "fiq",
+            Mode::Irq => "irq",
+            Mode::Supervisor => "svc",
+            Mode::Abort => "abt",
+            Mode::Undefined => "und",
+            Mode::System => "sys",
+        }
+    }
+
+    /// User and System share one bank.
+    fn bank(self) -> Mode {
+        match self {
+            Mode::System => Mode::User,
+            mode => mode,
+        }
+    }
+
+    /// Index into `IOCore.cpu.SPSR`, User and System have no SPSR.
+    fn spsr_index(self) -> Option<usize> {
+        match self {
+            Mode::Fiq => Some(0),
+            Mode::Irq => Some(1),
+            Mode::Supervisor => Some(2),
+            Mode::Abort => Some(3),
+            Mode::Undefined => Some(4),
+            Mode::User | Mode::System => None,
+        }
+    }
+}
+
+/// Bits of `IOCore.irq.interruptsEnabled`/`interruptsRequested`, the same as the IE and IF registers.
+pub const IRQ_NAMES: [&str; 14] = [
+    "VBlank", "HBlank", "VCounter", "Timer0", "Timer1", "Timer2", "Timer3", "Serial", "DMA0",
+    "DMA1", "DMA2", "DMA3", "Keypad", "GamePak",
+];
+
+pub fn irq_names(mask: i32) -> Vec<String> {
+    IRQ_NAMES
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| mask & (1 << bit) != 0)
+        .map(|(_, name)| name.to_string())
+        .collect()
+}
+
+/// R0-R15 as an instruction running in `mode` would see them.
+pub fn registers(save_state: &SaveState, mode: Mode) -> Vec<i32> {
+    let current = &save_state.iocore_arm_registers;
+    let current_mode = Mode::from_flags(save_state.iocore_cpu_mode_flags).map(Mode::bank);
+
+    if current.len() != 16 {
+        return current.clone();
+    }
+    if current_mode == Some(mode.bank()) {
+        return current.clone();
+    }
+
+    let usr = &save_state.iocore_arm_registers_usr;
+    let fiq = &save_state.iocore_cpu_registers_fiq;
+    let mut registers = current.clone();
+
+    /* R8-R12 are only banked in FIQ mode, everybody else shares them. */
+    match (mode, current_mode) {
+        (Mode::Fiq, _) => registers[8..13].copy_from_slice(&fiq[0..5]),
+        (_, Some(Mode::Fiq)) => registers[8..13].copy_from_slice(&usr[0..5]),
+        _ => {}
+    }
+
+    let banked = match mode.bank() {
+        Mode::User => &usr[5..7],
+        Mode::Fiq => &fiq[5..7],
+        Mode::Irq => &save_state.iocore_cpu_registers_irq[..],
+        Mode::Supervisor => &save_state.iocore_cpu_registers_svc[..],
+        Mode::Abort => &save_state.iocore_cpu_registers_abt[..],
+        Mode::Undefined => &save_state.iocore_cpu_registers_und[..],
+        Mode::System => unreachable!("System uses the User bank"),
+    };
+    registers[13..15].copy_from_slice(banked);
+
+    registers
+}
+
+pub fn spsr(save_state: &SaveState, mode: Mode) -> Option<u16> {
+    mode.spsr_index()
+        .and_then(|index| save_state.iocore_cpu_spsr.get(index).copied())
+}
+
+/// `IOCore.saves.saveType`, see `referenceSave` in `IodineGBA/core/Saves.js`.
+pub fn save_type_name(save_type: i32) -> &'static str {
+    match save_type {
+        0 => "Undetermined",
+        1 => "SRAM",
+        2 => "Flash",
+        3 => "EEPROM",
+        _ => "Unknown",
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timer {
+    pub enabled: bool,
+    pub counter: i32,
+    pub reload: i32,
+    pub prescalar: i32,
+    /// Counts the overflows of the previous timer, never set for timer 0.
+    pub count_up: bool,
+    pub irq: bool,
+}
+
+/// Fields are `None` where the emulator's state does not include the value for the channel.
+#[derive(Debug, Clone, Copy)]
+pub struct Dma {
+    pub enabled: Option<bool>,
+    pub source: i32,
+    pub destination: i32,
+    pub word_count: Option<i32>,
+    /// 0 immediately, 1 at v-blank, 2 at h-blank, 3 special.
+    pub timing: Option<i32>,
+    pub repeat: Option<bool>,
+    pub is32_bit: Option<bool>,
+}
+
+pub fn timer(s: &SaveState, index: usize) -> Option<Timer> {
+    let timer = match index {
+        0 => Timer {
+            enabled: s.iocore_timer_timer0_enabled,
+            counter: s.iocore_timer_timer0_counter,
+            reload: s.iocore_timer_timer0_reload,
+            prescalar: s.iocore_timer_timer0_prescalar,
+            count_up: false,
+            irq: s.iocore_timer_timer0_irq,
+        },
+        1 => Timer {
+            enabled: s.iocore_timer_timer1_enabled,
+            counter: s.iocore_timer_timer1_counter,
+            reload: s.iocore_timer_timer1_reload,
+            prescalar: s.iocore_timer_timer1_prescalar,
+            count_up: s.iocore_timer_timer1_count_up,
+            irq: s.iocore_timer_timer1_irq,
+        },
+        2 => Timer {
+            enabled: s.iocore_timer_timer2_enabled,
+            counter: s.iocore_timer_timer2_counter,
+            reload: s.iocore_timer_timer2_reload,
+            prescalar: s.iocore_timer_timer2_prescalar,
+            count_up: s.iocore_timer_timer2_count_up,
+            irq: s.iocore_timer_timer2_irq,
+        },
+        3 => Timer {
+            enabled: s.iocore_timer_timer3_enabled,
+            counter: s.iocore_timer_timer3_counter,
+            reload: s.iocore_timer_timer3_reload,
+            prescalar: s.iocore_timer_timer3_prescalar,
+            count_up: s.iocore_timer_timer3_count_up,
+            irq: s.iocore_timer_timer3_irq,
+        },
+        _ => return None,
+    };
+
+    Some(timer)
+}
+
+pub fn dma(s: &SaveState, index: usize) -> Option<Dma> {
+    let dma = match index {
+        0 => Dma {
+            enabled: Some(s.iocore_dma_channel0_enabled != 0),
+            source: s.iocore_dma_channel0_source,
+            destination: s.iocore_dma_channel0_destination,
+            word_count: Some(s.iocore_dma_channel0_word_count),
+            timing: Some(s.iocore_dma_channel0_dma_type),
+            repeat: Some(s.iocore_dma_channel0_repeat != 0),
+            is32_bit: Some(s.iocore_dma_channel0_is32_bit != 0),
+        },
+        1 => Dma {
+            enabled: Some(s.iocore_dma_channel1_enabled != 0),
+            source: s.iocore_dma_channel1_source,
+            destination: s.iocore_dma_channel1_destination,
+            word_count: Some(s.iocore_dma_channel1_word_count),
+            timing: Some(s.iocore_dma_channel1_dma_type),
+            repeat: Some(s.iocore_dma_channel1_repeat != 0),
+            is32_bit: Some(s.iocore_dma_channel1_is32_bit != 0),
+        },
+        2 => Dma {
+            enabled: Some(s.iocore_dma_channel2_enabled != 0),
+            source: s.iocore_dma_channel2_source,
+            destination: s.iocore_dma_channel2_destination,
+            word_count: None,
+            timing: None,
+            repeat: None,
+            is32_bit: None,
+        },
+        3 => Dma {
+            enabled: None,
+            source: s.iocore_dma_channel3_source,
+            destination: s.iocore_dma_channel3_destination,
+            word_count: Some(s.iocore_dma_channel3_word_count),
+            timing: None,
+            repeat: None,
+            is32_bit: None,
+        },
+        _ => return None,
+    };
+
+    Some(dma)
+}
+
+/// Fetch, decode and execute of the pipeline of the current instruction set.
+pub fn pipeline(s: &SaveState) -> [i32; 3] {
+    if s.iocore_cpu_mode_flags & THUMB != 0 {
+        [
+            s.iocore_thumb_fetch,
+            s.iocore_thumb_decode,
+            s.iocore_thumb_execute,
+        ]
+    } else {
+        [
+            s.iocore_arm_fetch,
+            s.iocore_arm_decode,
+            s.iocore_arm_execute,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inspect::{irq_names, registers, spsr, Mode};
+    use crate::SaveState;
+
+    fn save_state() -> SaveState {
+        SaveState {
+            iocore_cpu_mode_flags: 0x12,
+            iocore_arm_registers: (0..16).collect(),
+            iocore_arm_registers_usr: vec![-1, -2, -3, -4, -5, -6, -7],
+            iocore_cpu_registers_fiq: vec![81, 82, 83, 84, 85, 86, 87],
+            iocore_cpu_registers_irq: vec![131, 141],
+            iocore_cpu_registers_svc: vec![132, 142],
+            iocore_cpu_registers_abt: vec![133, 143],
+            iocore_cpu_registers_und: vec![134, 144],
+            iocore_cpu_spsr: vec![0x11, 0x1F, 0x13, 0x17, 0x1B],
+            ..SaveState::default()
+        }
+    }
+
+    #[test]
+    fn test_registers_per_mode() {
+        let s = save_state();
+
+        assert_eq!(registers(&s, Mode::Irq), (0..16).collect::<Vec<i32>>());
+        assert_eq!(
+            registers(&s, Mode::Supervisor)[8..16],
+            [8, 9, 10, 11, 12, 132, 142, 15]
+        );
+        assert_eq!(registers(&s, Mode::System)[12..15], [12, -6, -7]);
+        assert_eq!(
+            registers(&s, Mode::Fiq)[8..15],
+            [81, 82, 83, 84, 85, 86, 87]
+        );
+    }
+
+    #[test]
+    fn test_registers_from_fiq() {
+        let s = SaveState {
+            iocore_cpu_mode_flags: 0xD1,
+            ..save_state()
+        };
+
+        assert_eq!(registers(&s, Mode::Fiq), (0..16).collect::<Vec<i32>>());
+        assert_eq!(
+            registers(&s, Mode::User)[8..15],
+            [-1, -2, -3, -4, -5, -6, -7]
+        );
+        assert_eq!(
+            registers(&s, Mode::Abort)[8..15],
+            [-1, -2, -3, -4, -5, 133, 143]
+        );
+    }
+
+    #[test]
+    fn test_spsr_and_irqs() {
+        let s = save_state();
+
+        assert_eq!(spsr(&s, Mode::Irq), Some(0x1F));
+        assert_eq!(spsr(&s, Mode::User), None);
+        assert_eq!(Mode::from_name("SVC"), Some(Mode::Supervisor));
+        assert_eq!(irq_names(0x2009), ["VBlank", "Timer0", "GamePak"]);
+    }
+}
@@ -0,0 +1,1027 @@
+//! The emulator's save state and everything that works on it without a JS runtime.
+//!
+//! `SaveState` mirrors the object IodineGBA's `saveState` emits, `format` stores it, and the other modules read
+//! stored states for tools, the server and tests. `state-wasm` binds all of it to JS.
+
+use ::serde::{Deserialize, Serialize};
+
+use ::state_derive::JsObjectCodec;
+
+pub mod battery;
+
+pub mod cheat;
+
+pub mod format;
+
+pub mod generic;
+
+pub mod graphics;
+
+pub mod inspect;
+
+pub mod schema;
+
+pub mod screenshot;
+
+pub mod search;
+
+pub mod validate;
+
+fn vu8_to_vu16(vu8: &[u8]) -> Vec<u16> {
+    vu8.chunks_exact(2)
+        .map(|b| u16::from_ne_bytes([b[0], b[1]]))
+        .collect()
+}
+
+fn vu8_to_vi32(vu8: &[u8]) -> Vec<i32> {
+    vu8.chunks_exact(4)
+        .map(|b| i32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn vi8_to_vu16(vi8: &[i8]) -> Vec<u16> {
+    vi8.chunks_exact(2)
+        .map(|b| u16::from_ne_bytes([b[0] as u8, b[1] as u8]))
+        .collect()
+}
+
+fn vi8_to_vi32(vi8: &[i8]) -> Vec<i32> {
+    vi8.chunks_exact(4)
+        .map(|b| i32::from_ne_bytes([b[0] as u8, b[1] as u8, b[2] as u8, b[3] as u8]))
+        .collect()
+}
+
+#[derive(Serialize, Deserialize, Default, JsObjectCodec)]
+pub struct OamTableEntry {
+    #[js(key = "ycoord")]
+    pub ycoord: i32,
+    #[js(key = "matrix2D")]
+    pub matrix2_d: i32,
+    #[js(key = "doubleSizeOrDisabled")]
+    pub double_size_or_disabled: i32,
+    #[js(key = "mode")]
+    pub mode: i32,
+    #[js(key = "mosaic")]
+    pub mosaic: i32,
+    #[js(key = "monolithicPalette")]
+    pub monolithic_palette: i32,
+    #[js(key = "shape")]
+    pub shape: i32,
+    #[js(key = "xcoord")]
+    pub xcoord: i32,
+    #[js(key = "matrixParameters")]
+    pub matrix_parameters: i32,
+    #[js(key = "horizontalFlip")]
+    pub horizontal_flip: i32,
+    #[js(key = "verticalFlip")]
+    pub vertical_flip: i32,
+    #[js(key = "size")]
+    pub size: i32,
+    #[js(key = "tileNumber")]
+    pub tile_number: i32,
+    #[js(key = "priority")]
+    pub priority: i32,
+    #[js(key = "paletteNumber")]
+    pub palette_number: i32,
+}
+
+/// Fields missing from a stored state keep their default, see `format`.
+#[derive(Serialize, Deserialize, Default, JsObjectCodec)]
+#[serde(default)]
+pub struct SaveState {
+    #[js(key = "clockCyclesSinceStart")]
+    pub clock_cycles_since_start: i32,
+    #[js(key = "IOCore.accumulatedClocks")]
+    pub iocore_accumulated_clocks: i32,
+    #[js(key = "IOCore.ARM.decode")]
+    pub iocore_arm_decode: i32,
+    #[js(key = "IOCore.ARM.execute")]
+    pub iocore_arm_execute: i32,
+    #[js(key = "IOCore.ARM.fetch")]
+    pub iocore_arm_fetch: i32,
+    #[js(key = "IOCore.ARM.registers")]
+    pub iocore_arm_registers: Vec<i32>,
+    #[js(key = "IOCore.ARM.registersUSR")]
+    pub iocore_arm_registers_usr: Vec<i32>,
+    #[js(key = "IOCore.cartridge.EEPROMStart")]
+    pub iocore_cartridge_eepromstart: i32,
+    #[js(key = "IOCore.cartridge.flash_is128")]
+    pub iocore_cartridge_flash_is128: bool,
+    #[js(key = "IOCore.cartridge.flash_isAtmel")]
+    pub iocore_cartridge_flash_is_atmel: bool,
+    #[js(key = "IOCore.cartridge.name")]
+    pub iocore_cartridge_name: String,
+    #[js(key = "IOCore.cpu.modeFlags")]
+    pub iocore_cpu_mode_flags: i32,
+    #[js(key = "IOCore.cpu.mul64ResultHigh")]
+    pub iocore_cpu_mul64_result_high: i32,
+    #[js(key = "IOCore.cpu.mul64ResultLow")]
+    pub iocore_cpu_mul64_result_low: i32,
+    #[js(key = "IOCore.cpu.registersABT")]
+    pub iocore_cpu_registers_abt: Vec<i32>,
+    #[js(key = "IOCore.cpu.registersFIQ")]
+    pub iocore_cpu_registers_fiq: Vec<i32>,
+    #[js(key = "IOCore.cpu.registersIRQ")]
+    pub iocore_cpu_registers_irq: Vec<i32>,
+    #[js(key = "IOCore.cpu.registersSVC")]
+    pub iocore_cpu_registers_svc: Vec<i32>,
+    #[js(key = "IOCore.cpu.registersUND")]
+    pub iocore_cpu_registers_und: Vec<i32>,
+    #[js(key = "IOCore.cpu.SPSR")]
+    pub iocore_cpu_spsr: Vec<u16>,
+    #[js(key = "IOCore.cpu.triggeredIRQ")]
+    pub iocore_cpu_triggered_irq: i32,
+    #[js(key = "IOCore.cyclesOveriteratedPreviously")]
+    pub iocore_cycles_overiterated_previously: i32,
+    #[js(key = "IOCore.cyclesToIterate")]
+    pub iocore_cycles_to_iterate: i32,
+    #[js(key = "IOCore.dma.currentMatch")]
+    pub iocore_dma_current_match: i32,
+    #[js(key = "IOCore.dma.fetch")]
+    pub iocore_dma_fetch: i32,
+    #[js(key = "IOCore.dmaChannel0.destination")]
+    pub iocore_dma_channel0_destination: i32,
+    #[js(key = "IOCore.dmaChannel0.destinationControl")]
+    pub iocore_dma_channel0_destination_control: i32,
+    #[js(key = "IOCore.dmaChannel0.destinationShadow")]
+    pub iocore_dma_channel0_destination_shadow: i32,
+    #[js(key = "IOCore.dmaChannel0.dmaType")]
+    pub iocore_dma_channel0_dma_type: i32,
+    #[js(key = "IOCore.dmaChannel0.enabled")]
+    pub iocore_dma_channel0_enabled: i32,
+    #[js(key = "IOCore.dmaChannel0.irqFlagging")]
+    pub iocore_dma_channel0_irq_flagging: i32,
+    #[js(key = "IOCore.dmaChannel0.is32Bit")]
+    pub iocore_dma_channel0_is32_bit: i32,
+    #[js(key = "IOCore.dmaChannel0.pending")]
+    pub iocore_dma_channel0_pending: i32,
+    #[js(key = "IOCore.dmaChannel0.repeat")]
+    pub iocore_dma_channel0_repeat: i32,
+    #[js(key = "IOCore.dmaChannel0.source")]
+    pub iocore_dma_channel0_source: i32,
+    #[js(key = "IOCore.dmaChannel0.sourceControl")]
+    pub iocore_dma_channel0_source_control: i32,
+    #[js(key = "IOCore.dmaChannel0.sourceShadow")]
+    pub iocore_dma_channel0_source_shadow: i32,
+    #[js(key = "IOCore.dmaChannel0.wordCount")]
+    pub iocore_dma_channel0_word_count: i32,
+    #[js(key = "IOCore.dmaChannel0.wordCountShadow")]
+    pub iocore_dma_channel0_word_count_shadow: i32,
+    #[js(key = "IOCore.dmaChannel1.destination")]
+    pub iocore_dma_channel1_destination: i32,
+    #[js(key = "IOCore.dmaChannel1.destinationShadow")]
+    pub iocore_dma_channel1_destination_shadow: i32,
+    #[js(key = "IOCore.dmaChannel1.dmaType")]
+    pub iocore_dma_channel1_dma_type: i32,
+    #[js(key = "IOCore.dmaChannel1.enabled")]
+    pub iocore_dma_channel1_enabled: i32,
+    #[js(key = "IOCore.dmaChannel1.is32Bit")]
+    pub iocore_dma_channel1_is32_bit: i32,
+    #[js(key = "IOCore.dmaChannel1.repeat")]
+    pub iocore_dma_channel1_repeat: i32,
+    #[js(key = "IOCore.dmaChannel1.source")]
+    pub iocore_dma_channel1_source: i32,
+    #[js(key = "IOCore.dmaChannel1.sourceShadow")]
+    pub iocore_dma_channel1_source_shadow: i32,
+    #[js(key = "IOCore.dmaChannel1.wordCount")]
+    pub iocore_dma_channel1_word_count: i32,
+    #[js(key = "IOCore.dmaChannel1.wordCountShadow")]
+    pub iocore_dma_channel1_word_count_shadow: i32,
+    #[js(key = "IOCore.dmaChannel2.destination")]
+    pub iocore_dma_channel2_destination: i32,
+    #[js(key = "IOCore.dmaChannel2.destinationShadow")]
+    pub iocore_dma_channel2_destination_shadow: i32,
+    #[js(key = "IOCore.dmaChannel2.enabled")]
+    pub iocore_dma_channel2_enabled: i32,
+    #[js(key = "IOCore.dmaChannel2.source")]
+    pub iocore_dma_channel2_source: i32,
+    #[js(key = "IOCore.dmaChannel2.sourceShadow")]
+    pub iocore_dma_channel2_source_shadow: i32,
+    #[js(key = "IOCore.dmaChannel3.destination")]
+    pub iocore_dma_channel3_destination: i32,
+    #[js(key = "IOCore.dmaChannel3.destinationShadow")]
+    pub iocore_dma_channel3_destination_shadow: i32,
+    #[js(key = "IOCore.dmaChannel3.displaySyncEnableDelay")]
+    pub iocore_dma_channel3_display_sync_enable_delay: i32,
+    #[js(key = "IOCore.dmaChannel3.gamePakDMA")]
+    pub iocore_dma_channel3_game_pak_dma: i32,
+    #[js(key = "IOCore.dmaChannel3.source")]
+    pub iocore_dma_channel3_source: i32,
+    #[js(key = "IOCore.dmaChannel3.sourceControl")]
+    pub iocore_dma_channel3_source_control: i32,
+    #[js(key = "IOCore.dmaChannel3.sourceShadow")]
+    pub iocore_dma_channel3_source_shadow: i32,
+    #[js(key = "IOCore.dmaChannel3.wordCount")]
+    pub iocore_dma_channel3_word_count: i32,
+    #[js(key = "IOCore.gfxRenderer.IOData16")]
+    pub iocore_gfx_renderer_iodata16: Vec<u16>,
+    #[js(key = "IOCore.gfxRenderer.IOData32")]
+    pub iocore_gfx_renderer_iodata32: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.IOData8")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_gfx_renderer_iodata8: Vec<u8>,
+    #[js(key = "IOCore.gfxRenderer.renderer.backdrop")]
+    pub iocore_gfx_renderer_renderer_backdrop: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.BGCharacterBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_bgcharacter_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.BGLayer")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_bglayer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.BGXCoord")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_bgxcoord: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.BGYCoord")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_bgycoord: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.do256")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_do256: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.doMosaic")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_do_mosaic: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.offset")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_offset: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.priorityFlag")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_priority_flag: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.tileFetched")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_tile_fetched: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg0Renderer.tileMode")]
+    pub iocore_gfx_renderer_renderer_bg0_renderer_tile_mode: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.BGLayer")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_bglayer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.BGXCoord")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_bgxcoord: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.BGYCoord")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_bgycoord: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.offset")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_offset: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.priorityFlag")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_priority_flag: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg1Renderer.tileFetched")]
+    pub iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2FrameBufferRenderer.frameSelect")]
+    pub iocore_gfx_renderer_renderer_bg2_frame_buffer_renderer_frame_select: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.BGCharacterBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgcharacter_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.BGDisplayOverflow")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgdisplay_overflow: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.mapSize")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_map_size: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.mapSizeComparer")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_map_size_comparer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2MatrixRenderer.palette")]
+    pub iocore_gfx_renderer_renderer_bg2_matrix_renderer_palette: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.BGCharacterBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_bgcharacter_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.BGLayer")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_bglayer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.BGYCoord")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_bgycoord: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.offset")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_offset: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.priorityFlag")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_priority_flag: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.tileFetched")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_tile_fetched: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg2TextRenderer.tileMode")]
+    pub iocore_gfx_renderer_renderer_bg2_text_renderer_tile_mode: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3MatrixRenderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg3_matrix_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3MatrixRenderer.mapSize")]
+    pub iocore_gfx_renderer_renderer_bg3_matrix_renderer_map_size: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3MatrixRenderer.mapSizeComparer")]
+    pub iocore_gfx_renderer_renderer_bg3_matrix_renderer_map_size_comparer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.BGLayer")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_bglayer: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.BGScreenBaseBlock")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_bgscreen_base_block: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.offset")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_offset: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.priorityFlag")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_priority_flag: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bg3TextRenderer.tileFetched")]
+    pub iocore_gfx_renderer_renderer_bg3_text_renderer_tile_fetched: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGdmx")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdmx: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGdmy")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdmy: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGdx")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdx: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGdy")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgdy: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGReferenceX")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgreference_x: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.BGReferenceY")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_bgreference_y: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.pb")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_pb: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.pd")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_pd: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer0.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer0_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.BGdmy")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_bgdmy: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.BGdx")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_bgdx: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.BGReferenceX")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_bgreference_x: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.BGReferenceY")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_bgreference_y: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.pb")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_pb: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.pd")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_pd: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.bgAffineRenderer1.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.buffer")]
+    pub iocore_gfx_renderer_renderer_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.alphaBlendAmountTarget1")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_alpha_blend_amount_target1: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.alphaBlendAmountTarget2")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_alpha_blend_amount_target2: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.brightnessEffectAmount")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_brightness_effect_amount: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.colorEffectsType")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_color_effects_type: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.effectsTarget1")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_effects_target1: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.colorEffectsRenderer.effectsTarget2")]
+    pub iocore_gfx_renderer_renderer_color_effects_renderer_effects_target2: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.compositor.doEffects")]
+    pub iocore_gfx_renderer_renderer_compositor_do_effects: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.display")]
+    pub iocore_gfx_renderer_renderer_display: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.displayControl")]
+    pub iocore_gfx_renderer_renderer_display_control: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.frameBuffer")]
+    pub iocore_gfx_renderer_renderer_frame_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.greenSwap")]
+    pub iocore_gfx_renderer_renderer_green_swap: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.lastUnrenderedLine")]
+    pub iocore_gfx_renderer_renderer_last_unrendered_line: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.lineBuffer")]
+    pub iocore_gfx_renderer_renderer_line_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.mosaicRenderer.BGMosaicHSize")]
+    pub iocore_gfx_renderer_renderer_mosaic_renderer_bgmosaic_hsize: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.mosaicRenderer.BGMosaicVSize")]
+    pub iocore_gfx_renderer_renderer_mosaic_renderer_bgmosaic_vsize: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.mosaicRenderer.OBJMosaicHSize")]
+    pub iocore_gfx_renderer_renderer_mosaic_renderer_objmosaic_hsize: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.mosaicRenderer.OBJMosaicVSize")]
+    pub iocore_gfx_renderer_renderer_mosaic_renderer_objmosaic_vsize: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.cyclesToRender")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_cycles_to_render: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMRAM")]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMRAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMRAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_oamram: Vec<u8>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.OAMTable", array)]
+    pub iocore_gfx_renderer_renderer_obj_renderer_oamtable: Vec<OamTableEntry>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.OBJMatrixParameters")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_objmatrix_parameters: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.scratchBuffer")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_scratch_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.scratchOBJBuffer")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_scratch_objbuffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objRenderer.scratchWindowBuffer")]
+    pub iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objWindowRenderer.compositor.OBJWindowBuffer")]
+    pub iocore_gfx_renderer_renderer_obj_window_renderer_compositor_objwindow_buffer: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.objWindowRenderer.WINOBJOutside")]
+    pub iocore_gfx_renderer_renderer_obj_window_renderer_winobjoutside: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.palette16")]
+    pub iocore_gfx_renderer_renderer_palette16: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.palette256")]
+    pub iocore_gfx_renderer_renderer_palette256: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.paletteOBJ16")]
+    pub iocore_gfx_renderer_renderer_palette_obj16: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.paletteOBJ256")]
+    pub iocore_gfx_renderer_renderer_palette_obj256: Vec<i32>,
+    #[js(key = "IOCore.gfxRenderer.renderer.paletteRAM")]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.paletteRAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.paletteRAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_gfx_renderer_renderer_palette_ram: Vec<u8>,
+    #[js(key = "IOCore.gfxRenderer.renderer.queuedScanLines")]
+    pub iocore_gfx_renderer_renderer_queued_scan_lines: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.swizzledFrame")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_gfx_renderer_renderer_swizzled_frame: Vec<u8>,
+    #[js(key = "IOCore.gfxRenderer.renderer.totalLinesPassed")]
+    pub iocore_gfx_renderer_renderer_total_lines_passed: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.VRAM")]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.VRAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.gfxRenderer.renderer.VRAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_gfx_renderer_renderer_vram: Vec<u8>,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.compositor.doEffects")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_compositor_do_effects: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.windowDisplayControl")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_window_display_control: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.WINXCoordLeft")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_winxcoord_left: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.WINXCoordRight")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_winxcoord_right: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.WINYCoordBottom")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_winycoord_bottom: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.window0Renderer.WINYCoordTop")]
+    pub iocore_gfx_renderer_renderer_window0_renderer_winycoord_top: i32,
+    #[js(key = "IOCore.gfxRenderer.renderer.WINOutside")]
+    pub iocore_gfx_renderer_renderer_winoutside: i32,
+    #[js(key = "IOCore.gfxState.currentScanLine")]
+    pub iocore_gfx_state_current_scan_line: i32,
+    #[js(key = "IOCore.gfxState.IRQFlags")]
+    pub iocore_gfx_state_irqflags: i32,
+    #[js(key = "IOCore.gfxState.LCDTicks")]
+    pub iocore_gfx_state_lcdticks: i32,
+    #[js(key = "IOCore.gfxState.renderedScanLine")]
+    pub iocore_gfx_state_rendered_scan_line: bool,
+    #[js(key = "IOCore.gfxState.statusFlags")]
+    pub iocore_gfx_state_status_flags: i32,
+    #[js(key = "IOCore.gfxState.VCounter")]
+    pub iocore_gfx_state_vcounter: i32,
+    #[js(key = "IOCore.irq.interruptsEnabled")]
+    pub iocore_irq_interrupts_enabled: i32,
+    #[js(key = "IOCore.irq.interruptsRequested")]
+    pub iocore_irq_interrupts_requested: i32,
+    #[js(key = "IOCore.joypad.keyInput")]
+    pub iocore_joypad_key_input: i32,
+    #[js(key = "IOCore.joypad.keyInterrupt")]
+    pub iocore_joypad_key_interrupt: i32,
+    #[js(key = "IOCore.memory.externalRAM")]
+    #[js(view(key = "IOCore.memory.externalRAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.memory.externalRAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_memory_external_ram: Vec<u8>,
+    #[js(key = "IOCore.memory.internalRAM")]
+    #[js(view(key = "IOCore.memory.internalRAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.memory.internalRAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_memory_internal_ram: Vec<u8>,
+    #[js(key = "IOCore.memory.irq.IME")]
+    pub iocore_memory_irq_ime: i32,
+    #[js(key = "IOCore.memory.lastBIOSREAD")]
+    pub iocore_memory_last_biosread: i32,
+    #[js(key = "IOCore.memory.WRAMControlFlags")]
+    pub iocore_memory_wramcontrol_flags: i32,
+    #[js(key = "IOCore.nextEventClocks")]
+    pub iocore_next_event_clocks: i32,
+    #[js(key = "IOCore.saves.EEPROMChip.address")]
+    pub iocore_saves_eepromchip_address: i32,
+    #[js(key = "IOCore.saves.EEPROMChip.bitsProcessed")]
+    pub iocore_saves_eepromchip_bits_processed: i32,
+    #[js(key = "IOCore.saves.EEPROMChip.buffer")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_saves_eepromchip_buffer: Vec<u8>,
+    #[js(key = "IOCore.saves.EEPROMChip.largestSizePossible")]
+    pub iocore_saves_eepromchip_largest_size_possible: i32,
+    #[js(key = "IOCore.saves.EEPROMChip.mode")]
+    pub iocore_saves_eepromchip_mode: i32,
+    /// `None` until the game first uses the EEPROM.
+    #[js(key = "IOCore.saves.EEPROMChip.saves")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_saves_eepromchip_saves: Option<Vec<u8>>,
+    #[js(key = "IOCore.saves.FLASHChip.BANKOffset")]
+    pub iocore_saves_flashchip_bankoffset: i32,
+    #[js(key = "IOCore.saves.FLASHChip.flashCommand")]
+    pub iocore_saves_flashchip_flash_command: i32,
+    #[js(key = "IOCore.saves.FLASHChip.flashCommandUnlockStage")]
+    pub iocore_saves_flashchip_flash_command_unlock_stage: i32,
+    #[js(key = "IOCore.saves.FLASHChip.largestSizePossible")]
+    pub iocore_saves_flashchip_largest_size_possible: i32,
+    #[js(key = "IOCore.saves.FLASHChip.notATMEL")]
+    pub iocore_saves_flashchip_not_atmel: bool,
+    /// `None` until the game first uses the flash chip.
+    #[js(key = "IOCore.saves.FLASHChip.saves")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_saves_flashchip_saves: Option<Vec<u8>>,
+    #[js(key = "IOCore.saves.FLASHChip.writeBytesLeft")]
+    pub iocore_saves_flashchip_write_bytes_left: i32,
+    #[js(key = "IOCore.saves.GPIOChip.data")]
+    pub iocore_saves_gpiochip_data: i32,
+    #[js(key = "IOCore.saves.GPIOChip.direction")]
+    pub iocore_saves_gpiochip_direction: i32,
+    #[js(key = "IOCore.saves.GPIOChip.readWrite")]
+    pub iocore_saves_gpiochip_read_write: i32,
+    #[js(key = "IOCore.saves.GPIOChip.type")]
+    pub iocore_saves_gpiochip_type: i32,
+    #[js(key = "IOCore.saves.saveType")]
+    pub iocore_saves_save_type: i32,
+    /// `None` until the game first uses SRAM.
+    #[js(key = "IOCore.saves.SRAMChip.saves")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_saves_sramchip_saves: Option<Vec<u8>>,
+    #[js(key = "IOCore.saves.SRAMChip.TILTChipUnlocked")]
+    pub iocore_saves_sramchip_tiltchip_unlocked: i32,
+    #[js(key = "IOCore.saves.UNDETERMINED.possible")]
+    pub iocore_saves_undetermined_possible: i32,
+    #[js(key = "IOCore.serial.JOYBUS_CNTL_FLAGS")]
+    pub iocore_serial_joybus_cntl_flags: i32,
+    #[js(key = "IOCore.serial.JOYBUS_IRQ")]
+    pub iocore_serial_joybus_irq: i32,
+    #[js(key = "IOCore.serial.JOYBUS_RECV0")]
+    pub iocore_serial_joybus_recv0: i32,
+    #[js(key = "IOCore.serial.JOYBUS_RECV1")]
+    pub iocore_serial_joybus_recv1: i32,
+    #[js(key = "IOCore.serial.JOYBUS_RECV2")]
+    pub iocore_serial_joybus_recv2: i32,
+    #[js(key = "IOCore.serial.JOYBUS_RECV3")]
+    pub iocore_serial_joybus_recv3: i32,
+    #[js(key = "IOCore.serial.JOYBUS_SEND0")]
+    pub iocore_serial_joybus_send0: i32,
+    #[js(key = "IOCore.serial.JOYBUS_SEND1")]
+    pub iocore_serial_joybus_send1: i32,
+    #[js(key = "IOCore.serial.JOYBUS_SEND2")]
+    pub iocore_serial_joybus_send2: i32,
+    #[js(key = "IOCore.serial.JOYBUS_SEND3")]
+    pub iocore_serial_joybus_send3: i32,
+    #[js(key = "IOCore.serial.JOYBUS_STAT")]
+    pub iocore_serial_joybus_stat: i32,
+    #[js(key = "IOCore.serial.RCNTDataBitFlow")]
+    pub iocore_serial_rcntdata_bit_flow: i32,
+    #[js(key = "IOCore.serial.RCNTDataBits")]
+    pub iocore_serial_rcntdata_bits: i32,
+    #[js(key = "IOCore.serial.RCNTIRQ")]
+    pub iocore_serial_rcntirq: bool,
+    #[js(key = "IOCore.serial.RCNTMode")]
+    pub iocore_serial_rcntmode: i32,
+    #[js(key = "IOCore.serial.serialBitsShifted")]
+    pub iocore_serial_serial_bits_shifted: i32,
+    #[js(key = "IOCore.serial.shiftClocks")]
+    pub iocore_serial_shift_clocks: i32,
+    #[js(key = "IOCore.serial.SIOBaudRate")]
+    pub iocore_serial_siobaud_rate: i32,
+    #[js(key = "IOCore.serial.SIOCNT_IRQ")]
+    pub iocore_serial_siocnt_irq: i32,
+    #[js(key = "IOCore.serial.SIOCNT_MODE")]
+    pub iocore_serial_siocnt_mode: i32,
+    #[js(key = "IOCore.serial.SIOCNT_UART_CTS")]
+    pub iocore_serial_siocnt_uart_cts: bool,
+    #[js(key = "IOCore.serial.SIOCNT_UART_FIFO")]
+    pub iocore_serial_siocnt_uart_fifo: i32,
+    #[js(key = "IOCore.serial.SIOCNT_UART_FIFO_ENABLE")]
+    pub iocore_serial_siocnt_uart_fifo_enable: bool,
+    #[js(key = "IOCore.serial.SIOCNT_UART_MISC")]
+    pub iocore_serial_siocnt_uart_misc: i32,
+    #[js(key = "IOCore.serial.SIOCNT_UART_PARITY_ENABLE")]
+    pub iocore_serial_siocnt_uart_parity_enable: bool,
+    #[js(key = "IOCore.serial.SIOCNT_UART_RECV_ENABLE")]
+    pub iocore_serial_siocnt_uart_recv_enable: bool,
+    #[js(key = "IOCore.serial.SIOCNT_UART_SEND_ENABLE")]
+    pub iocore_serial_siocnt_uart_send_enable: bool,
+    #[js(key = "IOCore.serial.SIOCNT0_DATA")]
+    pub iocore_serial_siocnt0_data: i32,
+    #[js(key = "IOCore.serial.SIOCOMMERROR")]
+    pub iocore_serial_siocommerror: bool,
+    #[js(key = "IOCore.serial.SIODATA_A")]
+    pub iocore_serial_siodata_a: i32,
+    #[js(key = "IOCore.serial.SIODATA_B")]
+    pub iocore_serial_siodata_b: i32,
+    #[js(key = "IOCore.serial.SIODATA_C")]
+    pub iocore_serial_siodata_c: i32,
+    #[js(key = "IOCore.serial.SIODATA_D")]
+    pub iocore_serial_siodata_d: i32,
+    #[js(key = "IOCore.serial.SIODATA8")]
+    pub iocore_serial_siodata8: i32,
+    #[js(key = "IOCore.serial.SIOMULT_PLAYER_NUMBER")]
+    pub iocore_serial_siomult_player_number: i32,
+    #[js(key = "IOCore.serial.SIOShiftClockDivider")]
+    pub iocore_serial_sioshift_clock_divider: i32,
+    #[js(key = "IOCore.serial.SIOShiftClockExternal")]
+    pub iocore_serial_sioshift_clock_external: i32,
+    #[js(key = "IOCore.serial.SIOTransferStarted")]
+    pub iocore_serial_siotransfer_started: bool,
+    #[js(key = "IOCore.serialClocks")]
+    pub iocore_serial_clocks: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundA")]
+    pub iocore_sound_agbdirect_sound_a: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundAFolded")]
+    pub iocore_sound_agbdirect_sound_afolded: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundALeftCanPlay")]
+    pub iocore_sound_agbdirect_sound_aleft_can_play: bool,
+    #[js(key = "IOCore.sound.AGBDirectSoundARightCanPlay")]
+    pub iocore_sound_agbdirect_sound_aright_can_play: bool,
+    #[js(key = "IOCore.sound.AGBDirectSoundAShifter")]
+    pub iocore_sound_agbdirect_sound_ashifter: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundATimer")]
+    pub iocore_sound_agbdirect_sound_atimer: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundB")]
+    pub iocore_sound_agbdirect_sound_b: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundBFolded")]
+    pub iocore_sound_agbdirect_sound_bfolded: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundBLeftCanPlay")]
+    pub iocore_sound_agbdirect_sound_bleft_can_play: bool,
+    #[js(key = "IOCore.sound.AGBDirectSoundBRightCanPlay")]
+    pub iocore_sound_agbdirect_sound_bright_can_play: bool,
+    #[js(key = "IOCore.sound.AGBDirectSoundBShifter")]
+    pub iocore_sound_agbdirect_sound_bshifter: i32,
+    #[js(key = "IOCore.sound.AGBDirectSoundBTimer")]
+    pub iocore_sound_agbdirect_sound_btimer: i32,
+    #[js(key = "IOCore.sound.audioClocksUntilNextEvent")]
+    pub iocore_sound_audio_clocks_until_next_event: i32,
+    #[js(key = "IOCore.sound.audioClocksUntilNextEventCounter")]
+    pub iocore_sound_audio_clocks_until_next_event_counter: i32,
+    #[js(key = "IOCore.sound.audioIndex")]
+    pub iocore_sound_audio_index: i32,
+    #[js(key = "IOCore.sound.audioResamplerFirstPassFactor")]
+    pub iocore_sound_audio_resampler_first_pass_factor: i32,
+    #[js(key = "IOCore.sound.audioTicks")]
+    pub iocore_sound_audio_ticks: i32,
+    #[js(key = "IOCore.sound.CGBMixerOutputCacheLeft")]
+    pub iocore_sound_cgbmixer_output_cache_left: i32,
+    #[js(key = "IOCore.sound.CGBMixerOutputCacheLeftFolded")]
+    pub iocore_sound_cgbmixer_output_cache_left_folded: i32,
+    #[js(key = "IOCore.sound.CGBMixerOutputCacheRight")]
+    pub iocore_sound_cgbmixer_output_cache_right: i32,
+    #[js(key = "IOCore.sound.CGBMixerOutputCacheRightFolded")]
+    pub iocore_sound_cgbmixer_output_cache_right_folded: i32,
+    #[js(key = "IOCore.sound.CGBOutputRatio")]
+    pub iocore_sound_cgboutput_ratio: i32,
+    #[js(key = "IOCore.sound.channel1.CachedDuty")]
+    pub iocore_sound_channel1_cached_duty: f64,
+    #[js(key = "IOCore.sound.channel1.canPlay")]
+    pub iocore_sound_channel1_can_play: bool,
+    #[js(key = "IOCore.sound.channel1.consecutive")]
+    pub iocore_sound_channel1_consecutive: bool,
+    #[js(key = "IOCore.sound.channel1.currentSampleLeft")]
+    pub iocore_sound_channel1_current_sample_left: i32,
+    #[js(key = "IOCore.sound.channel1.currentSampleRight")]
+    pub iocore_sound_channel1_current_sample_right: i32,
+    #[js(key = "IOCore.sound.channel1.decreaseSweep")]
+    pub iocore_sound_channel1_decrease_sweep: bool,
+    #[js(key = "IOCore.sound.channel1.DutyTracker")]
+    pub iocore_sound_channel1_duty_tracker: i32,
+    #[js(key = "IOCore.sound.channel1.Enabled")]
+    pub iocore_sound_channel1_enabled: i32,
+    #[js(key = "IOCore.sound.channel1.envelopeSweeps")]
+    pub iocore_sound_channel1_envelope_sweeps: i32,
+    #[js(key = "IOCore.sound.channel1.envelopeSweepsLast")]
+    pub iocore_sound_channel1_envelope_sweeps_last: i32,
+    #[js(key = "IOCore.sound.channel1.envelopeVolume")]
+    pub iocore_sound_channel1_envelope_volume: i32,
+    #[js(key = "IOCore.sound.channel1.frequency")]
+    pub iocore_sound_channel1_frequency: i32,
+    #[js(key = "IOCore.sound.channel1.FrequencyCounter")]
+    pub iocore_sound_channel1_frequency_counter: i32,
+    #[js(key = "IOCore.sound.channel1.frequencySweepDivider")]
+    pub iocore_sound_channel1_frequency_sweep_divider: i32,
+    #[js(key = "IOCore.sound.channel1.FrequencyTracker")]
+    pub iocore_sound_channel1_frequency_tracker: i32,
+    #[js(key = "IOCore.sound.channel1.lastTimeSweep")]
+    pub iocore_sound_channel1_last_time_sweep: i32,
+    #[js(key = "IOCore.sound.channel1.leftEnable")]
+    pub iocore_sound_channel1_left_enable: i32,
+    #[js(key = "IOCore.sound.channel1.nr10")]
+    pub iocore_sound_channel1_nr10: i32,
+    #[js(key = "IOCore.sound.channel1.nr11")]
+    pub iocore_sound_channel1_nr11: i32,
+    #[js(key = "IOCore.sound.channel1.nr12")]
+    pub iocore_sound_channel1_nr12: i32,
+    #[js(key = "IOCore.sound.channel1.nr14")]
+    pub iocore_sound_channel1_nr14: i32,
+    #[js(key = "IOCore.sound.channel1.rightEnable")]
+    pub iocore_sound_channel1_right_enable: i32,
+    #[js(key = "IOCore.sound.channel1.ShadowFrequency")]
+    pub iocore_sound_channel1_shadow_frequency: i32,
+    #[js(key = "IOCore.sound.channel1.SweepFault")]
+    pub iocore_sound_channel1_sweep_fault: bool,
+    #[js(key = "IOCore.sound.channel1.Swept")]
+    pub iocore_sound_channel1_swept: bool,
+    #[js(key = "IOCore.sound.channel1.timeSweep")]
+    pub iocore_sound_channel1_time_sweep: i32,
+    #[js(key = "IOCore.sound.channel1.totalLength")]
+    pub iocore_sound_channel1_total_length: i32,
+    #[js(key = "IOCore.sound.channel2.CachedDuty")]
+    pub iocore_sound_channel2_cached_duty: f64,
+    #[js(key = "IOCore.sound.channel2.canPlay")]
+    pub iocore_sound_channel2_can_play: bool,
+    #[js(key = "IOCore.sound.channel2.consecutive")]
+    pub iocore_sound_channel2_consecutive: bool,
+    #[js(key = "IOCore.sound.channel2.currentSampleLeft")]
+    pub iocore_sound_channel2_current_sample_left: i32,
+    #[js(key = "IOCore.sound.channel2.currentSampleRight")]
+    pub iocore_sound_channel2_current_sample_right: i32,
+    #[js(key = "IOCore.sound.channel2.DutyTracker")]
+    pub iocore_sound_channel2_duty_tracker: i32,
+    #[js(key = "IOCore.sound.channel2.Enabled")]
+    pub iocore_sound_channel2_enabled: i32,
+    #[js(key = "IOCore.sound.channel2.envelopeSweeps")]
+    pub iocore_sound_channel2_envelope_sweeps: i32,
+    #[js(key = "IOCore.sound.channel2.envelopeSweepsLast")]
+    pub iocore_sound_channel2_envelope_sweeps_last: i32,
+    #[js(key = "IOCore.sound.channel2.envelopeVolume")]
+    pub iocore_sound_channel2_envelope_volume: i32,
+    #[js(key = "IOCore.sound.channel2.frequency")]
+    pub iocore_sound_channel2_frequency: i32,
+    #[js(key = "IOCore.sound.channel2.FrequencyCounter")]
+    pub iocore_sound_channel2_frequency_counter: i32,
+    #[js(key = "IOCore.sound.channel2.FrequencyTracker")]
+    pub iocore_sound_channel2_frequency_tracker: i32,
+    #[js(key = "IOCore.sound.channel2.leftEnable")]
+    pub iocore_sound_channel2_left_enable: i32,
+    #[js(key = "IOCore.sound.channel2.nr21")]
+    pub iocore_sound_channel2_nr21: i32,
+    #[js(key = "IOCore.sound.channel2.nr22")]
+    pub iocore_sound_channel2_nr22: i32,
+    #[js(key = "IOCore.sound.channel2.nr23")]
+    pub iocore_sound_channel2_nr23: i32,
+    #[js(key = "IOCore.sound.channel2.nr24")]
+    pub iocore_sound_channel2_nr24: i32,
+    #[js(key = "IOCore.sound.channel2.rightEnable")]
+    pub iocore_sound_channel2_right_enable: i32,
+    #[js(key = "IOCore.sound.channel2.ShadowFrequency")]
+    pub iocore_sound_channel2_shadow_frequency: i32,
+    #[js(key = "IOCore.sound.channel2.totalLength")]
+    pub iocore_sound_channel2_total_length: i32,
+    #[js(key = "IOCore.sound.channel3.cachedSample")]
+    pub iocore_sound_channel3_cached_sample: i32,
+    #[js(key = "IOCore.sound.channel3.canPlay")]
+    pub iocore_sound_channel3_can_play: bool,
+    #[js(key = "IOCore.sound.channel3.consecutive")]
+    pub iocore_sound_channel3_consecutive: bool,
+    #[js(key = "IOCore.sound.channel3.counter")]
+    pub iocore_sound_channel3_counter: i32,
+    #[js(key = "IOCore.sound.channel3.currentSampleLeft")]
+    pub iocore_sound_channel3_current_sample_left: i32,
+    #[js(key = "IOCore.sound.channel3.currentSampleRight")]
+    pub iocore_sound_channel3_current_sample_right: i32,
+    #[js(key = "IOCore.sound.channel3.Enabled")]
+    pub iocore_sound_channel3_enabled: i32,
+    #[js(key = "IOCore.sound.channel3.frequency")]
+    pub iocore_sound_channel3_frequency: i32,
+    #[js(key = "IOCore.sound.channel3.FrequencyPeriod")]
+    pub iocore_sound_channel3_frequency_period: i32,
+    #[js(key = "IOCore.sound.channel3.lastSampleLookup")]
+    pub iocore_sound_channel3_last_sample_lookup: i32,
+    #[js(key = "IOCore.sound.channel3.leftEnable")]
+    pub iocore_sound_channel3_left_enable: i32,
+    #[js(key = "IOCore.sound.channel3.nr30")]
+    pub iocore_sound_channel3_nr30: i32,
+    #[js(key = "IOCore.sound.channel3.nr31")]
+    pub iocore_sound_channel3_nr31: i32,
+    #[js(key = "IOCore.sound.channel3.nr32")]
+    pub iocore_sound_channel3_nr32: i32,
+    #[js(key = "IOCore.sound.channel3.nr33")]
+    pub iocore_sound_channel3_nr33: i32,
+    #[js(key = "IOCore.sound.channel3.nr34")]
+    pub iocore_sound_channel3_nr34: i32,
+    #[js(key = "IOCore.sound.channel3.patternType")]
+    pub iocore_sound_channel3_pattern_type: i32,
+    #[js(key = "IOCore.sound.channel3.PCM")]
+    #[js(view(key = "IOCore.sound.channel3.PCM16", with = vi8_to_vu16))]
+    #[js(view(key = "IOCore.sound.channel3.PCM32", with = vi8_to_vi32))]
+    pub iocore_sound_channel3_pcm: Vec<i8>,
+    #[js(key = "IOCore.sound.channel3.rightEnable")]
+    pub iocore_sound_channel3_right_enable: i32,
+    #[js(key = "IOCore.sound.channel3.totalLength")]
+    pub iocore_sound_channel3_total_length: i32,
+    #[js(key = "IOCore.sound.channel3.WaveRAMBankSize")]
+    pub iocore_sound_channel3_wave_rambank_size: i32,
+    #[js(key = "IOCore.sound.channel3.WAVERAM8")]
+    #[js(view(key = "IOCore.sound.channel3.WAVERAM16", with = vu8_to_vu16))]
+    #[js(view(key = "IOCore.sound.channel3.WAVERAM32", with = vu8_to_vi32))]
+    #[serde(with = "serde_bytes")]
+    pub iocore_sound_channel3_waveram8: Vec<u8>,
+    #[js(key = "IOCore.sound.channel3.WAVERAMBankAccessed")]
+    pub iocore_sound_channel3_waverambank_accessed: i32,
+    #[js(key = "IOCore.sound.channel3.WAVERAMBankSpecified")]
+    pub iocore_sound_channel3_waverambank_specified: i32,
+    #[js(key = "IOCore.sound.channel4.BitRange")]
+    pub iocore_sound_channel4_bit_range: i32,
+    #[js(key = "IOCore.sound.channel4.cachedSample")]
+    pub iocore_sound_channel4_cached_sample: i32,
+    #[js(key = "IOCore.sound.channel4.canPlay")]
+    pub iocore_sound_channel4_can_play: bool,
+    #[js(key = "IOCore.sound.channel4.consecutive")]
+    pub iocore_sound_channel4_consecutive: bool,
+    #[js(key = "IOCore.sound.channel4.counter")]
+    pub iocore_sound_channel4_counter: i32,
+    #[js(key = "IOCore.sound.channel4.currentSampleLeft")]
+    pub iocore_sound_channel4_current_sample_left: i32,
+    #[js(key = "IOCore.sound.channel4.currentSampleRight")]
+    pub iocore_sound_channel4_current_sample_right: i32,
+    #[js(key = "IOCore.sound.channel4.currentVolume")]
+    pub iocore_sound_channel4_current_volume: i32,
+    #[js(key = "IOCore.sound.channel4.Enabled")]
+    pub iocore_sound_channel4_enabled: i32,
+    #[js(key = "IOCore.sound.channel4.envelopeSweeps")]
+    pub iocore_sound_channel4_envelope_sweeps: i32,
+    #[js(key = "IOCore.sound.channel4.envelopeSweepsLast")]
+    pub iocore_sound_channel4_envelope_sweeps_last: i32,
+    #[js(key = "IOCore.sound.channel4.envelopeVolume")]
+    pub iocore_sound_channel4_envelope_volume: i32,
+    #[js(key = "IOCore.sound.channel4.FrequencyPeriod")]
+    pub iocore_sound_channel4_frequency_period: i32,
+    #[js(key = "IOCore.sound.channel4.lastSampleLookup")]
+    pub iocore_sound_channel4_last_sample_lookup: i32,
+    #[js(key = "IOCore.sound.channel4.leftEnable")]
+    pub iocore_sound_channel4_left_enable: i32,
+    // Constant noise tables the emulator builds itself. `fastLoad` re-points `noiseSampleTable` at one of them.
+    // iocore_sound_channel4_lsfr15_table: Vec<i8>,
+    // iocore_sound_channel4_lsfr7_table: Vec<i8>,
+    // iocore_sound_channel4_noise_sample_table: Vec<i8>,
+    #[js(key = "IOCore.sound.channel4.nr42")]
+    pub iocore_sound_channel4_nr42: i32,
+    #[js(key = "IOCore.sound.channel4.nr43")]
+    pub iocore_sound_channel4_nr43: i32,
+    #[js(key = "IOCore.sound.channel4.nr44")]
+    pub iocore_sound_channel4_nr44: i32,
+    #[js(key = "IOCore.sound.channel4.rightEnable")]
+    pub iocore_sound_channel4_right_enable: i32,
+    #[js(key = "IOCore.sound.channel4.totalLength")]
+    pub iocore_sound_channel4_total_length: i32,
+    #[js(key = "IOCore.sound.channel4.VolumeShifter")]
+    pub iocore_sound_channel4_volume_shifter: i32,
+    #[js(key = "IOCore.sound.downsampleInputLeft")]
+    pub iocore_sound_downsample_input_left: i32,
+    #[js(key = "IOCore.sound.downsampleInputRight")]
+    pub iocore_sound_downsample_input_right: i32,
+    #[js(key = "IOCore.sound.FIFOABuffer.buffer")]
+    pub iocore_sound_fifoabuffer_buffer: Vec<i8>,
+    #[js(key = "IOCore.sound.FIFOABuffer.count")]
+    pub iocore_sound_fifoabuffer_count: i32,
+    #[js(key = "IOCore.sound.FIFOABuffer.position")]
+    pub iocore_sound_fifoabuffer_position: i32,
+    #[js(key = "IOCore.sound.FIFOBBuffer.buffer")]
+    pub iocore_sound_fifobbuffer_buffer: Vec<i8>,
+    #[js(key = "IOCore.sound.FIFOBBuffer.count")]
+    pub iocore_sound_fifobbuffer_count: i32,
+    #[js(key = "IOCore.sound.FIFOBBuffer.position")]
+    pub iocore_sound_fifobbuffer_position: i32,
+    #[js(key = "IOCore.sound.mixerOutputCacheLeft")]
+    pub iocore_sound_mixer_output_cache_left: i32,
+    #[js(key = "IOCore.sound.mixerOutputCacheRight")]
+    pub iocore_sound_mixer_output_cache_right: i32,
+    #[js(key = "IOCore.sound.mixerSoundBIAS")]
+    pub iocore_sound_mixer_sound_bias: i32,
+    #[js(key = "IOCore.sound.nr50")]
+    pub iocore_sound_nr50: i32,
+    #[js(key = "IOCore.sound.nr51")]
+    pub iocore_sound_nr51: i32,
+    #[js(key = "IOCore.sound.nr52")]
+    pub iocore_sound_nr52: i32,
+    #[js(key = "IOCore.sound.nr60")]
+    pub iocore_sound_nr60: i32,
+    #[js(key = "IOCore.sound.nr61")]
+    pub iocore_sound_nr61: i32,
+    #[js(key = "IOCore.sound.nr62")]
+    pub iocore_sound_nr62: i32,
+    #[js(key = "IOCore.sound.nr63")]
+    pub iocore_sound_nr63: i32,
+    #[js(key = "IOCore.sound.PWMBitDepthMask")]
+    pub iocore_sound_pwmbit_depth_mask: i32,
+    #[js(key = "IOCore.sound.PWMBitDepthMaskShadow")]
+    pub iocore_sound_pwmbit_depth_mask_shadow: i32,
+    #[js(key = "IOCore.sound.PWMWidth")]
+    pub iocore_sound_pwmwidth: i32,
+    #[js(key = "IOCore.sound.PWMWidthOld")]
+    pub iocore_sound_pwmwidth_old: i32,
+    #[js(key = "IOCore.sound.PWMWidthShadow")]
+    pub iocore_sound_pwmwidth_shadow: i32,
+    #[js(key = "IOCore.sound.sequencePosition")]
+    pub iocore_sound_sequence_position: i32,
+    #[js(key = "IOCore.sound.sequencerClocks")]
+    pub iocore_sound_sequencer_clocks: i32,
+    #[js(key = "IOCore.sound.soundMasterEnabled")]
+    pub iocore_sound_sound_master_enabled: bool,
+    #[js(key = "IOCore.sound.VinLeftChannelMasterVolume")]
+    pub iocore_sound_vin_left_channel_master_volume: i32,
+    #[js(key = "IOCore.sound.VinRightChannelMasterVolume")]
+    pub iocore_sound_vin_right_channel_master_volume: i32,
+    #[js(key = "IOCore.systemStatus")]
+    pub iocore_system_status: i32,
+    #[js(key = "IOCore.THUMB.decode")]
+    pub iocore_thumb_decode: i32,
+    #[js(key = "IOCore.THUMB.execute")]
+    pub iocore_thumb_execute: i32,
+    #[js(key = "IOCore.THUMB.fetch")]
+    pub iocore_thumb_fetch: i32,
+    #[js(key = "IOCore.timer.timer0Control")]
+    pub iocore_timer_timer0_control: i32,
+    #[js(key = "IOCore.timer.timer0Counter")]
+    pub iocore_timer_timer0_counter: i32,
+    #[js(key = "IOCore.timer.timer0Enabled")]
+    pub iocore_timer_timer0_enabled: bool,
+    #[js(key = "IOCore.timer.timer0IRQ")]
+    pub iocore_timer_timer0_irq: bool,
+    #[js(key = "IOCore.timer.timer0Precounter")]
+    pub iocore_timer_timer0_precounter: i32,
+    #[js(key = "IOCore.timer.timer0Prescalar")]
+    pub iocore_timer_timer0_prescalar: i32,
+    #[js(key = "IOCore.timer.timer0PrescalarShifted")]
+    pub iocore_timer_timer0_prescalar_shifted: i32,
+    #[js(key = "IOCore.timer.timer0Reload")]
+    pub iocore_timer_timer0_reload: i32,
+    #[js(key = "IOCore.timer.timer1Control")]
+    pub iocore_timer_timer1_control: i32,
+    #[js(key = "IOCore.timer.timer1Counter")]
+    pub iocore_timer_timer1_counter: i32,
+    #[js(key = "IOCore.timer.timer1CountUp")]
+    pub iocore_timer_timer1_count_up: bool,
+    #[js(key = "IOCore.timer.timer1Enabled")]
+    pub iocore_timer_timer1_enabled: bool,
+    #[js(key = "IOCore.timer.timer1IRQ")]
+    pub iocore_timer_timer1_irq: bool,
+    #[js(key = "IOCore.timer.timer1Precounter")]
+    pub iocore_timer_timer1_precounter: i32,
+    #[js(key = "IOCore.timer.timer1Prescalar")]
+    pub iocore_timer_timer1_prescalar: i32,
+    #[js(key = "IOCore.timer.timer1PrescalarShifted")]
+    pub iocore_timer_timer1_prescalar_shifted: i32,
+    #[js(key = "IOCore.timer.timer1Reload")]
+    pub iocore_timer_timer1_reload: i32,
+    #[js(key = "IOCore.timer.timer1UseChainedClocks")]
+    pub iocore_timer_timer1_use_chained_clocks: bool,
+    #[js(key = "IOCore.timer.timer1UseMainClocks")]
+    pub iocore_timer_timer1_use_main_clocks: bool,
+    #[js(key = "IOCore.timer.timer2Control")]
+    pub iocore_timer_timer2_control: i32,
+    #[js(key = "IOCore.timer.timer2Counter")]
+    pub iocore_timer_timer2_counter: i32,
+    #[js(key = "IOCore.timer.timer2CountUp")]
+    pub iocore_timer_timer2_count_up: bool,
+    #[js(key = "IOCore.timer.timer2Enabled")]
+    pub iocore_timer_timer2_enabled: bool,
+    #[js(key = "IOCore.timer.timer2IRQ")]
+    pub iocore_timer_timer2_irq: bool,
+    #[js(key = "IOCore.timer.timer2Precounter")]
+    pub iocore_timer_timer2_precounter: i32,
+    #[js(key = "IOCore.timer.timer2Prescalar")]
+    pub iocore_timer_timer2_prescalar: i32,
+    #[js(key = "IOCore.timer.timer2PrescalarShifted")]
+    pub iocore_timer_timer2_prescalar_shifted: i32,
+    #[js(key = "IOCore.timer.timer2Reload")]
+    pub iocore_timer_timer2_reload: i32,
+    #[js(key = "IOCore.timer.timer2UseChainedClocks")]
+    pub iocore_timer_timer2_use_chained_clocks: bool,
+    #[js(key = "IOCore.timer.timer2UseMainClocks")]
+    pub iocore_timer_timer2_use_main_clocks: bool,
+    #[js(key = "IOCore.timer.timer3Control")]
+    pub iocore_timer_timer3_control: i32,
+    #[js(key = "IOCore.timer.timer3Counter")]
+    pub iocore_timer_timer3_counter: i32,
+    #[js(key = "IOCore.timer.timer3CountUp")]
+    pub iocore_timer_timer3_count_up: bool,
+    #[js(key = "IOCore.timer.timer3Enabled")]
+    pub iocore_timer_timer3_enabled: bool,
+    #[js(key = "IOCore.timer.timer3IRQ")]
+    pub iocore_timer_timer3_irq: bool,
+    #[js(key = "IOCore.timer.timer3Precounter")]
+    pub iocore_timer_timer3_precounter: i32,
+    #[js(key = "IOCore.timer.timer3Prescalar")]
+    pub iocore_timer_timer3_prescalar: i32,
+    #[js(key = "IOCore.timer.timer3PrescalarShifted")]
+    pub iocore_timer_timer3_prescalar_shifted: i32,
+    #[js(key = "IOCore.timer.timer3Reload")]
+    pub iocore_timer_timer3_reload: i32,
+    #[js(key = "IOCore.timer.timer3UseChainedClocks")]
+    pub iocore_timer_timer3_use_chained_clocks: bool,
+    #[js(key = "IOCore.timer.timer3UseMainClocks")]
+    pub iocore_timer_timer3_use_main_clocks: bool,
+    #[js(key = "IOCore.timerClocks")]
+    pub iocore_timer_clocks: i32,
+    #[js(key = "IOCore.wait.buffer")]
+    pub iocore_wait_buffer: i32,
+    #[js(key = "IOCore.wait.clocks")]
+    pub iocore_wait_clocks: i32,
+    #[js(key = "IOCore.wait.isOAMRendering")]
+    pub iocore_wait_is_oamrendering: i32,
+    #[js(key = "IOCore.wait.isRendering")]
+    pub iocore_wait_is_rendering: i32,
+    #[js(key = "IOCore.wait.nonSequential")]
+    pub iocore_wait_non_sequential: i32,
+    #[js(key = "IOCore.wait.POSTBOOT")]
+    pub iocore_wait_postboot: i32,
+    #[js(key = "IOCore.wait.SRAMWaitState")]
+    pub iocore_wait_sramwait_state: i32,
+    #[js(key = "IOCore.wait.WAITCNT0")]
+    pub iocore_wait_waitcnt0: i32,
+    #[js(key = "IOCore.wait.WAITCNT1")]
+    pub iocore_wait_waitcnt1: i32,
+    #[js(key = "IOCore.wait.waitStateClocks16")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_wait_wait_state_clocks16: Vec<u8>,
+    #[js(key = "IOCore.wait.waitStateClocks32")]
+    #[serde(with = "serde_bytes")]
+    pub iocore_wait_wait_state_clocks32: Vec<u8>,
+    #[js(key = "IOCore.wait.WRAMConfiguration")]
+    pub iocore_wait_wramconfiguration: i32,
+    #[js(key = "IOCore.wait.WRAMWaitState")]
+    pub iocore_wait_wramwait_state: i32,
+    #[js(key = "lastTimestamp")]
+    pub last_timestamp: i32,
+    #[js(key = "metricStart")]
+    pub metric_start: i32,
+}
@@ -0,0 +1,291 @@
+//! How the structs of a save state map to the keys of the emulator's JS objects, without depending on JS.
+//!
+//! `#[derive(JsObjectCodec)]` walks the fields of a struct through a `Decoder` or an `Encoder`, each field as a generic
+//! `Value`. The binding layer implements both for whatever holds the values, e.g. `state-wasm` for JS objects.
+
+use ::backtrace::Backtrace;
+
+use ::std::fmt;
+
+use crate::generic::Value;
+
+/// A struct mapped to the properties of a JS object, usually through `#[derive(JsObjectCodec)]`.
+pub trait Schema
+where
+    Self: Sized,
+{
+    fn decode<D: Decoder>(decoder: &mut D) -> Self;
+    fn encode<E: Encoder>(self, encoder: &mut E);
+
+    /// Like `decode`, but into `self`, so a decoder can fill the typed arrays it already has, see `Decoder::field_into`.
+    fn decode_into<D: Decoder>(&mut self, decoder: &mut D) {
+        *self = Self::decode(decoder);
+    }
+}
+
+pub trait Decoder {
+    /// The value of `key`. A value that is missing or fails to convert is the decoder's to report.
+    fn field<T: FieldValue>(&mut self, key: &str) -> T;
+
+    /// Like `field`, but into `value`. Decoders that can copy into the buffer of `FieldValue::typed_array_mut`
+    /// override it to skip allocating.
+    fn field_into<T: FieldValue>(&mut self, key: &str, value: &mut T) {
+        *value = self.field(key);
+    }
+
+    /// The array of objects at `key`.
+    fn array<T: Schema>(&mut self, key: &str) -> Vec<T>;
+}
+
+pub trait Encoder {
+    fn field<T: FieldValue>(&mut self, key: &str, value: T);
+
+    fn array<T: Schema>(&mut self, key: &str, values: Vec<T>);
+}
+
+/// A type a single property converts to and from.
+pub trait FieldValue
+where
+    Self: Sized + Default,
+{
+    fn into_value(self) -> Value;
+    fn from_value(value: Value) -> Result<Self, ValueError>;
+
+    /// The buffer of a typed array to decode into in place, `None` for other types.
+    fn typed_array_mut(&mut self) -> Option<TypedArrayMut<'_>> {
+        None
+    }
+}
+
+/// A typed-array field, by its JS type.
+pub enum TypedArrayMut<'a> {
+    Int8Array(&'a mut Vec<i8>),
+    Int16Array(&'a mut Vec<i16>),
+    Int32Array(&'a mut Vec<i32>),
+    Uint8Array(&'a mut Vec<u8>),
+    Uint16Array(&'a mut Vec<u16>),
+    Uint32Array(&'a mut Vec<u32>),
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] // `stack` is only read through `Debug`.
+pub struct ValueError {
+    kind: ValueErrorKind,
+    stack: Backtrace,
+}
+
+impl ValueError {
+    fn new(kind: ValueErrorKind) -> Self {
+        ValueError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+
+    fn unexpected_type(expected: &'static str, value: &Value) -> Self {
+        ValueError::new(ValueErrorKind::UnexpectedType {
+            expected,
+            actual: value.type_name(),
+        })
+    }
+}
+
+impl From<ValueErrorKind> for ValueError {
+    fn from(kind: ValueErrorKind) -> Self {
+        ValueError::new(kind)
+    }
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ValueErrorKind::UnexpectedType { expected, actual } => {
+                write!(f, "expected {} but got {}", expected, actual)
+            }
+            ValueErrorKind::NotI32(value) => write!(f, "expected an i32 but got {}", value),
+        }
+    }
+}
+
+impl ::std::error::Error for ValueError {}
+
+#[derive(Debug)]
+enum ValueErrorKind {
+    UnexpectedType {
+        expected: &'static str,
+        actual: &'static str,
+    },
+    NotI32(f64),
+}
+
+impl FieldValue for i32 {
+    fn into_value(self) -> Value {
+        Value::Number(self as f64)
+    }
+
+    fn from_value(value: Value) -> Result<Self, ValueError> {
+        match value {
+            Value::Number(f) if f > (i32::MAX as f64) => Err(ValueErrorKind::NotI32(f))?,
+            Value::Number(f) => Ok(f as i32),
+            value => Err(ValueError::unexpected_type("number", &value)),
+        }
+    }
+}
+
+impl FieldValue for f64 {
+    fn into_value(self) -> Value {
+        Value::Number(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, ValueError> {
+        match value {
+            Value::Number(f) => Ok(f),
+            value => Err(ValueError::unexpected_type("number", &value)),
+        }
+    }
+}
+
+impl FieldValue for bool {
+    fn into_value(self) -> Value {
+        Value::Bool(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, ValueError> {
+        match value {
+            Value::Bool(b) => Ok(b),
+            value => Err(ValueError::unexpected_type("boolean", &value)),
+        }
+    }
+}
+
+impl FieldValue for String {
+    fn into_value(self) -> Value {
+        Value::String(self)
+    }
+
+    fn from_value(value: Value) -> Result<Self, ValueError> {
+        match value {
+            Value::String(s) => Ok(s),
+            value => Err(ValueError::unexpected_type("string", &value)),
+        }
+    }
+}
+
+/// Typed arrays of the right kind and plain arrays, whose numbers are converted like a typed array would.
+macro_rules! typed_array_values {
+    ($($element:ty => $array:ident),*) => {
+        $(
+            impl FieldValue for Vec<$element> {
+                fn into_value(self) -> Value {
+                    Value::$array(self)
+                }
+
+                fn from_value(value: Value) -> Result<Self, ValueError> {
+                    match value {
+                        Value::$array(values) => Ok(values),
+                        Value::Array(values) => values
+                            .into_iter()
+                            .map(|value| match value {
+                                Value::Number(f) => Ok(f as i64 as $element),
+                                value => Err(ValueError::unexpected_type("number", &value)),
+                            })
+                            .collect(),
+                        value => Err(ValueError::unexpected_type(stringify!($array), &value)),
+                    }
+                }
+
+                fn typed_array_mut(&mut self) -> Option<TypedArrayMut<'_>> {
+                    Some(TypedArrayMut::$array(self))
+                }
+            }
+        )*
+    };
+}
+
+typed_array_values!(
+    i8 => Int8Array,
+    i16 => Int16Array,
+    i32 => Int32Array,
+    u8 => Uint8Array,
+    u16 => Uint16Array,
+    u32 => Uint32Array
+);
+
+/// `null` and `undefined` decode to `None`. `None` encodes to `undefined`, which `fastLoad` skips.
+impl<T: FieldValue> FieldValue for Option<T> {
+    fn into_value(self) -> Value {
+        match self {
+            Some(value) => value.into_value(),
+            None => Value::Undefined,
+        }
+    }
+
+    fn from_value(value: Value) -> Result<Self, ValueError> {
+        match value {
+            Value::Null | Value::Undefined => Ok(None),
+            value => Ok(Some(T::from_value(value)?)),
+        }
+    }
+
+    /// Only a `Some` has a buffer, decoding a `None` allocates.
+    fn typed_array_mut(&mut self) -> Option<TypedArrayMut<'_>> {
+        self.as_mut()?.typed_array_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generic::Value;
+    use crate::schema::{FieldValue, TypedArrayMut};
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(i32::from_value(Value::Number(-3.0)).unwrap(), -3);
+        assert!(i32::from_value(Value::Number(2147483648.0)).is_err());
+        assert!(i32::from_value(Value::String("3".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_typed_arrays_accept_plain_arrays() {
+        let plain = Value::Array(vec![
+            Value::Number(1.0),
+            Value::Number(257.0),
+            Value::Number(-1.0),
+        ]);
+
+        assert_eq!(Vec::<u8>::from_value(plain).unwrap(), vec![1, 1, 255]);
+        assert_eq!(
+            Vec::<u16>::from_value(Value::Uint16Array(vec![7])).unwrap(),
+            vec![7]
+        );
+        assert_eq!(
+            Vec::<u8>::from_value(Value::Int8Array(vec![1]))
+                .unwrap_err()
+                .to_string(),
+            "expected Uint8Array but got Int8Array"
+        );
+    }
+
+    #[test]
+    fn test_options() {
+        assert_eq!(Option::<Vec<u8>>::from_value(Value::Null).unwrap(), None);
+        assert_eq!(
+            Option::<Vec<u8>>::from_value(Value::Uint8Array(vec![1])).unwrap(),
+            Some(vec![1])
+        );
+        assert_eq!(None::<Vec<u8>>.into_value(), Value::Undefined);
+    }
+
+    #[test]
+    fn test_typed_array_buffers() {
+        let mut ram = Some(vec![1u8]);
+        match ram.typed_array_mut() {
+            Some(TypedArrayMut::Uint8Array(values)) => values.push(2),
+            _ => panic!("A `Some` typed array has a buffer"),
+        }
+        assert_eq!(ram, Some(vec![1, 2]));
+
+        assert!(None::<Vec<u8>>.typed_array_mut().is_none());
+        assert!(3i32.typed_array_mut().is_none());
+    }
+}
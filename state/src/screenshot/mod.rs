@@ -0,0 +1,263 @@
+//! The picture a `SaveState` was showing, as RGBA and PNG.
+//!
+//! `swizzledFrame` is what the emulator last presented, `frameBuffer` also holds the lines drawn since. The former is
+//! used when it is there, compact states leave it out and it is converted from the latter like on load.
+
+use ::backtrace::Backtrace;
+
+use ::std::fmt;
+
+use crate::format::{self, FormatError};
+use crate::SaveState;
+
+pub const WIDTH: u32 = 240;
+pub const HEIGHT: u32 = 160;
+
+const PIXELS: usize = (WIDTH * HEIGHT) as usize;
+
+#[derive(Debug)]
+#[allow(dead_code)] // Only read through `Debug`.
+pub struct ScreenshotError {
+    kind: ScreenshotErrorKind,
+    stack: Backtrace,
+}
+
+impl ScreenshotError {
+    fn new(kind: ScreenshotErrorKind) -> Self {
+        ScreenshotError {
+            kind,
+            stack: Backtrace::new(),
+        }
+    }
+}
+
+impl From<ScreenshotErrorKind> for ScreenshotError {
+    fn from(kind: ScreenshotErrorKind) -> Self {
+        ScreenshotError::new(kind)
+    }
+}
+
+impl From<FormatError> for ScreenshotError {
+    fn from(error: FormatError) -> Self {
+        ScreenshotError::new(ScreenshotErrorKind::Format(error))
+    }
+}
+
+impl From<::png::EncodingError> for ScreenshotError {
+    fn from(error: ::png::EncodingError) -> Self {
+        ScreenshotError::new(ScreenshotErrorKind::Png(error))
+    }
+}
+
+impl fmt::Display for ScreenshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.kind)
+    }
+}
+
+impl ::std::error::Error for ScreenshotError {}
+
+#[derive(Debug)]
+#[allow(dead_code)] // Only read through `Debug`.
+pub(crate) enum ScreenshotErrorKind {
+    Format(FormatError),
+    Png(::png::EncodingError),
+    /// Neither `swizzledFrame` nor `frameBuffer` has the size of a frame.
+    NoFrame,
+    /// A thumbnail can only be scaled down by a factor that divides both the width and the height.
+    BadScale(u32),
+}
+
+/// An RGBA picture, 4 bytes per pixel in rows from the top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Screenshot {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl Screenshot {
+    /// The screen of a state written by `Snapshotter`.
+    pub fn from_state(data: &[u8]) -> Result<Screenshot, ScreenshotError> {
+        Screenshot::from_save_state(&format::read(data)?)
+    }
+
+    pub fn from_save_state(save_state: &SaveState) -> Result<Screenshot, ScreenshotError> {
+        let swizzled = &save_state.iocore_gfx_renderer_renderer_swizzled_frame;
+        let frame_buffer = &save_state.iocore_gfx_renderer_renderer_frame_buffer;
+
+        let rgba = if swizzled.len() == PIXELS * 3 {
+            swizzled
+                .chunks_exact(3)
+                .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 0xFF])
+                .collect()
+        } else if frame_buffer.len() == PIXELS {
+            frame_buffer
+                .iter()
+                .flat_map(|&bgr| bgr_to_rgba(bgr as u16))
+                .collect()
+        } else {
+            Err(ScreenshotErrorKind::NoFrame)?
+        };
+
+        Ok(Screenshot {
+            width: WIDTH,
+            height: HEIGHT,
+            rgba,
+        })
+    }
+
+    /// `rgba` has to hold `width`×`height` pixels.
+    pub(crate) fn new(width: u32, height: u32, rgba: Vec<u8>) -> Screenshot {
+        debug_assert_eq!(rgba.len(), width as usize * height as usize * 4);
+
+        Screenshot {
+            width,
+            height,
+            rgba,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn rgba(&self) -> &[u8] {
+        &self.rgba
+    }
+
+    pub fn into_rgba(self) -> Vec<u8> {
+        self.rgba
+    }
+
+    /// Scales down by `scale` in both directions, each pixel the average of the `scale`×`scale` pixels it covers.
+    pub fn thumbnail(&self, scale: u32) -> Result<Screenshot, ScreenshotError> {
+        if scale == 0 || !self.width.is_multiple_of(scale) || !self.height.is_multiple_of(scale) {
+            Err(ScreenshotErrorKind::BadScale(scale))?
+        }
+
+        let (width, height) = (self.width / scale, self.height / scale);
+        let (scale, stride) = (scale as usize, self.width as usize * 4);
+        let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+
+        for y in 0..height as usize {
+            for x in 0..width as usize {
+                let mut sum = [0u32; 4];
+
+                for row in y * scale..(y + 1) * scale {
+                    let start = row * stride + x * scale * 4;
+
+                    for pixel in self.rgba[start..start + scale * 4].chunks_exact(4) {
+                        for (sum, &channel) in sum.iter_mut().zip(pixel) {
+                            *sum += channel as u32;
+                        }
+                    }
+                }
+
+                let count = (scale * scale) as u32;
+                rgba.extend(sum.iter().map(|sum| ((sum + count / 2) / count) as u8));
+            }
+        }
+
+        Ok(Screenshot {
+            width,
+            height,
+            rgba,
+        })
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, ScreenshotError> {
+        let mut png = Vec::new();
+        let mut encoder = ::png::Encoder::new(&mut png, self.width, self.height);
+        encoder.set_color(::png::ColorType::Rgba);
+        encoder.set_depth(::png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.rgba)?;
+        writer.finish()?;
+
+        Ok(png)
+    }
+}
+
+/// A 15-bit color of the GBA as an opaque RGBA pixel.
+///
+/// The same conversion as `swizzleFrameBuffer` in `IodineGBA/core/graphics/Renderer.js`.
+pub(crate) fn bgr_to_rgba(bgr: u16) -> [u8; 4] {
+    [
+        ((bgr & 0x1F) << 3) as u8,
+        ((bgr & 0x3E0) >> 2) as u8,
+        ((bgr & 0x7C00) >> 7) as u8,
+        0xFF,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::screenshot::{Screenshot, HEIGHT, WIDTH};
+    use crate::SaveState;
+
+    fn save_state() -> SaveState {
+        let mut frame_buffer = vec![0x7C1F; 240 * 160];
+        frame_buffer[1] = 0x03E0;
+
+        SaveState {
+            iocore_gfx_renderer_renderer_frame_buffer: frame_buffer,
+            ..SaveState::default()
+        }
+    }
+
+    #[test]
+    fn test_rgba_from_frame_buffer_and_swizzled_frame() {
+        let from_frame_buffer = Screenshot::from_save_state(&save_state()).unwrap();
+
+        assert_eq!(from_frame_buffer.rgba().len(), 240 * 160 * 4);
+        assert_eq!(
+            from_frame_buffer.rgba()[0..8],
+            [0xF8, 0, 0xF8, 0xFF, 0, 0xF8, 0, 0xFF]
+        );
+
+        let swizzled = SaveState {
+            iocore_gfx_renderer_renderer_swizzled_frame: vec![7; 240 * 160 * 3],
+            ..save_state()
+        };
+        let from_swizzled = Screenshot::from_save_state(&swizzled).unwrap();
+
+        assert_eq!(from_swizzled.rgba()[0..4], [7, 7, 7, 0xFF]);
+        assert!(Screenshot::from_save_state(&SaveState::default()).is_err());
+    }
+
+    #[test]
+    fn test_thumbnail_averages_pixels() {
+        let screenshot = Screenshot::from_save_state(&save_state()).unwrap();
+        let thumbnail = screenshot.thumbnail(2).unwrap();
+
+        assert_eq!(
+            (thumbnail.width(), thumbnail.height()),
+            (WIDTH / 2, HEIGHT / 2)
+        );
+        assert_eq!(
+            thumbnail.rgba()[0..8],
+            [0xBA, 0x3E, 0xBA, 0xFF, 0xF8, 0, 0xF8, 0xFF]
+        );
+        assert!(screenshot.thumbnail(3).is_err());
+        assert!(screenshot.thumbnail(0).is_err());
+    }
+
+    #[test]
+    fn test_png_round_trip() {
+        let screenshot = Screenshot::from_save_state(&save_state()).unwrap();
+        let png = screenshot.to_png().unwrap();
+
+        let mut reader = ::png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut rgba = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut rgba).unwrap();
+
+        assert_eq!((info.width, info.height), (WIDTH, HEIGHT));
+        assert_eq!(rgba, screenshot.rgba());
+    }
+}
@@ -0,0 +1,291 @@
+//! Finding the addresses of values such as lives or money by narrowing down across successive states.
+//!
+//! A `Search` starts with every aligned value of IWRAM and EWRAM as a candidate and keeps the ones that pass each
+//! filter, comparing a state with the one the previous step saw. Addresses are the GBA's bus addresses, the form
+//! cheat codes use.
+
+use crate::SaveState;
+
+/// Where EWRAM starts on the bus.
+pub const EWRAM: u32 = 0x0200_0000;
+/// Where IWRAM starts on the bus.
+pub const IWRAM: u32 = 0x0300_0000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+}
+
+impl ValueType {
+    /// `"u8"`, `"i8"`, `"u16"`, `"i16"`, `"u32"` or `"i32"`.
+    pub fn from_name(name: &str) -> Option<ValueType> {
+        match name.to_ascii_lowercase().as_str() {
+            "u8" => Some(ValueType::U8),
+            "i8" => Some(ValueType::I8),
+            "u16" => Some(ValueType::U16),
+            "i16" => Some(ValueType::I16),
+            "u32" => Some(ValueType::U32),
+            "i32" => Some(ValueType::I32),
+            _ => None,
+        }
+    }
+
+    /// Bytes of a value, which is also its alignment.
+    pub fn width(self) -> usize {
+        match self {
+            ValueType::U8 | ValueType::I8 => 1,
+            ValueType::U16 | ValueType::I16 => 2,
+            ValueType::U32 | ValueType::I32 => 4,
+        }
+    }
+
+    /// Truncates `value` to the width of the type and extends it back by its signedness, so `255` and `-1` are the
+    /// same `u8`.
+    pub fn normalize(self, value: i64) -> i64 {
+        match self {
+            ValueType::U8 => value as u8 as i64,
+            ValueType::I8 => value as i8 as i64,
+            ValueType::U16 => value as u16 as i64,
+            ValueType::I16 => value as i16 as i64,
+            ValueType::U32 => value as u32 as i64,
+            ValueType::I32 => value as i32 as i64,
+        }
+    }
+
+    /// Reads a little endian value at the start of `bytes`.
+    fn read(self, bytes: &[u8]) -> Option<i64> {
+        let bytes = bytes.get(..self.width())?;
+        let value = bytes
+            .iter()
+            .rev()
+            .fold(0i64, |value, byte| (value << 8) | *byte as i64);
+
+        Some(self.normalize(value))
+    }
+}
+
+/// Which candidates a step keeps, comparing each value with its value in the previous state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    Equal,
+    Changed,
+    Increased,
+    Decreased,
+    /// Has this value now, see `ValueType::normalize`.
+    Value(i64),
+}
+
+impl Filter {
+    /// `"equal"`, `"changed"`, `"increased"` or `"decreased"`, values have no name.
+    pub fn from_name(name: &str) -> Option<Filter> {
+        match name.to_ascii_lowercase().as_str() {
+            "equal" => Some(Filter::Equal),
+            "changed" => Some(Filter::Changed),
+            "increased" => Some(Filter::Increased),
+            "decreased" => Some(Filter::Decreased),
+            _ => None,
+        }
+    }
+
+    fn keeps(self, previous: i64, current: i64) -> bool {
+        match self {
+            Filter::Equal => current == previous,
+            Filter::Changed => current != previous,
+            Filter::Increased => current > previous,
+            Filter::Decreased => current < previous,
+            Filter::Value(value) => current == value,
+        }
+    }
+}
+
+/// A value that passed every step so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Candidate {
+    pub address: u32,
+    /// In the last state the search saw.
+    pub value: i64,
+}
+
+/// The work RAMs of a state.
+#[derive(Debug, Default)]
+struct Memory {
+    ewram: Vec<u8>,
+    iwram: Vec<u8>,
+}
+
+impl Memory {
+    fn of(save_state: &SaveState) -> Memory {
+        Memory {
+            ewram: save_state.iocore_memory_external_ram.clone(),
+            iwram: save_state.iocore_memory_internal_ram.clone(),
+        }
+    }
+
+    fn read(&self, address: u32, value_type: ValueType) -> Option<i64> {
+        let (memory, start) = match address & 0xFF00_0000 {
+            EWRAM => (&self.ewram, EWRAM),
+            IWRAM => (&self.iwram, IWRAM),
+            _ => return None,
+        };
+
+        value_type.read(memory.get((address - start) as usize..)?)
+    }
+
+    fn addresses(&self, value_type: ValueType) -> impl Iterator<Item = u32> + '_ {
+        let width = value_type.width();
+
+        [(EWRAM, &self.ewram), (IWRAM, &self.iwram)]
+            .into_iter()
+            .flat_map(move |(start, memory)| {
+                (0..memory.len() / width).map(move |index| start + (index * width) as u32)
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct Search {
+    value_type: ValueType,
+    /// Bus addresses, ascending.
+    addresses: Vec<u32>,
+    previous: Memory,
+}
+
+impl Search {
+    /// A search with every aligned value of `save_state` as a candidate.
+    pub fn new(save_state: &SaveState, value_type: ValueType) -> Search {
+        let previous = Memory::of(save_state);
+        let addresses = previous.addresses(value_type).collect();
+
+        Search {
+            value_type,
+            addresses,
+            previous,
+        }
+    }
+
+    pub fn value_type(&self) -> ValueType {
+        self.value_type
+    }
+
+    /// Keeps the candidates that pass `filter` from the previous state to `save_state`.
+    ///
+    /// Values that are no longer in `save_state`, e.g. because a memory shrunk, are dropped.
+    pub fn narrow(&mut self, save_state: &SaveState, filter: Filter) {
+        let current = Memory::of(save_state);
+        let value_type = self.value_type;
+        let filter = match filter {
+            Filter::Value(value) => Filter::Value(value_type.normalize(value)),
+            filter => filter,
+        };
+
+        let previous = &self.previous;
+        self.addresses.retain(|address| {
+            match (
+                previous.read(*address, value_type),
+                current.read(*address, value_type),
+            ) {
+                (Some(previous), Some(current)) => filter.keeps(previous, current),
+                _ => false,
+            }
+        });
+
+        self.previous = current;
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// The first `limit` candidates, by address.
+    pub fn candidates(&self, limit: usize) -> Vec<Candidate> {
+        self.addresses
+            .iter()
+            .take(limit)
+            .filter_map(|address| {
+                let value = self.previous.read(*address, self.value_type)?;
+
+                Some(Candidate {
+                    address: *address,
+                    value,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{Candidate, Filter, Search, ValueType};
+    use crate::SaveState;
+
+    fn save_state(iwram: &[(usize, u8)]) -> SaveState {
+        let mut s = SaveState {
+            iocore_memory_external_ram: vec![0; 0x40000],
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            ..SaveState::default()
+        };
+
+        for (offset, value) in iwram {
+            s.iocore_memory_internal_ram[*offset] = *value;
+        }
+
+        s
+    }
+
+    #[test]
+    fn test_value_types() {
+        assert_eq!(ValueType::I8.read(&[0xFF]), Some(-1));
+        assert_eq!(ValueType::U8.read(&[0xFF]), Some(255));
+        assert_eq!(ValueType::U16.read(&[0x34, 0x12]), Some(0x1234));
+        assert_eq!(ValueType::I32.read(&[0xFE, 0xFF, 0xFF, 0xFF]), Some(-2));
+        assert_eq!(ValueType::U32.read(&[0xFE, 0xFF, 0xFF]), None);
+        assert_eq!(ValueType::U8.normalize(-1), 255);
+    }
+
+    #[test]
+    fn test_narrowing_finds_the_value() {
+        /* Lives at 0x03000100 go 3 -> 2 -> 5, a timer at 0x03000200 counts up. */
+        let mut search = Search::new(&save_state(&[(0x100, 3), (0x200, 1)]), ValueType::U16);
+        assert_eq!(search.len(), (0x40000 + 0x8000) / 2);
+
+        search.narrow(&save_state(&[(0x100, 2), (0x200, 2)]), Filter::Changed);
+        assert_eq!(search.len(), 2);
+
+        search.narrow(&save_state(&[(0x100, 5), (0x200, 3)]), Filter::Increased);
+        assert_eq!(search.len(), 2);
+
+        search.narrow(&save_state(&[(0x100, 5), (0x200, 4)]), Filter::Value(5));
+        assert_eq!(
+            search.candidates(10),
+            [Candidate {
+                address: 0x0300_0100,
+                value: 5
+            }]
+        );
+
+        search.narrow(&save_state(&[(0x100, 5), (0x200, 5)]), Filter::Equal);
+        assert_eq!(search.len(), 1);
+    }
+
+    #[test]
+    fn test_signedness_decides_increased() {
+        let mut signed = Search::new(&save_state(&[(0x10, 0x01)]), ValueType::I8);
+        let mut unsigned = Search::new(&save_state(&[(0x10, 0x01)]), ValueType::U8);
+
+        signed.narrow(&save_state(&[(0x10, 0xFF)]), Filter::Decreased);
+        unsigned.narrow(&save_state(&[(0x10, 0xFF)]), Filter::Increased);
+
+        assert_eq!(signed.candidates(1)[0].address, 0x0300_0010);
+        assert_eq!(signed.candidates(1)[0].value, -1);
+        assert_eq!(unsigned.candidates(1)[0].value, 255);
+    }
+}
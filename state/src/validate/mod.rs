@@ -0,0 +1,383 @@
+//! Checks that a `SaveState` can be handed to the emulator's `fastLoad`.
+//!
+//! `fastLoad` copies every array into place without looking at it, so a wrong size or an out of range value only shows
+//! up as a crash somewhere in the JS core later. The sizes and ranges here are the ones the IodineGBA core allocates
+//! and masks to.
+
+use ::std::{fmt, ops::RangeInclusive};
+
+use crate::inspect::Mode;
+use crate::{format, generic, SaveState};
+
+/// `prescalarLookup` in `IodineGBA/core/Timer.js`.
+const PRESCALAR_SHIFTS: [i32; 4] = [0, 6, 8, 10];
+
+/// Every problem found in a state, empty if it is fine to load.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    issues: Vec<Issue>,
+}
+
+#[derive(Debug)]
+struct Issue {
+    field: String,
+    problem: Problem,
+}
+
+#[derive(Debug)]
+enum Problem {
+    /// The state could not be read at all.
+    Unreadable(String),
+    Length {
+        expected: &'static [usize],
+        actual: usize,
+    },
+    OutOfRange {
+        value: i32,
+        allowed: RangeInclusive<i32>,
+    },
+    Invariant(&'static str),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::Unreadable(error) => write!(f, "unreadable, {}", error),
+            Problem::Length { expected, actual } => {
+                write!(f, "has {} entries, expected {:?}", actual, expected)
+            }
+            Problem::OutOfRange { value, allowed } => {
+                write!(f, "is {}, outside of {:?}", value, allowed)
+            }
+            Problem::Invariant(description) => write!(f, "{}", description),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` {}", self.field, self.problem)
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.issues.is_empty() {
+            return write!(f, "valid");
+        }
+
+        let issues: Vec<String> = self.issues.iter().map(Issue::to_string).collect();
+        write!(f, "{} issues: {}", issues.len(), issues.join(", "))
+    }
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// One line per problem, e.g. "`iocore_arm_registers` has 15 entries, expected [16]".
+    pub fn issues(&self) -> Vec<String> {
+        self.issues.iter().map(Issue::to_string).collect()
+    }
+
+    /// Validates a state written by `Snapshotter`.
+    ///
+    /// Generic states have no schema to check them against and are always valid.
+    pub fn of_state(data: &[u8]) -> ValidationReport {
+        if generic::is_generic(data) {
+            return ValidationReport::default();
+        }
+
+        match format::read(data) {
+            Ok(save_state) => validate(&save_state),
+            Err(error) => ValidationReport {
+                issues: vec![Issue {
+                    field: "state".to_string(),
+                    problem: Problem::Unreadable(error.to_string()),
+                }],
+            },
+        }
+    }
+
+    fn push(&mut self, field: impl Into<String>, problem: Problem) {
+        self.issues.push(Issue {
+            field: field.into(),
+            problem,
+        });
+    }
+
+    fn length<T>(&mut self, field: &str, values: &[T], expected: &'static [usize]) {
+        if !expected.contains(&values.len()) {
+            self.push(
+                field,
+                Problem::Length {
+                    expected,
+                    actual: values.len(),
+                },
+            );
+        }
+    }
+
+    fn range(&mut self, field: impl Into<String>, value: i32, allowed: RangeInclusive<i32>) {
+        if !allowed.contains(&value) {
+            self.push(field, Problem::OutOfRange { value, allowed });
+        }
+    }
+
+    fn invariant(&mut self, field: &str, holds: bool, description: &'static str) {
+        if !holds {
+            self.push(field, Problem::Invariant(description));
+        }
+    }
+}
+
+macro_rules! check_lengths {
+    ($report:expr, $save_state:expr, $($field:ident: [$($length:expr),+]),* $(,)?) => {
+        $(
+            $report.length(stringify!($field), &$save_state.$field, &[$($length),+]);
+        )*
+    };
+}
+
+macro_rules! check_ranges {
+    ($report:expr, $save_state:expr, $($field:ident: $allowed:expr),* $(,)?) => {
+        $(
+            $report.range(stringify!($field), $save_state.$field, $allowed);
+        )*
+    };
+}
+
+pub fn validate(s: &SaveState) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    check_lengths!(report, s,
+        iocore_arm_registers: [16],
+        iocore_arm_registers_usr: [7],
+        iocore_cpu_registers_fiq: [7],
+        iocore_cpu_registers_irq: [2],
+        iocore_cpu_registers_svc: [2],
+        iocore_cpu_registers_abt: [2],
+        iocore_cpu_registers_und: [2],
+        iocore_cpu_spsr: [5],
+        iocore_memory_internal_ram: [0x8000],
+        iocore_memory_external_ram: [0x40000],
+        iocore_gfx_renderer_iodata8: [20],
+        iocore_gfx_renderer_iodata16: [10],
+        iocore_gfx_renderer_iodata32: [5],
+        iocore_gfx_renderer_renderer_vram: [0x18000],
+        iocore_gfx_renderer_renderer_palette_ram: [0x400],
+        iocore_gfx_renderer_renderer_obj_renderer_oamram: [0x400],
+        iocore_gfx_renderer_renderer_obj_renderer_oamtable: [128],
+        iocore_gfx_renderer_renderer_obj_renderer_objmatrix_parameters: [0x80],
+        iocore_gfx_renderer_renderer_obj_renderer_scratch_buffer: [240],
+        iocore_gfx_renderer_renderer_obj_renderer_scratch_objbuffer: [128],
+        iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer: [240],
+        iocore_gfx_renderer_renderer_obj_window_renderer_compositor_objwindow_buffer: [240],
+        /* The SIMD build of the renderer bounces effects through a larger buffer. */
+        iocore_gfx_renderer_renderer_buffer: [0x680, 0x900],
+        iocore_gfx_renderer_renderer_line_buffer: [240],
+        iocore_gfx_renderer_renderer_frame_buffer: [240 * 160],
+        iocore_gfx_renderer_renderer_swizzled_frame: [240 * 160 * 3],
+        iocore_gfx_renderer_renderer_palette16: [0x100],
+        iocore_gfx_renderer_renderer_palette256: [0x100],
+        iocore_gfx_renderer_renderer_palette_obj16: [0x100],
+        iocore_gfx_renderer_renderer_palette_obj256: [0x100],
+        iocore_gfx_renderer_renderer_bg2_matrix_renderer_palette: [0x100],
+        iocore_gfx_renderer_renderer_bg0_renderer_scratch_buffer: [248],
+        iocore_gfx_renderer_renderer_bg1_renderer_scratch_buffer: [248],
+        iocore_gfx_renderer_renderer_bg2_text_renderer_scratch_buffer: [248],
+        iocore_gfx_renderer_renderer_bg3_text_renderer_scratch_buffer: [248],
+        iocore_gfx_renderer_renderer_bg0_renderer_tile_fetched: [8],
+        iocore_gfx_renderer_renderer_bg1_renderer_tile_fetched: [8],
+        iocore_gfx_renderer_renderer_bg2_text_renderer_tile_fetched: [8],
+        iocore_gfx_renderer_renderer_bg3_text_renderer_tile_fetched: [8],
+        iocore_gfx_renderer_renderer_bg_affine_renderer0_scratch_buffer: [240],
+        iocore_gfx_renderer_renderer_bg_affine_renderer1_scratch_buffer: [240],
+        /* The loader skips empty buffers and keeps the emulator's own, e.g. for the sound unit before format version 2. */
+        iocore_sound_channel3_pcm: [0, 0x40],
+        iocore_sound_channel3_waveram8: [0, 0x20],
+        iocore_sound_fifoabuffer_buffer: [0, 0x20],
+        iocore_sound_fifobbuffer_buffer: [0, 0x20],
+        iocore_wait_wait_state_clocks16: [0x20],
+        iocore_wait_wait_state_clocks32: [0x20],
+        iocore_saves_eepromchip_buffer: [0, 8],
+    );
+
+    if let Some(saves) = &s.iocore_saves_flashchip_saves {
+        report.length("iocore_saves_flashchip_saves", saves, &[0x10000, 0x20000]);
+    }
+    if let Some(saves) = &s.iocore_saves_eepromchip_saves {
+        report.length("iocore_saves_eepromchip_saves", saves, &[0x200, 0x2000]);
+    }
+
+    check_ranges!(report, s,
+        iocore_gfx_state_current_scan_line: 0..=227,
+        iocore_saves_save_type: 0..=3,
+        iocore_irq_interrupts_enabled: 0..=0x3FFF,
+        iocore_irq_interrupts_requested: 0..=0x3FFF,
+        iocore_dma_channel0_dma_type: 0..=3,
+        iocore_dma_channel1_dma_type: 0..=3,
+    );
+
+    report.invariant(
+        "iocore_cpu_mode_flags",
+        Mode::from_flags(s.iocore_cpu_mode_flags).is_some(),
+        "does not hold an ARM mode",
+    );
+
+    let timers = [
+        (
+            "iocore_timer_timer0_prescalar",
+            s.iocore_timer_timer0_prescalar,
+            s.iocore_timer_timer0_prescalar_shifted,
+        ),
+        (
+            "iocore_timer_timer1_prescalar",
+            s.iocore_timer_timer1_prescalar,
+            s.iocore_timer_timer1_prescalar_shifted,
+        ),
+        (
+            "iocore_timer_timer2_prescalar",
+            s.iocore_timer_timer2_prescalar,
+            s.iocore_timer_timer2_prescalar_shifted,
+        ),
+        (
+            "iocore_timer_timer3_prescalar",
+            s.iocore_timer_timer3_prescalar,
+            s.iocore_timer_timer3_prescalar_shifted,
+        ),
+    ];
+    for (field, prescalar, shifted) in timers {
+        report.invariant(
+            field,
+            PRESCALAR_SHIFTS.contains(&shifted) && prescalar == 1 << shifted,
+            "is not one of 1, 64, 256 or 1024 shifted by its `prescalar_shifted`",
+        );
+    }
+
+    /* The masks of `writeOAM16` in `IodineGBA/core/graphics/OBJ.js`. */
+    for (index, entry) in s
+        .iocore_gfx_renderer_renderer_obj_renderer_oamtable
+        .iter()
+        .enumerate()
+    {
+        let field = |name: &str| {
+            format!(
+                "iocore_gfx_renderer_renderer_obj_renderer_oamtable[{}].{}",
+                index, name
+            )
+        };
+
+        report.range(field("ycoord"), entry.ycoord, 0..=0xFF);
+        report.range(field("xcoord"), entry.xcoord, 0..=0x1FF);
+        report.range(field("mode"), entry.mode, 0..=3);
+        report.range(field("shape"), entry.shape, 0..=3);
+        report.range(field("size"), entry.size, 0..=3);
+        report.range(field("priority"), entry.priority, 0..=3);
+        report.range(field("tile_number"), entry.tile_number, 0..=0x3FF);
+        report.range(field("palette_number"), entry.palette_number, 0..=0xF0);
+        report.range(
+            field("matrix_parameters"),
+            entry.matrix_parameters,
+            0..=0x7C,
+        );
+    }
+
+    report
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::validate::{validate, ValidationReport};
+    use crate::{format, OamTableEntry, SaveState};
+
+    /// A state that passes validation.
+    pub(crate) fn save_state() -> SaveState {
+        SaveState {
+            iocore_cpu_mode_flags: 0x1F,
+            iocore_arm_registers: vec![0; 16],
+            iocore_arm_registers_usr: vec![0; 7],
+            iocore_cpu_registers_fiq: vec![0; 7],
+            iocore_cpu_registers_irq: vec![0; 2],
+            iocore_cpu_registers_svc: vec![0; 2],
+            iocore_cpu_registers_abt: vec![0; 2],
+            iocore_cpu_registers_und: vec![0; 2],
+            iocore_cpu_spsr: vec![0; 5],
+            iocore_memory_internal_ram: vec![0; 0x8000],
+            iocore_memory_external_ram: vec![0; 0x40000],
+            iocore_gfx_renderer_iodata8: vec![0; 20],
+            iocore_gfx_renderer_iodata16: vec![0; 10],
+            iocore_gfx_renderer_iodata32: vec![0; 5],
+            iocore_gfx_renderer_renderer_vram: vec![0; 0x18000],
+            iocore_gfx_renderer_renderer_palette_ram: vec![0; 0x400],
+            iocore_gfx_renderer_renderer_obj_renderer_oamram: vec![0; 0x400],
+            iocore_gfx_renderer_renderer_obj_renderer_objmatrix_parameters: vec![0; 0x80],
+            iocore_gfx_renderer_renderer_obj_renderer_scratch_window_buffer: vec![0; 240],
+            iocore_gfx_renderer_renderer_buffer: vec![0; 0x680],
+            iocore_gfx_renderer_renderer_frame_buffer: vec![0; 240 * 160],
+            iocore_sound_channel3_pcm: vec![0; 0x40],
+            iocore_sound_channel3_waveram8: vec![0; 0x20],
+            iocore_sound_fifoabuffer_buffer: vec![0; 0x20],
+            iocore_sound_fifobbuffer_buffer: vec![0; 0x20],
+            iocore_wait_wait_state_clocks16: vec![0; 0x20],
+            iocore_wait_wait_state_clocks32: vec![0; 0x20],
+            iocore_saves_eepromchip_buffer: vec![0; 8],
+            iocore_timer_timer0_prescalar: 1,
+            iocore_timer_timer1_prescalar: 64,
+            iocore_timer_timer1_prescalar_shifted: 6,
+            iocore_timer_timer2_prescalar: 1,
+            iocore_timer_timer3_prescalar: 1,
+            ..SaveState::default()
+        }
+    }
+
+    /// A state with the derived buffers `read` rebuilds.
+    fn read_save_state() -> SaveState {
+        format::read(&format::write_compact(&save_state()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_consistent_state_is_valid() {
+        let data = format::write(&read_save_state()).unwrap();
+        let report = ValidationReport::of_state(&data);
+
+        assert!(report.is_valid(), "{}", report);
+    }
+
+    #[test]
+    fn test_every_issue_is_reported() {
+        let mut s = read_save_state();
+        s.iocore_arm_registers.pop();
+        s.iocore_memory_external_ram.truncate(0x100);
+        s.iocore_saves_save_type = 4;
+        s.iocore_cpu_mode_flags = 0x00;
+        s.iocore_timer_timer2_prescalar = 64;
+        s.iocore_gfx_renderer_renderer_obj_renderer_oamtable = (0..128)
+            .map(|_| OamTableEntry {
+                xcoord: 0x200,
+                ..OamTableEntry::default()
+            })
+            .collect();
+
+        let issues = validate(&s).issues();
+
+        assert_eq!(issues.len(), 5 + 128);
+        assert!(
+            issues.contains(&"`iocore_arm_registers` has 15 entries, expected [16]".to_string())
+        );
+        assert!(issues.contains(&"`iocore_saves_save_type` is 4, outside of 0..=3".to_string()));
+        assert!(issues.contains(
+            &"`iocore_gfx_renderer_renderer_obj_renderer_oamtable[127].xcoord` is 512, outside of 0..=511"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn test_unreadable_state_is_invalid() {
+        let report = ValidationReport::of_state(&[1, 2, 3]);
+
+        assert!(!report.is_valid());
+        assert!(report.issues()[0].starts_with("`state` unreadable"));
+    }
+}